@@ -0,0 +1,105 @@
+# hypercube-greeter, Deutsch. Fehlende Meldungen kommen aus dem englischen Katalog.
+
+## Header
+
+# Rechts vom Rechnernamen, z. B. "Samstag, 17. Oktober"
+header-date = { $weekday }, { $day }. { $month }
+weekday-mon = Montag
+weekday-tue = Dienstag
+weekday-wed = Mittwoch
+weekday-thu = Donnerstag
+weekday-fri = Freitag
+weekday-sat = Samstag
+weekday-sun = Sonntag
+month-1 = Januar
+month-2 = Februar
+month-3 = März
+month-4 = April
+month-5 = Mai
+month-6 = Juni
+month-7 = Juli
+month-8 = August
+month-9 = September
+month-10 = Oktober
+month-11 = November
+month-12 = Dezember
+
+## Login form
+
+login-title = Anmeldung
+login-session = Sitzung:
+login-no-session = (keine Sitzung)
+login-username = Benutzername
+login-password = Passwort
+login-edit-hint = (i zum Bearbeiten)
+login-hint = Enter zum Anmelden, :help für Befehle
+login-hint-prompt = Enter zum Antworten, :cancel zum Abbrechen
+
+## Message panel
+
+message-error = Fehler
+message-info = Info
+message-authenticating = Anmeldung läuft... (Esc zum Abbrechen)
+message-dismiss = (beliebige Taste zum Schließen)
+message-login-cancelled = Anmeldung abgebrochen
+
+## Pickers
+
+picker-sessions = Sitzungen (j/k zum Wählen, Enter zum Bestätigen)
+picker-users = Benutzer (j/k zum Wählen, Enter zum Bestätigen)
+
+## Status bar
+
+status-demo = DEMO
+status-users = Benutzer
+status-sessions = Sitzungen
+status-power = Energie
+
+## Confirm dialog
+
+confirm-reboot-title = Neustart
+confirm-reboot = Wirklich neu starten?
+confirm-poweroff-title = Herunterfahren
+confirm-poweroff = Wirklich herunterfahren?
+confirm-yes = Ja
+confirm-no = Nein
+
+## Help
+
+help-title = Hilfe
+help-normal-mode = Normalmodus
+help-cursor = Cursor nach links/rechts
+help-words = Wortsprünge (mit Anzahl: 3w)
+help-find = Zeichen suchen, ; und , wiederholen
+help-fields = Zwischen Feldern wechseln
+help-insert = Einfügemodus
+help-command = Befehlsmodus
+help-delete = Zeichen / bis Ende / Feld löschen
+help-operators = Löschen / ändern (dw, cw, ciw)
+help-replace = Ersetzen / Groß-klein
+help-undo = Rückgängig / wiederholen
+help-login = Anmelden
+help-insert-mode = Einfügemodus
+help-escape = Zurück zum Normalmodus
+help-submit = Absenden / nächstes Feld
+help-commands = Befehle
+help-cmd-session = Sitzung wählen
+help-cmd-user = Benutzer wählen
+help-cmd-cancel = Anmeldung abbrechen
+help-cmd-reboot = System neu starten
+help-cmd-poweroff = System herunterfahren
+help-cmd-help = Diese Hilfe zeigen
+help-cmd-quit = Anmelden / beenden
+help-cmd-complete = Ergänzen / Verlauf
+help-close = Escape zum Schließen
+
+## Errors
+
+error-session-not-found = Sitzung nicht gefunden: { $name }
+error-user-not-found = Benutzer nicht gefunden: { $name }
+error-username-required = Benutzername erforderlich
+error-no-session = Keine Sitzung ausgewählt
+error-session-start = Sitzung konnte nicht gestartet werden
+error-greetd-unreachable = greetd nicht erreichbar: { $error }
+error-reboot = Neustart fehlgeschlagen: { $error }
+error-poweroff = Herunterfahren fehlgeschlagen: { $error }
@@ -0,0 +1,273 @@
+# hypercube-onboard, Deutsch. Fehlende Meldungen kommen aus dem englischen Katalog.
+# Leerzeichen am Anfang und Ende setzt der Code, nicht der Katalog.
+
+## Header
+
+header-network-ok = Netzwerk: OK
+header-network-none = Netzwerk: --
+
+## Sidebar
+
+sidebar-title = Schritte
+sidebar-hint = j/k:Wahl l/Enter:Bearb.
+step-user = Benutzer
+step-hostname = Rechner
+step-locale = Sprache
+step-keyboard = Tastatur
+step-network = Netzwerk
+step-preferences = Optionen
+step-review = Prüfen
+step-update = Pakete
+step-reboot = Neustart
+step-custom = Eigener
+locked-title = Schritt gesperrt
+locked-text = Schließe zuerst die vorigen Schritte ab.
+
+## Message panel
+
+message-error = Fehler
+message-info = Info
+please-wait = Bitte warten...
+
+## Confirm dialog
+
+confirm-reboot-title = Neustart
+confirm-reboot = Wirklich neu starten?
+confirm-poweroff-title = Ausschalten
+confirm-poweroff = Wirklich ausschalten?
+confirm-cancel-title = Einrichtung abbrechen
+confirm-cancel = Einrichtung wirklich abbrechen?
+confirm-resume-title = Einrichtung fortsetzen
+confirm-resume = Die unterbrochene Einrichtung fortsetzen?
+confirm-yes = Ja
+confirm-no = Nein
+
+## Help
+
+help-title = Hilfe
+help-navigation = Navigation:
+help-focus-sidebar = Zur Seitenleiste
+help-focus-content = Zum Inhalt
+help-up-down = Hoch/runter
+help-collapse = Ein-/ausklappen
+help-select = Wählen / bearbeiten
+help-quick-select = Schritt direkt wählen
+help-vim-modes = Vim-Modi:
+help-insert = Einfügemodus
+help-normal = Zurück zum Normalmodus
+help-command = Befehlsmodus (Tab ergänzt)
+help-commands = Befehle:
+help-close = q oder Esc zum Schließen
+
+## Welcome
+
+welcome-intro = Dieser Assistent richtet dein System ein:
+welcome-user = Benutzerkonto anlegen
+welcome-language = Sprache und Tastatur einstellen
+welcome-timezone = Zeitzone festlegen
+welcome-network = Mit dem Netzwerk verbinden (falls nötig)
+welcome-apps = Anwendungen installieren
+welcome-start = Einrichtung starten
+welcome-hint = Enter zum Beginnen
+
+## Forms
+
+field-empty = (leer)
+field-username = Benutzer
+field-password = Passwort
+field-confirm = Bestätigen
+field-hostname = Rechnername
+field-pretty-name = Anzeigename
+user-title = Benutzerkonto anlegen
+hostname-title = Diesen Rechner benennen
+hostname-help = Buchstaben, Ziffern und Bindestriche; im Netzwerk sichtbar.
+hostname-pretty-help = Optional, beliebiger Text, z. B. "Alex' Laptop"
+
+## Pickers
+
+picker-locale = Sprache wählen
+picker-keyboard = Tastaturbelegung wählen
+picker-timezone = Zeitzone wählen
+picker-choose-one = Eine Option wählen
+picker-filter = Filter:
+picker-default = (Standard: { $default })
+
+## Preferences
+
+preferences-title = Optionen
+preference-ntp = Uhr mit Netzwerkzeit abgleichen
+preference-keyring = Anmelde-Schlüsselbund anlegen
+
+## Network
+
+network-title = Netzwerk einrichten
+network-status-connected = Status: Verbunden
+network-status-disconnected = Status: Nicht verbunden
+network-done = Das Netzwerk ist bereits verbunden. Dieser Schritt ist erledigt.
+network-launch = Enter startet { $program } zur WLAN-Einrichtung
+network-skip-hint = Oder :skip, um ohne Netzwerk fortzufahren
+
+## Review
+
+review-title = Einstellungen prüfen und anwenden
+review-summary = Zusammenfassung:
+review-user = Benutzer
+review-hostname = Rechnername
+review-locale = Sprache
+review-keyboard = Tastatur
+review-timezone = Zeitzone
+review-ntp = Netzwerkzeit
+review-keyring = Schlüsselbund
+review-network = Netzwerk
+review-not-set = (nicht gesetzt)
+review-system-default = (Systemvorgabe)
+review-on = An
+review-off = Aus
+review-keyring-none = Nicht angelegt
+review-keyring-password = Mit dem Passwort des Benutzers angelegt
+review-keyring-first-login = Wird bei der ersten Anmeldung angelegt
+review-connected = Verbunden
+review-disconnected = Nicht verbunden
+review-applying = Einstellungen werden angewendet:
+review-press-enter = Enter wendet die Einstellungen an und legt den Benutzer an.
+
+## Update
+
+update-title = Pakete installieren
+update-installing = Installation:
+update-select = Zu installierende Pakete wählen:
+update-more-above = ↑ weitere oben
+update-more-below = ↓ { $count } weitere unten
+update-sudo = Passwort für sudo-Befehle erforderlich:
+update-password = Passwort:
+update-enter-password = (Passwort eingeben)
+task-output = Ausgabe: { $task }
+task-no-output = (noch keine Ausgabe)
+task-create-user = Benutzer '{ $username }' wird angelegt
+task-set-locale = Sprache wird auf { $locale } gesetzt
+task-set-keymap = Tastatur wird auf { $keymap } gesetzt
+task-set-timezone = Zeitzone wird auf { $timezone } gesetzt
+task-set-hostname = Rechnername wird auf { $hostname } gesetzt
+task-ntp-on = Zeitabgleich über das Netzwerk wird eingeschaltet
+task-ntp-off = Zeitabgleich über das Netzwerk wird ausgeschaltet
+task-create-keyring = Anmelde-Schlüsselbund für '{ $username }' wird angelegt
+task-already-done = In einem früheren Durchlauf erledigt
+task-finishing = Einrichtung wird abgeschlossen
+task-failed-summary = { $task } (fehlgeschlagen)
+task-retrying = { $error }; neuer Versuch in { $delay } s (Versuch { $attempt } von { $attempts })
+
+## Reboot
+
+reboot-title = Einrichtung abgeschlossen!
+reboot-ready = Dein System ist eingerichtet und bereit.
+reboot-user-created = Benutzer '{ $username }' wurde angelegt.
+reboot-skipped = Übersprungen:
+reboot-required = Ein Neustart ist nötig, um alle Änderungen zu übernehmen.
+reboot-after = Nach dem Neustart erscheint der Anmeldebildschirm.
+
+## Custom steps
+
+custom-runs = Führt aus: { $command }
+
+## Buttons, shown as "[Enter] <label>"
+
+button-create-user = Benutzer anlegen
+button-set-hostname = Name festlegen
+button-save-next = Speichern & weiter
+button-next = Weiter
+button-configure-wifi = WLAN einrichten
+button-apply = Anwenden & Benutzer anlegen
+button-enter-password = Passwort eingeben
+button-install = Auswahl installieren
+button-skip-continue = Überspringen & weiter
+button-reboot = Jetzt neu starten
+button-continue = Weiter
+button-run = { $program } starten
+
+## Status bar hints: the state on the left, keys on the right
+
+status-sidebar = j/k: navigieren
+status-sidebar-keys = l/Enter: bearbeiten  :help
+status-picker = j/k: navigieren  Enter: wählen
+status-picker-keys = i: filtern  Strg+h: Seitenleiste
+status-picker-insert = Tippen zum Filtern
+status-picker-insert-keys = Esc: normal  Enter: wählen
+status-toggles = j/k: bewegen  Leertaste: umschalten
+status-toggles-keys = Enter: speichern  Strg+h: Seitenleiste
+status-info-keys = Enter: weiter  Strg+h: Seitenleiste
+status-program-keys = Enter: starten  :skip  Strg+h: Seitenleiste
+status-form = j/k: Felder  i: bearbeiten
+status-form-keys = Enter: absenden  Strg+h: Seitenleiste
+status-form-insert = Text eingeben
+status-form-insert-keys = Esc: normal  Tab: nächstes Feld
+status-command-keys = Tab: ergänzen  Enter: ausführen  Esc: abbrechen
+status-welcome-keys = Enter: Einrichtung starten
+status-review = Einstellungen prüfen
+status-review-keys = Enter: anwenden  Strg+h: Seitenleiste
+status-update-password = Passwort erforderlich
+status-update-password-keys = i: Passwort eingeben  :skip
+status-update = Bereit zum Ausführen
+status-update-keys = Enter: ausführen  :skip
+status-task-failed = Aufgabe fehlgeschlagen
+status-task-failed-keys = r: wiederholen  s: überspringen  o: Ausgabe  J/K: Aufgabe
+status-task-output = Befehlsausgabe
+status-task-output-keys = j/k: blättern  o: vergrößern  J/K: Aufgabe
+status-reboot = Einrichtung abgeschlossen!
+status-reboot-keys = Enter: System neu starten
+status-network = Netzwerk verbunden
+status-network-keys = Enter: weiter  Strg+h: Seitenleiste
+status-network-none = Netzwerk nicht verbunden
+status-network-none-keys = Enter: einrichten  :skip
+status-locked = Schritt gesperrt
+status-locked-keys = Zuerst die vorigen Schritte abschließen
+status-executing = Bitte warten...
+
+## Messages
+
+msg-step-locked = Dieser Schritt ist gesperrt. Schließe zuerst die vorigen Schritte ab.
+msg-locale-selected = Sprache gewählt: { $value }
+msg-keyboard-selected = Tastatur gewählt: { $value }
+msg-timezone-selected = Zeitzone gewählt: { $value }
+msg-choice-selected = { $step } gewählt: { $value }
+msg-step-saved = { $step } gespeichert
+msg-preferences-saved = Optionen gespeichert
+msg-would-run = Würde { $program } starten
+msg-program-failed = { $program } wurde nicht erfolgreich beendet
+msg-launch-failed = { $program } konnte nicht gestartet werden: { $error }
+msg-reboot-failed = Neustart fehlgeschlagen: { $error }
+msg-poweroff-failed = Herunterfahren fehlgeschlagen: { $error }
+msg-step-required = Dieser Schritt ist erforderlich
+msg-start-setup-first = Starte zuerst die Einrichtung
+msg-unknown-step = Unbekannter Schritt: { $name }
+msg-review-first = Schließe zuerst den Schritt Prüfen ab
+msg-unknown-command = Unbekannter Befehl: { $name }
+msg-username-required = Benutzername erforderlich
+msg-username-chars = Der Benutzername darf nur Buchstaben, Ziffern, Unterstrich und Bindestrich enthalten
+msg-username-length = Der Benutzername darf höchstens 32 Zeichen lang sein
+msg-password-required = Passwort erforderlich
+msg-password-length = Das Passwort muss mindestens { $min } Zeichen lang sein
+msg-password-mismatch = Die Passwörter stimmen nicht überein
+msg-hostname-required = Rechnername erforderlich
+msg-hostname-length = Der Rechnername darf höchstens { $max } Zeichen lang sein
+msg-hostname-empty-part = Der Rechnername darf keine leeren Teile zwischen Punkten haben
+msg-hostname-part-length = Jeder Teil des Rechnernamens darf höchstens { $max } Zeichen lang sein
+msg-hostname-chars = Der Rechnername darf nur Buchstaben, Ziffern, Bindestriche und Punkte enthalten
+msg-hostname-dash = Teile des Rechnernamens dürfen nicht mit einem Bindestrich beginnen oder enden
+msg-pretty-name-control = Der Anzeigename darf keine Steuerzeichen enthalten
+msg-resumed = Vorherige Einrichtung fortgesetzt
+msg-hostname-set = Rechnername gesetzt: { $hostname }
+msg-complete-step-first = Schließe zuerst den Schritt { $step } ab
+msg-no-packages = Keine Pakete gewählt. Es geht weiter zum Abschluss.
+msg-user-first = Der Benutzer muss angelegt sein, bevor Befehle laufen
+msg-sudo-password = Gib dein Passwort für die sudo-Befehle ein
+msg-user-required = Das Benutzerkonto ist erforderlich und kann nicht übersprungen werden
+msg-task-failed = '{ $task }' fehlgeschlagen. r: wiederholen  s: überspringen  o: Ausgabe
+msg-applied-reboot = Einstellungen angewendet! Neu starten, um die Einrichtung abzuschließen.
+msg-applied-install = Einstellungen angewendet! Jetzt können Pakete installiert werden.
+msg-applied-select = Einstellungen angewendet! Wähle die zu installierenden Pakete.
+msg-commands-completed = Befehle abgeschlossen! Neu starten, um die Einrichtung abzuschließen.
+msg-tasks-failed = { $count ->
+    [one] 1 Aufgabe ist bei der Einrichtung fehlgeschlagen
+   *[other] { $count } Aufgaben sind bei der Einrichtung fehlgeschlagen
+}
+msg-installation-complete = Installation abgeschlossen! Neu starten, um die Einrichtung abzuschließen.
@@ -0,0 +1,106 @@
+# hypercube-greeter, English. This is the reference catalog: every message
+# starts here, and other languages fall back to it for anything they lack.
+
+## Header
+
+# Shown right of the host name, e.g. "Saturday, October 17"
+header-date = { $weekday }, { $month } { $day }
+weekday-mon = Monday
+weekday-tue = Tuesday
+weekday-wed = Wednesday
+weekday-thu = Thursday
+weekday-fri = Friday
+weekday-sat = Saturday
+weekday-sun = Sunday
+month-1 = January
+month-2 = February
+month-3 = March
+month-4 = April
+month-5 = May
+month-6 = June
+month-7 = July
+month-8 = August
+month-9 = September
+month-10 = October
+month-11 = November
+month-12 = December
+
+## Login form
+
+login-title = Login
+login-session = Session:
+login-no-session = (no session)
+login-username = Username
+login-password = Password
+login-edit-hint = (i to edit)
+login-hint = Press Enter to login, :help for commands
+login-hint-prompt = Press Enter to answer, :cancel to abort
+
+## Message panel
+
+message-error = Error
+message-info = Info
+message-authenticating = Authenticating... (Esc to cancel)
+message-dismiss = (press any key to dismiss)
+message-login-cancelled = Login cancelled
+
+## Pickers
+
+picker-sessions = Sessions (j/k to select, Enter to confirm)
+picker-users = Users (j/k to select, Enter to confirm)
+
+## Status bar
+
+status-demo = DEMO
+status-users = users
+status-sessions = sessions
+status-power = power
+
+## Confirm dialog
+
+confirm-reboot-title = Reboot
+confirm-reboot = Are you sure you want to reboot?
+confirm-poweroff-title = Shutdown
+confirm-poweroff = Are you sure you want to shut down?
+confirm-yes = Yes
+confirm-no = No
+
+## Help
+
+help-title = Help
+help-normal-mode = Normal Mode
+help-cursor = Move cursor left/right
+help-words = Word motions (with counts: 3w)
+help-find = Find character, ; and , repeat
+help-fields = Move between fields
+help-insert = Enter insert mode
+help-command = Enter command mode
+help-delete = Delete char / to end / field
+help-operators = Delete / change (dw, cw, ciw)
+help-replace = Replace / toggle case
+help-undo = Undo / redo
+help-login = Login
+help-insert-mode = Insert Mode
+help-escape = Return to normal mode
+help-submit = Submit / next field
+help-commands = Commands
+help-cmd-session = Select session
+help-cmd-user = Select user
+help-cmd-cancel = Abort login
+help-cmd-reboot = Reboot system
+help-cmd-poweroff = Shutdown system
+help-cmd-help = Show this help
+help-cmd-quit = Login / quit
+help-cmd-complete = Complete / history
+help-close = Press Escape to close
+
+## Errors
+
+error-session-not-found = Session not found: { $name }
+error-user-not-found = User not found: { $name }
+error-username-required = Username is required
+error-no-session = No session selected
+error-session-start = Failed to start session
+error-greetd-unreachable = Cannot reach greetd: { $error }
+error-reboot = Reboot failed: { $error }
+error-poweroff = Poweroff failed: { $error }
@@ -0,0 +1,274 @@
+# hypercube-onboard, English. This is the reference catalog: every message
+# starts here, and other languages fall back to it for anything they lack.
+# Leading and trailing spaces are added by the code, not kept here.
+
+## Header
+
+header-network-ok = Network: OK
+header-network-none = Network: --
+
+## Sidebar
+
+sidebar-title = Steps
+sidebar-hint = j/k:nav l/Enter:edit
+step-user = User
+step-hostname = Host
+step-locale = Locale
+step-keyboard = Keyboard
+step-network = Network
+step-preferences = Prefs
+step-review = Review
+step-update = Update
+step-reboot = Reboot
+step-custom = Custom
+locked-title = Step Locked
+locked-text = Complete the previous steps to unlock this step.
+
+## Message panel
+
+message-error = Error
+message-info = Info
+please-wait = Please wait...
+
+## Confirm dialog
+
+confirm-reboot-title = Reboot
+confirm-reboot = Are you sure you want to reboot?
+confirm-poweroff-title = Power Off
+confirm-poweroff = Are you sure you want to power off?
+confirm-cancel-title = Cancel Setup
+confirm-cancel = Are you sure you want to cancel setup?
+confirm-resume-title = Resume Setup
+confirm-resume = Continue the interrupted setup?
+confirm-yes = Yes
+confirm-no = No
+
+## Help
+
+help-title = Help
+help-navigation = Navigation:
+help-focus-sidebar = Focus sidebar
+help-focus-content = Focus content
+help-up-down = Navigate up/down
+help-collapse = Collapse/Expand
+help-select = Select / Edit
+help-quick-select = Quick select step
+help-vim-modes = Vim Modes:
+help-insert = Enter insert mode
+help-normal = Return to normal
+help-command = Command mode (Tab completes)
+help-commands = Commands:
+help-close = Press q or Esc to close
+
+## Welcome
+
+welcome-intro = This wizard will help you set up your system:
+welcome-user = Create your user account
+welcome-language = Configure language and keyboard
+welcome-timezone = Set your timezone
+welcome-network = Connect to the network (if needed)
+welcome-apps = Install applications
+welcome-start = Start Setup
+welcome-hint = Press Enter to begin
+
+## Forms
+
+field-empty = (empty)
+field-username = Username
+field-password = Password
+field-confirm = Confirm
+field-hostname = Hostname
+field-pretty-name = Pretty name
+user-title = Create User Account
+hostname-title = Name This Computer
+hostname-help = Letters, numbers and dashes; shown on the network.
+hostname-pretty-help = Optional, any text, e.g. "Alex's Laptop"
+
+## Pickers
+
+picker-locale = Select Locale
+picker-keyboard = Select Keyboard Layout
+picker-timezone = Select Timezone
+picker-choose-one = Choose one
+picker-filter = Filter:
+picker-default = (default: { $default })
+
+## Preferences
+
+preferences-title = Preferences
+preference-ntp = Sync clock with network time
+preference-keyring = Create a login keyring
+
+## Network
+
+network-title = Network Configuration
+network-status-connected = Status: Connected
+network-status-disconnected = Status: Not connected
+network-done = Network is already connected. This step is complete.
+network-launch = Press Enter to launch { $program } for WiFi setup
+network-skip-hint = Or use :skip to continue without network
+
+## Review
+
+review-title = Review & Apply Configuration
+review-summary = Configuration Summary:
+review-user = User
+review-hostname = Hostname
+review-locale = Locale
+review-keyboard = Keyboard
+review-timezone = Timezone
+review-ntp = Network time
+review-keyring = Login keyring
+review-network = Network
+review-not-set = (not set)
+review-system-default = (system default)
+review-on = On
+review-off = Off
+review-keyring-none = Not created
+review-keyring-password = Created with the user's password
+review-keyring-first-login = Created at first login
+review-connected = Connected
+review-disconnected = Not connected
+review-applying = Applying configuration:
+review-press-enter = Press Enter to apply configuration and create user.
+
+## Update
+
+update-title = Install Packages
+update-installing = Installing:
+update-select = Select packages to install:
+update-more-above = ↑ more above
+update-more-below = ↓ { $count } more below
+update-sudo = Password required for sudo commands:
+update-password = Password:
+update-enter-password = (enter password)
+task-output = Output: { $task }
+task-no-output = (no output yet)
+task-create-user = Creating user '{ $username }'
+task-set-locale = Setting locale to { $locale }
+task-set-keymap = Setting keyboard to { $keymap }
+task-set-timezone = Setting timezone to { $timezone }
+task-set-hostname = Setting hostname to { $hostname }
+task-ntp-on = Turning on network time sync
+task-ntp-off = Turning off network time sync
+task-create-keyring = Creating login keyring for '{ $username }'
+task-already-done = Done in an earlier run
+task-finishing = Finishing setup
+task-failed-summary = { $task } (failed)
+task-retrying = { $error }; retrying in { $delay }s (attempt { $attempt } of { $attempts })
+
+## Reboot
+
+reboot-title = Setup Complete!
+reboot-ready = Your system is configured and ready.
+reboot-user-created = User '{ $username }' has been created.
+reboot-skipped = Skipped:
+reboot-required = A reboot is required to apply all changes.
+reboot-after = After rebooting, you will see the login screen.
+
+## Custom steps
+
+custom-runs = Runs: { $command }
+
+## Buttons, shown as "[Enter] <label>"
+
+button-create-user = Create User
+button-set-hostname = Set Hostname
+button-save-next = Save & Next
+button-next = Next
+button-configure-wifi = Configure WiFi
+button-apply = Apply & Create User
+button-enter-password = Enter Password
+button-install = Install Selected
+button-skip-continue = Skip & Continue
+button-reboot = Reboot Now
+button-continue = Continue
+button-run = Run { $program }
+
+## Status bar hints: the state on the left, keys on the right
+
+status-sidebar = j/k: navigate
+status-sidebar-keys = l/Enter: edit  :help
+status-picker = j/k: navigate  Enter: select
+status-picker-keys = i: filter  Ctrl+h: sidebar
+status-picker-insert = Type to filter
+status-picker-insert-keys = Esc: normal  Enter: select
+status-toggles = j/k: move  Space: toggle
+status-toggles-keys = Enter: save  Ctrl+h: sidebar
+status-info-keys = Enter: continue  Ctrl+h: sidebar
+status-program-keys = Enter: run  :skip  Ctrl+h: sidebar
+status-form = j/k: fields  i: edit
+status-form-keys = Enter: submit  Ctrl+h: sidebar
+status-form-insert = Type to enter text
+status-form-insert-keys = Esc: normal  Tab: next field
+status-command-keys = Tab: complete  Enter: run  Esc: cancel
+status-welcome-keys = Enter: start setup
+status-review = Review your settings
+status-review-keys = Enter: apply  Ctrl+h: sidebar
+status-update-password = Password required
+status-update-password-keys = i: enter password  :skip
+status-update = Ready to run commands
+status-update-keys = Enter: run  :skip
+status-task-failed = Task failed
+status-task-failed-keys = r: retry  s: skip  o: output  J/K: task
+status-task-output = Command output
+status-task-output-keys = j/k: scroll  o: expand  J/K: task
+status-reboot = Setup complete!
+status-reboot-keys = Enter: reboot system
+status-network = Network connected
+status-network-keys = Enter: next  Ctrl+h: sidebar
+status-network-none = Network not connected
+status-network-none-keys = Enter: configure  :skip
+status-locked = Step locked
+status-locked-keys = Complete previous steps first
+status-executing = Please wait...
+
+## Messages
+
+msg-step-locked = This step is locked. Complete previous steps first.
+msg-locale-selected = Locale selected: { $value }
+msg-keyboard-selected = Keyboard selected: { $value }
+msg-timezone-selected = Timezone selected: { $value }
+msg-choice-selected = { $step } selected: { $value }
+msg-step-saved = { $step } saved
+msg-preferences-saved = Preferences saved
+msg-would-run = Would run { $program }
+msg-program-failed = { $program } did not finish successfully
+msg-launch-failed = Failed to launch { $program }: { $error }
+msg-reboot-failed = Reboot failed: { $error }
+msg-poweroff-failed = Poweroff failed: { $error }
+msg-step-required = This step is required
+msg-start-setup-first = Start the setup first
+msg-unknown-step = Unknown step: { $name }
+msg-review-first = Complete the Review step first
+msg-unknown-command = Unknown command: { $name }
+msg-username-required = Username is required
+msg-username-chars = Username can only contain letters, numbers, underscore, and dash
+msg-username-length = Username must be 32 characters or less
+msg-password-required = Password is required
+msg-password-length = Password must be at least { $min } characters
+msg-password-mismatch = Passwords do not match
+msg-hostname-required = Hostname is required
+msg-hostname-length = Hostname must be { $max } characters or less
+msg-hostname-empty-part = Hostname cannot have empty parts between dots
+msg-hostname-part-length = Each part of the hostname must be { $max } characters or less
+msg-hostname-chars = Hostname can only contain letters, numbers, dashes and dots
+msg-hostname-dash = Parts of the hostname cannot start or end with a dash
+msg-pretty-name-control = Pretty name cannot contain control characters
+msg-resumed = Resumed the previous setup
+msg-hostname-set = Hostname set: { $hostname }
+msg-complete-step-first = Complete the { $step } step first
+msg-no-packages = No packages selected. Continuing to finish.
+msg-user-first = User must be created before running commands
+msg-sudo-password = Enter your password for sudo commands
+msg-user-required = The user account is required and cannot be skipped
+msg-task-failed = '{ $task }' failed. r: retry  s: skip  o: output
+msg-applied-reboot = Configuration applied! Reboot to finish setup.
+msg-applied-install = Configuration applied! You can now install packages.
+msg-applied-select = Configuration applied! Select packages to install.
+msg-commands-completed = Commands completed! Click Reboot to finish setup.
+msg-tasks-failed = { $count ->
+    [one] 1 task failed during configuration
+   *[other] { $count } tasks failed during configuration
+}
+msg-installation-complete = Installation complete! Reboot to finish setup.
@@ -0,0 +1,105 @@
+# hypercube-greeter, français. Les messages manquants viennent du catalogue anglais.
+
+## Header
+
+# À droite du nom de la machine, p. ex. "samedi 17 octobre"
+header-date = { $weekday } { $day } { $month }
+weekday-mon = lundi
+weekday-tue = mardi
+weekday-wed = mercredi
+weekday-thu = jeudi
+weekday-fri = vendredi
+weekday-sat = samedi
+weekday-sun = dimanche
+month-1 = janvier
+month-2 = février
+month-3 = mars
+month-4 = avril
+month-5 = mai
+month-6 = juin
+month-7 = juillet
+month-8 = août
+month-9 = septembre
+month-10 = octobre
+month-11 = novembre
+month-12 = décembre
+
+## Login form
+
+login-title = Connexion
+login-session = Session :
+login-no-session = (aucune session)
+login-username = Utilisateur
+login-password = Mot de passe
+login-edit-hint = (i pour modifier)
+login-hint = Entrée pour se connecter, :help pour les commandes
+login-hint-prompt = Entrée pour répondre, :cancel pour abandonner
+
+## Message panel
+
+message-error = Erreur
+message-info = Info
+message-authenticating = Authentification... (Échap pour annuler)
+message-dismiss = (appuyez sur une touche pour fermer)
+message-login-cancelled = Connexion annulée
+
+## Pickers
+
+picker-sessions = Sessions (j/k pour choisir, Entrée pour valider)
+picker-users = Utilisateurs (j/k pour choisir, Entrée pour valider)
+
+## Status bar
+
+status-demo = DÉMO
+status-users = utilisateurs
+status-sessions = sessions
+status-power = alimentation
+
+## Confirm dialog
+
+confirm-reboot-title = Redémarrer
+confirm-reboot = Voulez-vous vraiment redémarrer ?
+confirm-poweroff-title = Éteindre
+confirm-poweroff = Voulez-vous vraiment éteindre ?
+confirm-yes = Oui
+confirm-no = Non
+
+## Help
+
+help-title = Aide
+help-normal-mode = Mode normal
+help-cursor = Curseur à gauche/droite
+help-words = Mouvements par mot (avec nombre : 3w)
+help-find = Chercher un caractère, ; et , répètent
+help-fields = Passer d'un champ à l'autre
+help-insert = Mode insertion
+help-command = Mode commande
+help-delete = Effacer car. / jusqu'à la fin / champ
+help-operators = Effacer / changer (dw, cw, ciw)
+help-replace = Remplacer / changer la casse
+help-undo = Annuler / rétablir
+help-login = Se connecter
+help-insert-mode = Mode insertion
+help-escape = Retour au mode normal
+help-submit = Valider / champ suivant
+help-commands = Commandes
+help-cmd-session = Choisir la session
+help-cmd-user = Choisir l'utilisateur
+help-cmd-cancel = Abandonner la connexion
+help-cmd-reboot = Redémarrer le système
+help-cmd-poweroff = Éteindre le système
+help-cmd-help = Afficher cette aide
+help-cmd-quit = Se connecter / quitter
+help-cmd-complete = Compléter / historique
+help-close = Échap pour fermer
+
+## Errors
+
+error-session-not-found = Session introuvable : { $name }
+error-user-not-found = Utilisateur introuvable : { $name }
+error-username-required = Le nom d'utilisateur est requis
+error-no-session = Aucune session sélectionnée
+error-session-start = Impossible de démarrer la session
+error-greetd-unreachable = Impossible de joindre greetd : { $error }
+error-reboot = Échec du redémarrage : { $error }
+error-poweroff = Échec de l'extinction : { $error }
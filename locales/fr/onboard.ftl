@@ -0,0 +1,273 @@
+# hypercube-onboard, français. Les messages manquants viennent du catalogue anglais.
+# Les espaces de début et de fin sont ajoutées par le code, pas par le catalogue.
+
+## Header
+
+header-network-ok = Réseau : OK
+header-network-none = Réseau : --
+
+## Sidebar
+
+sidebar-title = Étapes
+sidebar-hint = j/k:nav l/Entrée:modif.
+step-user = Compte
+step-hostname = Machine
+step-locale = Langue
+step-keyboard = Clavier
+step-network = Réseau
+step-preferences = Options
+step-review = Résumé
+step-update = Paquets
+step-reboot = Redémarrage
+step-custom = Perso
+locked-title = Étape verrouillée
+locked-text = Terminez d'abord les étapes précédentes.
+
+## Message panel
+
+message-error = Erreur
+message-info = Info
+please-wait = Veuillez patienter...
+
+## Confirm dialog
+
+confirm-reboot-title = Redémarrer
+confirm-reboot = Voulez-vous vraiment redémarrer ?
+confirm-poweroff-title = Éteindre
+confirm-poweroff = Voulez-vous vraiment éteindre ?
+confirm-cancel-title = Annuler l'installation
+confirm-cancel = Voulez-vous vraiment annuler l'installation ?
+confirm-resume-title = Reprendre l'installation
+confirm-resume = Reprendre l'installation interrompue ?
+confirm-yes = Oui
+confirm-no = Non
+
+## Help
+
+help-title = Aide
+help-navigation = Navigation :
+help-focus-sidebar = Aller au panneau latéral
+help-focus-content = Aller au contenu
+help-up-down = Monter/descendre
+help-collapse = Replier/déplier
+help-select = Choisir / modifier
+help-quick-select = Aller à une étape
+help-vim-modes = Modes Vim :
+help-insert = Mode insertion
+help-normal = Retour au mode normal
+help-command = Mode commande (Tab complète)
+help-commands = Commandes :
+help-close = q ou Échap pour fermer
+
+## Welcome
+
+welcome-intro = Cet assistant va configurer votre système :
+welcome-user = Créer votre compte utilisateur
+welcome-language = Régler la langue et le clavier
+welcome-timezone = Choisir votre fuseau horaire
+welcome-network = Se connecter au réseau (si nécessaire)
+welcome-apps = Installer des applications
+welcome-start = Commencer
+welcome-hint = Appuyez sur Entrée pour commencer
+
+## Forms
+
+field-empty = (vide)
+field-username = Utilisateur
+field-password = Mot de passe
+field-confirm = Confirmer
+field-hostname = Nom
+field-pretty-name = Nom affiché
+user-title = Créer le compte utilisateur
+hostname-title = Nommer cet ordinateur
+hostname-help = Lettres, chiffres et tirets ; visible sur le réseau.
+hostname-pretty-help = Facultatif, texte libre, p. ex. "Portable d'Alex"
+
+## Pickers
+
+picker-locale = Choisir la langue
+picker-keyboard = Choisir la disposition du clavier
+picker-timezone = Choisir le fuseau horaire
+picker-choose-one = Choisissez une option
+picker-filter = Filtre :
+picker-default = (par défaut : { $default })
+
+## Preferences
+
+preferences-title = Options
+preference-ntp = Synchroniser l'horloge avec le réseau
+preference-keyring = Créer un trousseau de session
+
+## Network
+
+network-title = Configuration réseau
+network-status-connected = État : connecté
+network-status-disconnected = État : non connecté
+network-done = Le réseau est déjà connecté. Cette étape est terminée.
+network-launch = Appuyez sur Entrée pour lancer { $program } et configurer le Wi-Fi
+network-skip-hint = Ou utilisez :skip pour continuer sans réseau
+
+## Review
+
+review-title = Vérifier et appliquer la configuration
+review-summary = Résumé de la configuration :
+review-user = Utilisateur
+review-hostname = Nom de la machine
+review-locale = Langue
+review-keyboard = Clavier
+review-timezone = Fuseau horaire
+review-ntp = Heure réseau
+review-keyring = Trousseau
+review-network = Réseau
+review-not-set = (non défini)
+review-system-default = (valeur du système)
+review-on = Activée
+review-off = Désactivée
+review-keyring-none = Non créé
+review-keyring-password = Créé avec le mot de passe de l'utilisateur
+review-keyring-first-login = Créé à la première connexion
+review-connected = Connecté
+review-disconnected = Non connecté
+review-applying = Application de la configuration :
+review-press-enter = Appuyez sur Entrée pour appliquer la configuration et créer le compte.
+
+## Update
+
+update-title = Installer des paquets
+update-installing = Installation :
+update-select = Choisissez les paquets à installer :
+update-more-above = ↑ autres au-dessus
+update-more-below = ↓ { $count } autres en dessous
+update-sudo = Mot de passe requis pour les commandes sudo :
+update-password = Mot de passe :
+update-enter-password = (saisir le mot de passe)
+task-output = Sortie : { $task }
+task-no-output = (pas encore de sortie)
+task-create-user = Création de l'utilisateur '{ $username }'
+task-set-locale = Réglage de la langue sur { $locale }
+task-set-keymap = Réglage du clavier sur { $keymap }
+task-set-timezone = Réglage du fuseau horaire sur { $timezone }
+task-set-hostname = Réglage du nom de la machine sur { $hostname }
+task-ntp-on = Activation de la synchronisation de l'heure par le réseau
+task-ntp-off = Désactivation de la synchronisation de l'heure par le réseau
+task-create-keyring = Création du trousseau de connexion pour '{ $username }'
+task-already-done = Fait lors d'une exécution précédente
+task-finishing = Finalisation de la configuration
+task-failed-summary = { $task } (échec)
+task-retrying = { $error } ; nouvel essai dans { $delay } s (tentative { $attempt } sur { $attempts })
+
+## Reboot
+
+reboot-title = Installation terminée !
+reboot-ready = Votre système est configuré et prêt.
+reboot-user-created = L'utilisateur '{ $username }' a été créé.
+reboot-skipped = Ignoré :
+reboot-required = Un redémarrage est nécessaire pour appliquer toutes les modifications.
+reboot-after = Après le redémarrage, l'écran de connexion s'affichera.
+
+## Custom steps
+
+custom-runs = Exécute : { $command }
+
+## Buttons, shown as "[Enter] <label>"
+
+button-create-user = Créer le compte
+button-set-hostname = Définir le nom
+button-save-next = Enregistrer et continuer
+button-next = Suivant
+button-configure-wifi = Configurer le Wi-Fi
+button-apply = Appliquer et créer le compte
+button-enter-password = Saisir le mot de passe
+button-install = Installer la sélection
+button-skip-continue = Ignorer et continuer
+button-reboot = Redémarrer maintenant
+button-continue = Continuer
+button-run = Lancer { $program }
+
+## Status bar hints: the state on the left, keys on the right
+
+status-sidebar = j/k : naviguer
+status-sidebar-keys = l/Entrée : modifier  :help
+status-picker = j/k : naviguer  Entrée : choisir
+status-picker-keys = i : filtrer  Ctrl+h : panneau
+status-picker-insert = Tapez pour filtrer
+status-picker-insert-keys = Échap : normal  Entrée : choisir
+status-toggles = j/k : déplacer  Espace : cocher
+status-toggles-keys = Entrée : enregistrer  Ctrl+h : panneau
+status-info-keys = Entrée : continuer  Ctrl+h : panneau
+status-program-keys = Entrée : lancer  :skip  Ctrl+h : panneau
+status-form = j/k : champs  i : modifier
+status-form-keys = Entrée : valider  Ctrl+h : panneau
+status-form-insert = Saisissez le texte
+status-form-insert-keys = Échap : normal  Tab : champ suivant
+status-command-keys = Tab : compléter  Entrée : exécuter  Échap : annuler
+status-welcome-keys = Entrée : commencer l'installation
+status-review = Vérifiez vos réglages
+status-review-keys = Entrée : appliquer  Ctrl+h : panneau
+status-update-password = Mot de passe requis
+status-update-password-keys = i : saisir le mot de passe  :skip
+status-update = Prêt à exécuter les commandes
+status-update-keys = Entrée : exécuter  :skip
+status-task-failed = Échec de la tâche
+status-task-failed-keys = r : réessayer  s : ignorer  o : sortie  J/K : tâche
+status-task-output = Sortie de la commande
+status-task-output-keys = j/k : défiler  o : agrandir  J/K : tâche
+status-reboot = Installation terminée !
+status-reboot-keys = Entrée : redémarrer le système
+status-network = Réseau connecté
+status-network-keys = Entrée : suivant  Ctrl+h : panneau
+status-network-none = Réseau non connecté
+status-network-none-keys = Entrée : configurer  :skip
+status-locked = Étape verrouillée
+status-locked-keys = Terminez d'abord les étapes précédentes
+status-executing = Veuillez patienter...
+
+## Messages
+
+msg-step-locked = Cette étape est verrouillée. Terminez d'abord les étapes précédentes.
+msg-locale-selected = Langue choisie : { $value }
+msg-keyboard-selected = Clavier choisi : { $value }
+msg-timezone-selected = Fuseau horaire choisi : { $value }
+msg-choice-selected = { $step } choisi : { $value }
+msg-step-saved = { $step } enregistré
+msg-preferences-saved = Options enregistrées
+msg-would-run = Lancerait { $program }
+msg-program-failed = { $program } ne s'est pas terminé correctement
+msg-launch-failed = Impossible de lancer { $program } : { $error }
+msg-reboot-failed = Échec du redémarrage : { $error }
+msg-poweroff-failed = Échec de l'extinction : { $error }
+msg-step-required = Cette étape est obligatoire
+msg-start-setup-first = Commencez d'abord l'installation
+msg-unknown-step = Étape inconnue : { $name }
+msg-review-first = Terminez d'abord l'étape Résumé
+msg-unknown-command = Commande inconnue : { $name }
+msg-username-required = Le nom d'utilisateur est requis
+msg-username-chars = Le nom d'utilisateur ne peut contenir que des lettres, des chiffres, le tiret bas et le tiret
+msg-username-length = Le nom d'utilisateur ne doit pas dépasser 32 caractères
+msg-password-required = Le mot de passe est requis
+msg-password-length = Le mot de passe doit contenir au moins { $min } caractères
+msg-password-mismatch = Les mots de passe ne correspondent pas
+msg-hostname-required = Le nom de la machine est obligatoire
+msg-hostname-length = Le nom de la machine doit faire au plus { $max } caractères
+msg-hostname-empty-part = Le nom de la machine ne peut pas avoir de parties vides entre les points
+msg-hostname-part-length = Chaque partie du nom de la machine doit faire au plus { $max } caractères
+msg-hostname-chars = Le nom de la machine ne peut contenir que des lettres, des chiffres, des tirets et des points
+msg-hostname-dash = Les parties du nom de la machine ne peuvent pas commencer ou finir par un tiret
+msg-pretty-name-control = Le nom affiché ne peut pas contenir de caractères de contrôle
+msg-resumed = Installation précédente reprise
+msg-hostname-set = Nom de la machine défini : { $hostname }
+msg-complete-step-first = Terminez d'abord l'étape { $step }
+msg-no-packages = Aucun paquet choisi. Passage à la fin.
+msg-user-first = Le compte doit être créé avant d'exécuter les commandes
+msg-sudo-password = Saisissez votre mot de passe pour les commandes sudo
+msg-user-required = Le compte utilisateur est obligatoire et ne peut pas être ignoré
+msg-task-failed = Échec de '{ $task }'. r : réessayer  s : ignorer  o : sortie
+msg-applied-reboot = Configuration appliquée ! Redémarrez pour terminer l'installation.
+msg-applied-install = Configuration appliquée ! Vous pouvez maintenant installer des paquets.
+msg-applied-select = Configuration appliquée ! Choisissez les paquets à installer.
+msg-commands-completed = Commandes terminées ! Redémarrez pour terminer l'installation.
+msg-tasks-failed = { $count ->
+    [one] { $count } tâche a échoué pendant la configuration
+   *[other] { $count } tâches ont échoué pendant la configuration
+}
+msg-installation-complete = Installation terminée ! Redémarrez pour terminer l'installation.
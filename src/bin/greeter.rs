@@ -1,29 +1,46 @@
 use clap::Parser;
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{DisableMouseCapture, EnableMouseCapture, KeyCode},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use hypercube_utils::error::{HypercubeError, Result};
 use hypercube_utils::event::{Event, EventHandler};
-use hypercube_utils::greeter::{App, AppAction};
-use hypercube_utils::ipc::GreetdClient;
+use hypercube_utils::greeter::{App, AppAction, GreeterConfig};
+use hypercube_utils::i18n::{Catalog, Domain};
+use hypercube_utils::ipc::mock::{MockGreetd, MockScript};
+use hypercube_utils::ipc::{AuthRequest, AuthResponse, GreetdClient};
 use hypercube_utils::system;
 use ratatui::prelude::*;
 use std::io::stdout;
 use std::panic;
+use std::path::PathBuf;
 use std::time::Duration;
-use tracing::{error, info};
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info, warn};
 use tracing_subscriber::EnvFilter;
 
 #[derive(Parser, Debug)]
 #[command(name = "hypercube-greeter")]
 #[command(author, version, about = "A vim-inspired greeter for greetd")]
 struct Args {
+    /// Path to greeter config file (default: /usr/share/hypercube/greeter.toml,
+    /// then /etc/hypercube/greeter.toml and /etc/hypercube/greeter.d/*.toml, merged)
+    #[arg(long)]
+    config: Option<String>,
+
+    /// Print the merged config and exit
+    #[arg(long)]
+    print_config: bool,
+
     /// Simulate all operations without making real changes
     #[arg(long)]
     dryrun: bool,
 
+    /// Talk to an in-process fake greetd that plays back the given script
+    #[arg(long, value_name = "SCRIPT")]
+    mock_greetd: Option<String>,
+
     /// Log file path (logging disabled if not specified)
     #[arg(long)]
     log_file: Option<String>,
@@ -33,6 +50,10 @@ struct Args {
 async fn main() -> Result<()> {
     let args = Args::parse();
 
+    if args.print_config {
+        std::process::exit(print_config(args.config.as_deref()));
+    }
+
     // Set up logging only if log file is specified
     if let Some(ref log_path) = args.log_file {
         let file = std::fs::OpenOptions::new()
@@ -65,25 +86,49 @@ async fn main() -> Result<()> {
     // Initialize terminal
     let mut terminal = setup_terminal()?;
 
+    // Load config from specified path, default path, or use defaults
+    let config = match args.config.as_deref() {
+        Some(path) => GreeterConfig::load_from(path),
+        None => GreeterConfig::load(),
+    }
+    .unwrap_or_else(|e| {
+        warn!("{}, using defaults", e);
+        GreeterConfig::default()
+    });
+
+    // Start the fake greetd before connecting to it
+    let mock = match args.mock_greetd.as_deref().map(start_mock) {
+        Some(Ok(mock)) => Some(mock),
+        Some(Err(e)) => {
+            error!("Failed to start mock greetd: {}", e);
+            restore_terminal()?;
+            return Err(e);
+        }
+        None => None,
+    };
+
+    let connector = Connector {
+        dryrun: args.dryrun,
+        socket: mock.as_ref().map(|m| m.socket_path().to_path_buf()),
+        timeout: config.greetd.timeout(),
+    };
+
     // Create greetd client
-    let mut client = if args.dryrun {
-        GreetdClient::demo()
-    } else {
-        match GreetdClient::connect().await {
-            Ok(c) => c,
-            Err(e) => {
-                error!("Failed to connect to greetd: {}. Use --dryrun to test without greetd.", e);
-                restore_terminal()?;
-                return Err(e);
-            }
+    let client = match connector.connect().await {
+        Ok(c) => c,
+        Err(e) => {
+            error!("Failed to connect to greetd: {}. Use --dryrun to test without greetd.", e);
+            restore_terminal()?;
+            return Err(e);
         }
     };
 
-    // Create app state
-    let mut app = App::new(args.dryrun);
+    // Create app state; a mock login must not reboot the machine or save state
+    let mut app = App::new(config, args.dryrun || mock.is_some());
+    app.catalog = Catalog::from_env(Domain::Greeter);
 
     // Run the app
-    let result = run(&mut terminal, &mut app, &mut client).await;
+    let result = run(&mut terminal, &mut app, &connector, client).await;
 
     // Restore terminal
     restore_terminal()?;
@@ -113,50 +158,162 @@ fn restore_terminal() -> Result<()> {
     Ok(())
 }
 
+/// A greetd request running in the background, handing the client back when done
+type InFlight = JoinHandle<(GreetdClient, Result<AuthResponse>)>;
+
+/// Print the config files merged into one; the exit status is 1 if they do not load
+fn print_config(config_path: Option<&str>) -> i32 {
+    let paths = match config_path {
+        Some(path) => vec![path.into()],
+        None => GreeterConfig::layer_paths(),
+    };
+    let layers = match GreeterConfig::read_layers(&paths) {
+        Ok(layers) => layers,
+        Err(e) => {
+            eprintln!("hypercube-greeter: {e}");
+            return 1;
+        }
+    };
+    print!("{}", layers.to_toml());
+    match layers.deserialize::<GreeterConfig>() {
+        Ok(_) => 0,
+        Err(e) => {
+            eprintln!("hypercube-greeter: {e}");
+            1
+        }
+    }
+}
+
+fn spawn_request(mut client: GreetdClient, request: AuthRequest) -> InFlight {
+    debug!("Sending {:?}", request);
+    tokio::spawn(async move {
+        let response = client.request(request).await;
+        (client, response)
+    })
+}
+
+fn start_mock(script_path: &str) -> Result<MockGreetd> {
+    let script = MockScript::load_from(script_path)?;
+    MockGreetd::start(script)
+}
+
+/// How to (re)connect the greetd client
+struct Connector {
+    dryrun: bool,
+    /// Socket of the mock greetd, instead of $GREETD_SOCK
+    socket: Option<PathBuf>,
+    timeout: Duration,
+}
+
+impl Connector {
+    async fn connect(&self) -> Result<GreetdClient> {
+        if self.dryrun {
+            Ok(GreetdClient::demo())
+        } else if let Some(ref socket) = self.socket {
+            GreetdClient::connect_to(socket, self.timeout).await
+        } else {
+            GreetdClient::connect(self.timeout).await
+        }
+    }
+}
+
 async fn run(
     terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
     app: &mut App,
-    client: &mut GreetdClient,
+    connector: &Connector,
+    client: GreetdClient,
 ) -> Result<()> {
     let tick_rate = Duration::from_millis(250);
     let mut events = EventHandler::new(tick_rate);
 
+    // The client is moved into the task while a request is in flight
+    let mut client = Some(client);
+    let mut in_flight: Option<InFlight> = None;
+
     loop {
         // Draw UI
         terminal
             .draw(|frame| hypercube_utils::greeter::ui::draw(frame, app))
             .map_err(|e| HypercubeError::Terminal(e.to_string()))?;
 
-        // Handle events
-        if let Some(event) = events.next().await {
-            match event {
-                Event::Key(key) => {
-                    if let Some(action) = app.handle_key(key) {
-                        match action {
-                            AppAction::Login => {
-                                app.login(client).await;
-                            }
-                            AppAction::Cancel => {
-                                let _ = client.cancel_session().await;
-                                app.password.clear();
-                                app.working = false;
-                            }
-                            AppAction::Reboot => {
-                                if let Err(e) = system::reboot(app.demo_mode) {
-                                    app.set_error(format!("Reboot failed: {}", e));
+        let mut next_request = None;
+
+        tokio::select! {
+            Some(event) = events.next() => {
+                match event {
+                    Event::Key(key) if in_flight.is_some() => {
+                        // Only Esc is handled while greetd is busy; dropping the
+                        // connection makes greetd discard the half-open session
+                        if key.code == KeyCode::Esc
+                            && let Some(task) = in_flight.take()
+                        {
+                            task.abort();
+                            app.abort_auth();
+                            // Without a client, the next request connects again
+                            client = match connector.connect().await {
+                                Ok(c) => Some(c),
+                                Err(e) => {
+                                    error!("Failed to reconnect to greetd: {}", e);
+                                    None
                                 }
-                            }
-                            AppAction::Poweroff => {
-                                if let Err(e) = system::poweroff(app.demo_mode) {
-                                    app.set_error(format!("Poweroff failed: {}", e));
+                            };
+                        }
+                    }
+                    Event::Key(key) => {
+                        if let Some(action) = app.handle_key(key) {
+                            match action {
+                                AppAction::Login => {
+                                    next_request = app.login();
+                                }
+                                AppAction::Respond => {
+                                    next_request = app.respond();
+                                }
+                                AppAction::Cancel => {
+                                    next_request = app.cancel();
+                                }
+                                AppAction::Reboot => {
+                                    if let Err(e) =
+                                        system::run_power_command(&app.config.power.reboot, app.demo_mode)
+                                    {
+                                        app.set_error(app.catalog.tr_args("error-reboot", &[("error", e.to_string().into())]));
+                                    }
+                                }
+                                AppAction::Poweroff => {
+                                    if let Err(e) =
+                                        system::run_power_command(&app.config.power.poweroff, app.demo_mode)
+                                    {
+                                        app.set_error(app.catalog.tr_args("error-poweroff", &[("error", e.to_string().into())]));
+                                    }
                                 }
                             }
                         }
                     }
+                    Event::Mouse => {}
+                    Event::Resize => {}
+                    Event::Tick => {}
                 }
-                Event::Mouse => {}
-                Event::Resize => {}
-                Event::Tick => {}
+            }
+            joined = async { in_flight.as_mut().expect("guarded by is_some").await }, if in_flight.is_some() => {
+                in_flight = None;
+                let (returned, response) = joined.map_err(|e| HypercubeError::Ipc(e.into()))?;
+                client = Some(returned);
+                next_request = app.handle_auth_response(response);
+            }
+        }
+
+        if let Some(request) = next_request {
+            if client.is_none() {
+                match connector.connect().await {
+                    Ok(c) => client = Some(c),
+                    Err(e) => {
+                        error!("Failed to reconnect to greetd: {}", e);
+                        app.abort_auth();
+                        app.set_error(app.catalog.tr_args("error-greetd-unreachable", &[("error", e.to_string().into())]));
+                    }
+                }
+            }
+            if let Some(c) = client.take() {
+                in_flight = Some(spawn_request(c, request));
             }
         }
 
@@ -1,4 +1,4 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use crossterm::{
     event::{DisableMouseCapture, EnableMouseCapture},
     execute,
@@ -6,36 +6,77 @@ use crossterm::{
 };
 use hypercube_utils::error::{HypercubeError, Result};
 use hypercube_utils::event::{Event, EventHandler};
-use hypercube_utils::onboard::{ExecutionMessage, OnboardApp, OnboardAction, OnboardConfig};
+use hypercube_utils::i18n::{Catalog, Domain};
+use hypercube_utils::onboard::{
+    check_file, run_unattended, Answers, ConfirmAction, ExecutionMessage, OnboardApp,
+    OnboardAction, OnboardConfig, UnattendedError,
+};
 use hypercube_utils::system;
 use ratatui::prelude::*;
 use std::io::stdout;
 use std::panic;
+use std::path::Path;
 use std::time::Duration;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 use tracing_subscriber::EnvFilter;
 
 #[derive(Parser, Debug)]
 #[command(name = "hypercube-onboard")]
 #[command(author, version, about = "First-boot onboarding wizard for Hypercube Linux")]
 struct Args {
-    /// Path to onboard config file (default: /etc/hypercube/onboard.toml)
+    /// Path to onboard config file (default: /usr/share/hypercube/onboard.toml,
+    /// then /etc/hypercube/onboard.toml and /etc/hypercube/onboard.d/*.toml, merged)
     #[arg(long)]
     config: Option<String>,
 
+    /// Print the merged config and exit
+    #[arg(long)]
+    print_config: bool,
+
     /// Simulate all operations without making real changes
     #[arg(long)]
     dryrun: bool,
 
+    /// Answer file for unattended setup; runs headless unless --confirm is given
+    #[arg(long, value_name = "FILE")]
+    answers: Option<String>,
+
+    /// Pre-fill the wizard from --answers and wait for confirmation
+    #[arg(long, requires = "answers")]
+    confirm: bool,
+
+    /// Refuse to start if the config has any problem `check-config` would report
+    #[arg(long)]
+    strict: bool,
+
     /// Log file path (logging disabled if not specified)
     #[arg(long)]
     log_file: Option<String>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Check a config file for syntax errors, unknown keys and settings that cannot work
+    CheckConfig {
+        /// Config file to check
+        path: String,
+    },
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = Args::parse();
 
+    if let Some(Command::CheckConfig { path }) = &args.command {
+        std::process::exit(check_config(Path::new(path)));
+    }
+    if args.print_config {
+        std::process::exit(print_config(args.config.as_deref()));
+    }
+
     // Set up logging only if log file is specified
     if let Some(ref log_path) = args.log_file {
         let file = std::fs::OpenOptions::new()
@@ -58,6 +99,28 @@ async fn main() -> Result<()> {
         }
     }
 
+    let answers = match args.answers.as_deref().map(Answers::load_from) {
+        Some(Ok(answers)) => Some(answers),
+        Some(Err(e)) => exit_unattended(UnattendedError::Answers(e.to_string())),
+        None => None,
+    };
+
+    let (config, config_error) = load_config(args.config.as_deref(), args.dryrun, args.strict);
+
+    if let Some(ref answers) = answers
+        && !args.confirm
+    {
+        let mut app = OnboardApp::new(config);
+        if let Err(e) = run_unattended(&mut app, answers).await {
+            exit_unattended(e);
+        }
+        if let Err(e) = finish_unattended(&app) {
+            eprintln!("hypercube-onboard: completion action failed: {e}");
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     // Set up panic handler to restore terminal
     let original_hook = panic::take_hook();
     panic::set_hook(Box::new(move |panic_info| {
@@ -69,7 +132,7 @@ async fn main() -> Result<()> {
     let mut terminal = setup_terminal()?;
 
     // Run onboard wizard
-    let result = run_onboard(&mut terminal, args.config.as_deref(), args.dryrun).await;
+    let result = run_onboard(&mut terminal, config, config_error, answers).await;
 
     // Restore terminal
     restore_terminal()?;
@@ -99,26 +162,114 @@ fn restore_terminal() -> Result<()> {
     Ok(())
 }
 
-async fn run_onboard(
-    terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
-    config_path: Option<&str>,
-    dryrun: bool,
-) -> Result<()> {
-    let tick_rate = Duration::from_millis(250);
-    let mut events = EventHandler::new(tick_rate);
+/// Report every problem in the config at `path`; the exit status is 1 if there were any
+fn check_config(path: &Path) -> i32 {
+    match check_file(path) {
+        Ok(_) => {
+            println!("{}: OK", path.display());
+            0
+        }
+        Err(problems) => {
+            for problem in &problems {
+                eprintln!("{}", problem.in_file(path));
+            }
+            1
+        }
+    }
+}
 
-    // Load config from specified path, default path, or use defaults
-    let mut config = match config_path {
-        Some(path) => OnboardConfig::load_from(path).unwrap_or_default(),
-        None => OnboardConfig::load().unwrap_or_default(),
+/// Print the config files merged into one; the exit status is 1 if they do not load
+fn print_config(config_path: Option<&str>) -> i32 {
+    let paths = match config_path {
+        Some(path) => vec![path.into()],
+        None => OnboardConfig::layer_paths(),
+    };
+    let layers = match OnboardConfig::read_layers(&paths) {
+        Ok(layers) => layers,
+        Err(e) => {
+            eprintln!("hypercube-onboard: {e}");
+            return 1;
+        }
     };
+    print!("{}", layers.to_toml());
+    match layers.deserialize::<OnboardConfig>() {
+        Ok(_) => 0,
+        Err(e) => {
+            eprintln!("hypercube-onboard: {e}");
+            1
+        }
+    }
+}
+
+/// Load config from specified path, default path, or use defaults.
+/// A config that fails to load is reported and replaced by the defaults, unless
+/// `strict`, which also refuses configs with problems and exits instead.
+fn load_config(config_path: Option<&str>, dryrun: bool, strict: bool) -> (OnboardConfig, Option<String>) {
+    let loaded = match (config_path, strict) {
+        (Some(path), true) => OnboardConfig::load_strict(path),
+        (None, true) => OnboardConfig::load_checked(),
+        (Some(path), false) => OnboardConfig::load_from(path),
+        (None, false) => OnboardConfig::load(),
+    };
+    let (mut config, config_error) = match loaded {
+        Ok(config) => (config, None),
+        Err(e) if strict => {
+            error!("{}", e);
+            eprintln!("hypercube-onboard: {e}");
+            std::process::exit(1);
+        }
+        Err(e) => {
+            error!("Config not loaded, using defaults: {}", e);
+            eprintln!("hypercube-onboard: config not loaded, using defaults: {e}");
+            (OnboardConfig::default(), Some(format!("Config not loaded: {e}")))
+        }
+    };
+    for problem in config.problems() {
+        warn!("Config: {}", problem);
+    }
 
     // --dryrun flag overrides config
     if dryrun {
         config.general.dryrun = true;
     }
+    (config, config_error)
+}
+
+fn exit_unattended(e: UnattendedError) -> ! {
+    error!("Unattended setup failed: {}", e);
+    eprintln!("hypercube-onboard: {e}");
+    std::process::exit(e.exit_code().into())
+}
+
+/// Run the configured completion action after a headless setup
+fn finish_unattended(app: &OnboardApp) -> std::io::Result<()> {
+    match app.confirm_action {
+        Some(ConfirmAction::Reboot) => system::reboot(app.is_dryrun())?,
+        Some(ConfirmAction::Poweroff) => system::poweroff(app.is_dryrun())?,
+        _ => {}
+    }
+    Ok(())
+}
+
+async fn run_onboard(
+    terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
+    config: OnboardConfig,
+    config_error: Option<String>,
+    answers: Option<Answers>,
+) -> Result<()> {
+    let tick_rate = Duration::from_millis(250);
+    let mut events = EventHandler::new(tick_rate);
 
     let mut app = OnboardApp::new(config);
+    app.set_catalog(Catalog::from_env(Domain::Onboard));
+    if let Some(e) = config_error {
+        app.set_error(e);
+    }
+    if let Some(answers) = answers
+        && let Err(e) = app.apply_answers(&answers)
+    {
+        app.set_error(e.to_string());
+    }
     let mut exec_rx: Option<tokio::sync::mpsc::UnboundedReceiver<ExecutionMessage>> = None;
 
     loop {
@@ -140,18 +291,22 @@ async fn run_onboard(
                                             .args(&args)
                                             .status();
                                         *terminal = setup_terminal()?;
-                                        if let Err(e) = status {
-                                            app.set_error(format!("Failed to launch {}: {}", program, e));
+                                        match status {
+                                            Ok(status) => app.external_program_exited(status.success()),
+                                            Err(e) => {
+                                                let args = [("program", program.as_str().into()), ("error", e.to_string().into())];
+                                                app.set_error(app.catalog.tr_args("msg-launch-failed", &args));
+                                            }
                                         }
                                     }
                                     OnboardAction::Reboot => {
                                         if let Err(e) = system::reboot(app.is_dryrun()) {
-                                            app.set_error(format!("Reboot failed: {}", e));
+                                            app.set_error(app.catalog.tr_args("msg-reboot-failed", &[("error", e.to_string().into())]));
                                         }
                                     }
                                     OnboardAction::Poweroff => {
                                         if let Err(e) = system::poweroff(app.is_dryrun()) {
-                                            app.set_error(format!("Poweroff failed: {}", e));
+                                            app.set_error(app.catalog.tr_args("msg-poweroff-failed", &[("error", e.to_string().into())]));
                                         }
                                     }
                                     OnboardAction::ExecuteStep => {
@@ -163,6 +318,12 @@ async fn run_onboard(
                                     OnboardAction::ExecuteUpdate => {
                                         exec_rx = app.start_update_execution();
                                     }
+                                    OnboardAction::RetryTask(idx) => {
+                                        exec_rx = app.retry_task(idx);
+                                    }
+                                    OnboardAction::SkipTask(idx) => {
+                                        exec_rx = app.skip_task(idx);
+                                    }
                                     OnboardAction::ExitToLogin => {
                                         app.finish_setup().await;
                                     }
@@ -9,7 +9,16 @@ pub enum HypercubeError {
     Ipc(#[from] std::io::Error),
 
     #[error("Codec error: {0}")]
-    Codec(#[from] greetd_ipc::codec::Error),
+    Codec(#[from] serde_json::Error),
+
+    #[error("Protocol error: {0}")]
+    Protocol(String),
+
+    #[error("greetd did not respond within {0:?}")]
+    Timeout(std::time::Duration),
+
+    #[error("Lost the connection to greetd; start the login again")]
+    ConnectionLost,
 
     #[error("Authentication failed: {0}")]
     AuthFailed(String),
@@ -19,6 +28,9 @@ pub enum HypercubeError {
 
     #[error("Terminal error: {0}")]
     Terminal(String),
+
+    #[error("Config error: {0}")]
+    Config(String),
 }
 
 pub type Result<T> = std::result::Result<T, HypercubeError>;
@@ -23,23 +23,14 @@ impl EventHandler {
         std::thread::spawn(move || {
             loop {
                 if event::poll(tick_rate).unwrap_or(false) {
-                    match event::read() {
-                        Ok(event::Event::Key(key)) => {
-                            if event_tx.send(Event::Key(key)).is_err() {
-                                break;
-                            }
-                        }
-                        Ok(event::Event::Mouse(_)) => {
-                            if event_tx.send(Event::Mouse).is_err() {
-                                break;
-                            }
-                        }
-                        Ok(event::Event::Resize(_, _)) => {
-                            if event_tx.send(Event::Resize).is_err() {
-                                break;
-                            }
-                        }
-                        _ => {}
+                    let event = match event::read() {
+                        Ok(event::Event::Key(key)) => Event::Key(key),
+                        Ok(event::Event::Mouse(_)) => Event::Mouse,
+                        Ok(event::Event::Resize(_, _)) => Event::Resize,
+                        _ => continue,
+                    };
+                    if event_tx.send(event).is_err() {
+                        break;
                     }
                 } else if event_tx.send(Event::Tick).is_err() {
                     break;
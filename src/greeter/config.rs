@@ -0,0 +1,166 @@
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::error::{HypercubeError, Result};
+use crate::ipc::DEFAULT_TIMEOUT;
+use crate::layers::{self, Layers};
+use crate::system::{POWEROFF_COMMAND, REBOOT_COMMAND};
+use crate::ui::ThemeConfig;
+use crate::vim::VimMode;
+
+const DEFAULT_STATE_PATH: &str = "/var/cache/hypercube-greeter/state.toml";
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct GreeterConfig {
+    pub general: GeneralConfig,
+    pub users: UsersConfig,
+    pub power: PowerConfig,
+    pub greetd: GreetdConfig,
+    pub theme: ThemeConfig,
+}
+
+impl GreeterConfig {
+    /// Load the vendor config, /etc/hypercube/greeter.toml and its drop-ins, merged
+    pub fn load() -> Result<Self> {
+        Self::read_layers(&Self::layer_paths())?.deserialize().map_err(HypercubeError::Config)
+    }
+
+    /// Existing config files, lowest priority first
+    pub fn layer_paths() -> Vec<PathBuf> {
+        layers::layer_paths(Path::new(layers::VENDOR_DIR), Path::new(layers::ADMIN_DIR), "greeter")
+    }
+
+    pub fn read_layers(paths: &[PathBuf]) -> Result<Layers> {
+        let layers = Layers::read(paths, &[]).map_err(HypercubeError::Config)?;
+        info!("Loaded config from {:?}", layers.sources);
+        Ok(layers)
+    }
+
+    pub fn load_from<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+
+        if !path.exists() {
+            info!("Config file not found at {:?}, using defaults", path);
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| HypercubeError::Config(format!("{}: {}", path.display(), e)))?;
+        let config: GreeterConfig = toml::from_str(&content)
+            .map_err(|e| HypercubeError::Config(format!("{}: {}", path.display(), e)))?;
+        info!("Loaded config from {:?}", path);
+        Ok(config)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct GeneralConfig {
+    /// Text shown above the login form (empty to hide)
+    pub greeting: String,
+    /// Session selected at startup, matched by slug or name
+    pub default_session: Option<String>,
+    /// Username pre-filled at startup
+    pub default_user: Option<String>,
+    /// Vim mode the greeter starts in ("normal" or "insert")
+    pub initial_mode: VimMode,
+    /// Pre-fill the last logged-in user and their last session
+    pub remember_last_user: bool,
+    /// Where the last user and sessions are stored
+    pub state_file: String,
+}
+
+impl Default for GeneralConfig {
+    fn default() -> Self {
+        Self {
+            greeting: String::new(),
+            default_session: None,
+            default_user: None,
+            initial_mode: VimMode::Insert,
+            remember_last_user: true,
+            state_file: DEFAULT_STATE_PATH.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct UsersConfig {
+    /// Users never listed in the user picker
+    pub hidden: Vec<String>,
+    /// Overrides UID_MIN from /etc/login.defs
+    pub min_uid: Option<u32>,
+    /// Overrides UID_MAX from /etc/login.defs
+    pub max_uid: Option<u32>,
+}
+
+impl Default for UsersConfig {
+    fn default() -> Self {
+        Self {
+            hidden: vec![
+                "nobody".to_string(),
+                "nfsnobody".to_string(),
+                "greeter".to_string(),
+            ],
+            min_uid: None,
+            max_uid: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PowerConfig {
+    pub reboot: Vec<String>,
+    pub poweroff: Vec<String>,
+}
+
+impl Default for PowerConfig {
+    fn default() -> Self {
+        Self {
+            reboot: REBOOT_COMMAND.iter().map(|s| s.to_string()).collect(),
+            poweroff: POWEROFF_COMMAND.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct GreetdConfig {
+    /// Seconds to wait for greetd to answer a request. Requests wait on PAM, so this must
+    /// be longer than the slowest PAM module (fingerprint readers, LDAP); hitting it loses
+    /// the greetd session and the login has to start over.
+    #[serde(deserialize_with = "deserialize_timeout")]
+    pub timeout_secs: u64,
+}
+
+impl GreetdConfig {
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_secs)
+    }
+}
+
+/// A zero timeout would fail every request before greetd could answer, so it becomes 1
+fn deserialize_timeout<'de, D>(deserializer: D) -> std::result::Result<u64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match u64::deserialize(deserializer)? {
+        0 => {
+            warn!("greetd timeout_secs must be at least 1, using 1");
+            Ok(1)
+        }
+        secs => Ok(secs),
+    }
+}
+
+impl Default for GreetdConfig {
+    fn default() -> Self {
+        Self {
+            timeout_secs: DEFAULT_TIMEOUT.as_secs(),
+        }
+    }
+}
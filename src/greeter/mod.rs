@@ -1,10 +1,18 @@
+mod config;
+mod state;
 pub mod ui;
 pub mod widgets;
 
-use crate::ipc::{AuthResponse, GreetdClient};
+pub use config::GreeterConfig;
+pub use state::GreeterState;
+
+use crate::error::Result;
+use crate::i18n::{Catalog, Domain};
+use crate::ipc::{AuthRequest, AuthResponse};
 use crate::system::{discover_sessions, discover_users, Session, User};
 use crate::ui::Theme;
-use crate::vim::{parse_command, Command, InputBuffer, ModeAction, VimMode};
+use crate::vim::{command_names, parse_command, Command, CommandLine, InputBuffer, COMMANDS, ModeAction, NormalParser, Parsed, VimMode};
+use chrono::{Local, NaiveDateTime};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use tracing::{debug, error, info, warn};
 
@@ -13,6 +21,8 @@ pub enum FocusField {
     #[default]
     Username,
     Password,
+    /// Answer field for a pending PAM prompt
+    Prompt,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -21,17 +31,35 @@ pub enum ConfirmAction {
     Poweroff,
 }
 
+/// Where the greeter is in its conversation with greetd
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AuthStage {
+    Idle,
+    Authenticating,
+    StartingSession,
+    Cancelling,
+}
+
 pub struct Message {
     pub text: String,
     pub is_error: bool,
 }
 
+/// A PAM prompt waiting for the user's answer
+pub struct AuthPrompt {
+    pub text: String,
+    pub secret: bool,
+    pub input: InputBuffer,
+}
+
 pub struct App {
+    pub config: GreeterConfig,
+    pub state: GreeterState,
     pub vim_mode: VimMode,
     pub focus: FocusField,
     pub username: InputBuffer,
     pub password: InputBuffer,
-    pub command_buffer: InputBuffer,
+    pub command_line: CommandLine,
     pub sessions: Vec<Session>,
     pub selected_session: usize,
     pub users: Vec<User>,
@@ -45,31 +73,95 @@ pub struct App {
     pub show_help: bool,
     pub confirm_action: Option<ConfirmAction>,
     pub theme: Theme,
+    /// Translated UI text, English unless the binary picks a language
+    pub catalog: Catalog,
     pub demo_mode: bool,
-    pending_dd: bool,
+    /// Host name shown in the header
+    pub hostname: String,
+    /// Time shown in the header instead of the clock (used by snapshot tests)
+    pub frozen_time: Option<NaiveDateTime>,
+    /// Prompt currently shown below the login form, if greetd asked for more input
+    pub auth_prompt: Option<AuthPrompt>,
+    /// Info and error messages received during the current PAM conversation
+    pub auth_messages: Vec<Message>,
+    /// Whether the password field has already answered a secret prompt
+    password_sent: bool,
+    /// What the greetd request in flight is for
+    auth_stage: AuthStage,
+    /// Partially typed normal-mode command
+    normal: NormalParser,
 }
 
 impl App {
-    pub fn new(demo_mode: bool) -> Self {
+    pub fn new(config: GreeterConfig, demo_mode: bool) -> Self {
         let sessions = discover_sessions();
-        let users = discover_users();
+        let users = discover_users(
+            &config.users.hidden,
+            config.users.min_uid,
+            config.users.max_uid,
+        );
+        Self::with_system(config, sessions, users, demo_mode)
+    }
 
+    /// Build the app from already discovered sessions and users
+    pub fn with_system(
+        config: GreeterConfig,
+        sessions: Vec<Session>,
+        users: Vec<User>,
+        demo_mode: bool,
+    ) -> Self {
         info!(
             "Initialized app with {} sessions and {} users",
             sessions.len(),
             users.len()
         );
 
+        let state = if config.general.remember_last_user {
+            GreeterState::load(&config.general.state_file)
+        } else {
+            GreeterState::default()
+        };
+
+        // The remembered user wins over the configured default
+        let initial_user = state
+            .last_user
+            .clone()
+            .or_else(|| config.general.default_user.clone());
+
+        let mut username = InputBuffer::new();
+        let mut focus = FocusField::Username;
+        let mut selected_user = 0;
+        if let Some(ref initial_user) = initial_user {
+            username.set(initial_user);
+            focus = FocusField::Password;
+            if let Some(idx) = users.iter().position(|u| &u.username == initial_user) {
+                selected_user = idx;
+            }
+        }
+
+        let selected_session = initial_user
+            .as_deref()
+            .and_then(|user| state.session_for(user))
+            .and_then(|slug| find_session(&sessions, slug))
+            .or_else(|| {
+                config
+                    .general
+                    .default_session
+                    .as_deref()
+                    .and_then(|name| find_session(&sessions, name))
+            })
+            .unwrap_or(0);
+
         Self {
-            vim_mode: VimMode::Insert,
-            focus: FocusField::Username,
-            username: InputBuffer::new(),
+            vim_mode: config.general.initial_mode,
+            focus,
+            username,
             password: InputBuffer::masked(),
-            command_buffer: InputBuffer::new(),
+            command_line: CommandLine::new(),
             sessions,
-            selected_session: 0,
+            selected_session,
             users,
-            selected_user: 0,
+            selected_user,
             message: None,
             working: false,
             should_exit: false,
@@ -78,9 +170,20 @@ impl App {
             show_user_picker: false,
             show_help: false,
             confirm_action: None,
-            theme: Theme::default(),
+            theme: config.theme.build(),
+            catalog: Catalog::english(Domain::Greeter),
+            config,
+            state,
             demo_mode,
-            pending_dd: false,
+            hostname: hostname::get()
+                .map(|h| h.to_string_lossy().to_string())
+                .unwrap_or_else(|_| "hypercube".to_string()),
+            frozen_time: None,
+            auth_prompt: None,
+            auth_messages: Vec::new(),
+            password_sent: false,
+            auth_stage: AuthStage::Idle,
+            normal: NormalParser::new(),
         }
     }
 
@@ -120,96 +223,51 @@ impl App {
     }
 
     fn handle_normal_mode(&mut self, key: KeyEvent) -> Option<AppAction> {
+        // Counts, motions and operators on the focused field
+        match self.normal.feed(key) {
+            Parsed::Consumed => return None,
+            Parsed::Command(command) => {
+                command.apply(self.current_input_mut());
+                if command.enters_insert() {
+                    self.vim_mode = self.vim_mode.transition(ModeAction::EnterInsert);
+                }
+                return None;
+            }
+            Parsed::Unhandled => {}
+        }
+
         match key.code {
             // Mode transitions
-            KeyCode::Char('i') => {
-                self.vim_mode = self.vim_mode.transition(ModeAction::EnterInsert);
-                self.pending_dd = false;
-            }
-            KeyCode::Char('a') => {
-                self.vim_mode = self.vim_mode.transition(ModeAction::EnterInsert);
-                self.current_input_mut().move_right();
-                self.pending_dd = false;
-            }
-            KeyCode::Char('A') => {
-                self.vim_mode = self.vim_mode.transition(ModeAction::EnterInsert);
-                self.current_input_mut().move_end();
-                self.pending_dd = false;
-            }
-            KeyCode::Char('I') => {
-                self.vim_mode = self.vim_mode.transition(ModeAction::EnterInsert);
-                self.current_input_mut().move_start();
-                self.pending_dd = false;
-            }
             KeyCode::Char(':') => {
                 self.vim_mode = self.vim_mode.transition(ModeAction::EnterCommand);
-                self.command_buffer.clear();
-                self.pending_dd = false;
+                self.command_line.clear();
             }
 
             // Navigation
-            KeyCode::Char('h') | KeyCode::Left => {
-                self.current_input_mut().move_left();
-                self.pending_dd = false;
-            }
-            KeyCode::Char('l') | KeyCode::Right => {
-                self.current_input_mut().move_right();
-                self.pending_dd = false;
-            }
             KeyCode::Char('j') | KeyCode::Down | KeyCode::Tab => {
                 self.next_field();
-                self.pending_dd = false;
             }
             KeyCode::Char('k') | KeyCode::Up | KeyCode::BackTab => {
                 self.prev_field();
-                self.pending_dd = false;
-            }
-            KeyCode::Char('0') => {
-                self.current_input_mut().move_start();
-                self.pending_dd = false;
-            }
-            KeyCode::Char('$') => {
-                self.current_input_mut().move_end();
-                self.pending_dd = false;
-            }
-
-            // Editing
-            KeyCode::Char('x') => {
-                self.current_input_mut().delete_forward();
-                self.pending_dd = false;
-            }
-            KeyCode::Char('d') => {
-                if self.pending_dd {
-                    self.current_input_mut().clear();
-                    self.pending_dd = false;
-                } else {
-                    self.pending_dd = true;
-                }
             }
 
             // Actions
             KeyCode::Enter => {
-                self.pending_dd = false;
-                return Some(AppAction::Login);
+                return Some(self.submit_action());
             }
 
             // Function keys
             KeyCode::F(2) => {
                 self.show_user_picker = true;
-                self.pending_dd = false;
             }
             KeyCode::F(3) => {
                 self.show_session_picker = true;
-                self.pending_dd = false;
             }
             KeyCode::F(12) => {
                 self.confirm_action = Some(ConfirmAction::Poweroff);
-                self.pending_dd = false;
             }
 
-            _ => {
-                self.pending_dd = false;
-            }
+            _ => {}
         }
         None
     }
@@ -226,6 +284,8 @@ impl App {
                 } else if self.focus == FocusField::Password {
                     self.vim_mode = VimMode::Normal;
                     return Some(AppAction::Login);
+                } else if self.focus == FocusField::Prompt {
+                    return Some(AppAction::Respond);
                 }
             }
             KeyCode::Tab => {
@@ -255,6 +315,10 @@ impl App {
             KeyCode::Char(c) => {
                 // Handle Ctrl shortcuts
                 if key.modifiers.contains(KeyModifiers::CONTROL) {
+                    // Line and word deletes are their own undo steps
+                    if matches!(c, 'u' | 'w') {
+                        self.current_input_mut().checkpoint();
+                    }
                     match c {
                         'u' => self.current_input_mut().clear(),
                         'a' => self.current_input_mut().move_start(),
@@ -267,9 +331,7 @@ impl App {
                                 let cursor = input.cursor();
                                 if cursor > 0 {
                                     let prev_char = content.chars().nth(cursor - 1);
-                                    if prev_char.is_some_and(|c| c.is_whitespace()) && cursor > 1 {
-                                        input.delete_back();
-                                    } else if prev_char.is_some_and(|c| !c.is_whitespace()) {
+                                    if prev_char.is_some_and(|c| !c.is_whitespace() || cursor > 1) {
                                         input.delete_back();
                                     } else {
                                         break;
@@ -281,6 +343,9 @@ impl App {
                         }
                         _ => {}
                     }
+                    if matches!(c, 'u' | 'w') {
+                        self.current_input_mut().checkpoint();
+                    }
                 } else {
                     self.current_input_mut().insert(c);
                 }
@@ -294,23 +359,36 @@ impl App {
         match key.code {
             KeyCode::Esc => {
                 self.vim_mode = self.vim_mode.transition(ModeAction::Escape);
-                self.command_buffer.clear();
+                self.command_line.clear();
             }
             KeyCode::Enter => {
-                let cmd = self.command_buffer.content().to_string();
+                let cmd = self.command_line.submit();
                 self.vim_mode = self.vim_mode.transition(ModeAction::Execute);
-                self.command_buffer.clear();
                 return self.execute_command(&cmd);
             }
             KeyCode::Backspace => {
-                if self.command_buffer.is_empty() {
+                if self.command_line.is_empty() {
                     self.vim_mode = self.vim_mode.transition(ModeAction::Escape);
                 } else {
-                    self.command_buffer.delete_back();
+                    self.command_line.delete_back();
                 }
             }
+            KeyCode::Up => {
+                self.command_line.history_prev();
+            }
+            KeyCode::Down => {
+                self.command_line.history_next();
+            }
+            KeyCode::Tab | KeyCode::BackTab => {
+                let (sessions, users) = (&self.sessions, &self.users);
+                self.command_line.complete(
+                    &command_names(COMMANDS),
+                    |cmd| argument_candidates(cmd, sessions, users),
+                    key.code == KeyCode::Tab,
+                );
+            }
             KeyCode::Char(c) => {
-                self.command_buffer.insert(c);
+                self.command_line.insert(c);
             }
             _ => {}
         }
@@ -347,7 +425,9 @@ impl App {
                     self.show_session_picker = false;
                 } else {
                     if let Some(user) = self.users.get(self.selected_user) {
-                        self.username.set(&user.username);
+                        let username = user.username.clone();
+                        self.username.set(&username);
+                        self.select_remembered_session(&username);
                     }
                     self.show_user_picker = false;
                 }
@@ -384,13 +464,10 @@ impl App {
             }
             Ok(Command::Session(name)) => {
                 if let Some(name) = name {
-                    if let Some(idx) = self.sessions.iter().position(|s| {
-                        s.name.to_lowercase().contains(&name.to_lowercase())
-                            || s.slug.to_lowercase() == name.to_lowercase()
-                    }) {
+                    if let Some(idx) = find_session(&self.sessions, &name) {
                         self.selected_session = idx;
                     } else {
-                        self.set_error(format!("Session not found: {}", name));
+                        self.set_error(self.catalog.tr_args("error-session-not-found", &[("name", name.as_str().into())]));
                     }
                 } else {
                     self.show_session_picker = true;
@@ -401,17 +478,19 @@ impl App {
                     if let Some(idx) = self.users.iter().position(|u| {
                         u.username.to_lowercase() == name.to_lowercase()
                     }) {
+                        let username = self.users[idx].username.clone();
                         self.selected_user = idx;
-                        self.username.set(&self.users[idx].username.clone());
+                        self.username.set(&username);
+                        self.select_remembered_session(&username);
                     } else {
-                        self.set_error(format!("User not found: {}", name));
+                        self.set_error(self.catalog.tr_args("error-user-not-found", &[("name", name.as_str().into())]));
                     }
                 } else {
                     self.show_user_picker = true;
                 }
             }
             Ok(Command::Login) | Ok(Command::Quit) => {
-                return Some(AppAction::Login);
+                return Some(self.submit_action());
             }
             Ok(Command::Cancel) => {
                 return Some(AppAction::Cancel);
@@ -426,10 +505,34 @@ impl App {
         None
     }
 
+    /// Switch to the session the user last logged into, if known
+    fn select_remembered_session(&mut self, username: &str) {
+        if let Some(idx) = self
+            .state
+            .session_for(username)
+            .and_then(|slug| find_session(&self.sessions, slug))
+        {
+            self.selected_session = idx;
+        }
+    }
+
+    /// Login starts a new conversation; with a prompt pending, Enter answers it instead
+    fn submit_action(&self) -> AppAction {
+        if self.auth_prompt.is_some() {
+            AppAction::Respond
+        } else {
+            AppAction::Login
+        }
+    }
+
     fn current_input_mut(&mut self) -> &mut InputBuffer {
         match self.focus {
             FocusField::Username => &mut self.username,
             FocusField::Password => &mut self.password,
+            FocusField::Prompt => match self.auth_prompt.as_mut() {
+                Some(prompt) => &mut prompt.input,
+                None => &mut self.password,
+            },
         }
     }
 
@@ -437,6 +540,8 @@ impl App {
         self.focus = match self.focus {
             FocusField::Username => FocusField::Password,
             FocusField::Password => FocusField::Username,
+            // The prompt keeps focus until it is answered or cancelled
+            FocusField::Prompt => FocusField::Prompt,
         };
     }
 
@@ -444,9 +549,16 @@ impl App {
         self.focus = match self.focus {
             FocusField::Username => FocusField::Password,
             FocusField::Password => FocusField::Username,
+            FocusField::Prompt => FocusField::Prompt,
         };
     }
 
+    /// Current local time, unless frozen
+    pub fn now(&self) -> NaiveDateTime {
+        self.frozen_time
+            .unwrap_or_else(|| Local::now().naive_local())
+    }
+
     pub fn set_error(&mut self, text: String) {
         self.message = Some(Message {
             text,
@@ -461,84 +573,195 @@ impl App {
         });
     }
 
-    pub async fn login(&mut self, client: &mut GreetdClient) {
+    /// Start a PAM conversation for the entered username
+    pub fn login(&mut self) -> Option<AuthRequest> {
         if self.username.is_empty() {
-            self.set_error("Username is required".to_string());
-            return;
+            self.set_error(self.catalog.tr("error-username-required"));
+            return None;
         }
 
+        self.reset_auth();
         self.working = true;
         self.message = None;
 
         let username = self.username.content().to_string();
         debug!("Creating session for user: {}", username);
 
-        match client.create_session(&username).await {
-            Ok(AuthResponse::PromptSecret(_)) => {
-                // Password prompt expected, send password
-                let password = self.password.content().to_string();
-                match client.post_auth_response(Some(password)).await {
-                    Ok(AuthResponse::Success) => {
-                        info!("Authentication successful");
-                        self.start_session(client).await;
-                    }
-                    Ok(AuthResponse::Error(msg)) => {
-                        warn!("Authentication failed: {}", msg);
-                        self.working = false;
-                        self.set_error(msg);
-                        self.password.clear();
-                        let _ = client.cancel_session().await;
-                    }
-                    Ok(AuthResponse::PromptSecret(prompt)) => {
-                        // MFA or additional prompt
-                        self.working = false;
-                        self.set_info(prompt);
-                    }
-                    Ok(other) => {
-                        warn!("Unexpected auth response: {:?}", other);
-                        self.working = false;
-                        self.set_error("Unexpected authentication response".to_string());
-                        let _ = client.cancel_session().await;
-                    }
-                    Err(e) => {
-                        error!("Auth error: {}", e);
-                        self.working = false;
-                        self.set_error(e.to_string());
-                        let _ = client.cancel_session().await;
-                    }
-                }
-            }
-            Ok(AuthResponse::Success) => {
-                // No password required
-                info!("Authentication successful (no password)");
-                self.start_session(client).await;
-            }
-            Ok(AuthResponse::Error(msg)) => {
-                warn!("Session creation failed: {}", msg);
-                self.working = false;
-                self.set_error(msg);
-            }
-            Ok(other) => {
-                warn!("Unexpected response: {:?}", other);
-                self.working = false;
-                self.set_error("Unexpected response from greetd".to_string());
-            }
+        self.auth_stage = AuthStage::Authenticating;
+        Some(AuthRequest::CreateSession(username))
+    }
+
+    /// Send the answer for the pending PAM prompt and continue the conversation
+    pub fn respond(&mut self) -> Option<AuthRequest> {
+        let prompt = self.auth_prompt.take()?;
+
+        self.working = true;
+        self.message = None;
+
+        let answer = prompt.input.content().to_string();
+        Some(AuthRequest::PostResponse(Some(answer)))
+    }
+
+    /// Abort the current PAM conversation
+    pub fn cancel(&mut self) -> Option<AuthRequest> {
+        self.password.clear();
+        self.reset_auth();
+        self.working = false;
+        self.auth_stage = AuthStage::Cancelling;
+        Some(AuthRequest::CancelSession)
+    }
+
+    /// Forget a request that was abandoned while greetd was still working on it
+    ///
+    /// The connection it was using is dropped, which makes greetd discard the session.
+    pub fn abort_auth(&mut self) {
+        info!("Aborting in-flight greetd request");
+        self.working = false;
+        self.password.clear();
+        self.reset_auth();
+        self.auth_stage = AuthStage::Idle;
+        if self.message.is_none() {
+            self.set_info(self.catalog.tr("message-login-cancelled"));
+        }
+    }
+
+    /// Handle greetd's answer to the last request, returning the next one to send
+    pub fn handle_auth_response(&mut self, response: Result<AuthResponse>) -> Option<AuthRequest> {
+        let stage = std::mem::replace(&mut self.auth_stage, AuthStage::Idle);
+
+        let response = match response {
+            Ok(response) => response,
             Err(e) => {
-                error!("IPC error: {}", e);
-                self.working = false;
-                self.set_error(e.to_string());
+                error!("greetd request failed: {}", e);
+                if stage == AuthStage::Cancelling {
+                    return None;
+                }
+                return self.fail_auth(e.to_string());
             }
+        };
+
+        match stage {
+            AuthStage::Idle | AuthStage::Cancelling => None,
+            AuthStage::Authenticating => self.continue_auth(response),
+            AuthStage::StartingSession => match response {
+                AuthResponse::Success => {
+                    if let Some(slug) = self.sessions.get(self.selected_session).map(|s| s.slug.clone()) {
+                        self.remember_login(&slug);
+                    }
+                    self.should_exit = true;
+                    self.exit_success = true;
+                    None
+                }
+                other => {
+                    error!("Unexpected response to session start: {:?}", other);
+                    self.fail_auth(self.catalog.tr("error-session-start"))
+                }
+            },
         }
     }
 
-    async fn start_session(&mut self, client: &mut GreetdClient) {
-        let session = match self.sessions.get(self.selected_session) {
-            Some(s) => s,
-            None => {
-                self.set_error("No session selected".to_string());
-                self.working = false;
-                return;
+    /// Advance the PAM conversation by one message
+    fn continue_auth(&mut self, response: AuthResponse) -> Option<AuthRequest> {
+        self.auth_stage = AuthStage::Authenticating;
+
+        match response {
+            AuthResponse::PromptSecret(_) if !self.password_sent && !self.password.is_empty() => {
+                // The first secret prompt is answered from the login form
+                self.password_sent = true;
+                let password = self.password.content().to_string();
+                Some(AuthRequest::PostResponse(Some(password)))
+            }
+            AuthResponse::PromptSecret(text) => {
+                self.show_prompt(text, true);
+                None
+            }
+            AuthResponse::PromptVisible(text) => {
+                self.show_prompt(text, false);
+                None
+            }
+            AuthResponse::Info(text) => {
+                debug!("PAM info: {}", text);
+                self.auth_messages.push(Message {
+                    text,
+                    is_error: false,
+                });
+                Some(AuthRequest::PostResponse(None))
+            }
+            AuthResponse::ErrorMessage(text) => {
+                warn!("PAM error message: {}", text);
+                self.auth_messages.push(Message {
+                    text,
+                    is_error: true,
+                });
+                Some(AuthRequest::PostResponse(None))
+            }
+            AuthResponse::Success => {
+                info!("Authentication successful");
+                self.start_session()
+            }
+            AuthResponse::Error(msg) => {
+                warn!("Authentication failed: {}", msg);
+                self.fail_auth(msg)
             }
+        }
+    }
+
+    fn show_prompt(&mut self, text: String, secret: bool) {
+        debug!("PAM prompt (secret={}): {}", secret, text);
+        self.auth_prompt = Some(AuthPrompt {
+            text,
+            secret,
+            input: if secret {
+                InputBuffer::masked()
+            } else {
+                InputBuffer::new()
+            },
+        });
+        self.focus = FocusField::Prompt;
+        self.vim_mode = VimMode::Insert;
+        self.working = false;
+    }
+
+    fn fail_auth(&mut self, msg: String) -> Option<AuthRequest> {
+        self.working = false;
+        self.set_error(msg);
+        self.password.clear();
+        self.reset_auth();
+        self.auth_stage = AuthStage::Cancelling;
+        Some(AuthRequest::CancelSession)
+    }
+
+    fn reset_auth(&mut self) {
+        self.auth_prompt = None;
+        self.auth_messages.clear();
+        self.password_sent = false;
+        if self.focus == FocusField::Prompt {
+            self.focus = FocusField::Password;
+        }
+    }
+
+    /// Persist the user and session of a successful login
+    fn remember_login(&mut self, session_slug: &str) {
+        if !self.config.general.remember_last_user {
+            return;
+        }
+
+        let username = self.username.content().to_string();
+        self.state.remember(&username, session_slug);
+
+        if self.demo_mode {
+            info!("Demo mode: not saving greeter state");
+            return;
+        }
+
+        if let Err(e) = self.state.save(&self.config.general.state_file) {
+            warn!("Failed to save greeter state: {}", e);
+        }
+    }
+
+    fn start_session(&mut self) -> Option<AuthRequest> {
+        let Some(session) = self.sessions.get(self.selected_session) else {
+            return self.fail_auth(self.catalog.tr("error-no-session"));
         };
 
         let cmd = session.build_cmd();
@@ -546,23 +769,37 @@ impl App {
 
         info!("Starting session: {:?} with env: {:?}", cmd, env);
 
-        match client.start_session(cmd, env).await {
-            Ok(()) => {
-                self.should_exit = true;
-                self.exit_success = true;
-            }
-            Err(e) => {
-                error!("Failed to start session: {}", e);
-                self.set_error(e.to_string());
-                self.working = false;
-            }
-        }
+        self.auth_stage = AuthStage::StartingSession;
+        Some(AuthRequest::StartSession { cmd, env })
+    }
+}
+
+/// Tab completion source for command arguments: session slugs and names, usernames
+fn argument_candidates(cmd: &str, sessions: &[Session], users: &[User]) -> Vec<String> {
+    match parse_command(cmd) {
+        Ok(Command::Session(_)) => sessions
+            .iter()
+            .flat_map(|s| [s.slug.clone(), s.name.clone()])
+            .collect(),
+        Ok(Command::User(_)) => users.iter().map(|u| u.username.clone()).collect(),
+        _ => Vec::new(),
     }
 }
 
+/// Find a session by slug or (partial) display name, case-insensitively
+fn find_session(sessions: &[Session], name: &str) -> Option<usize> {
+    let name = name.to_lowercase();
+    sessions
+        .iter()
+        .position(|s| s.slug.to_lowercase() == name)
+        .or_else(|| sessions.iter().position(|s| s.name.to_lowercase().contains(&name)))
+}
+
 #[derive(Debug)]
 pub enum AppAction {
     Login,
+    /// Answer the pending PAM prompt
+    Respond,
     Cancel,
     Reboot,
     Poweroff,
@@ -574,8 +811,21 @@ impl std::fmt::Debug for AuthResponse {
             AuthResponse::PromptSecret(_) => write!(f, "PromptSecret(...)"),
             AuthResponse::PromptVisible(s) => write!(f, "PromptVisible({})", s),
             AuthResponse::Info(s) => write!(f, "Info({})", s),
+            AuthResponse::ErrorMessage(s) => write!(f, "ErrorMessage({})", s),
             AuthResponse::Error(s) => write!(f, "Error({})", s),
             AuthResponse::Success => write!(f, "Success"),
         }
     }
 }
+
+impl std::fmt::Debug for AuthRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthRequest::CreateSession(user) => write!(f, "CreateSession({})", user),
+            AuthRequest::PostResponse(Some(_)) => write!(f, "PostResponse(...)"),
+            AuthRequest::PostResponse(None) => write!(f, "PostResponse(None)"),
+            AuthRequest::StartSession { cmd, .. } => write!(f, "StartSession({:?})", cmd),
+            AuthRequest::CancelSession => write!(f, "CancelSession"),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use tracing::{debug, info, warn};
+
+/// State persisted between greeter runs
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GreeterState {
+    /// Last user who logged in successfully
+    pub last_user: Option<String>,
+    /// Session slug each user last logged into
+    pub sessions: BTreeMap<String, String>,
+}
+
+impl GreeterState {
+    /// Load state from disk, falling back to empty state on any error
+    pub fn load<P: AsRef<Path>>(path: P) -> Self {
+        let path = path.as_ref();
+
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) => {
+                debug!("No greeter state at {:?}: {}", path, e);
+                return Self::default();
+            }
+        };
+
+        match toml::from_str(&content) {
+            Ok(state) => state,
+            Err(e) => {
+                warn!("Ignoring corrupt greeter state at {:?}: {}", path, e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Write state to disk, replacing the previous file atomically
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        let path = path.as_ref();
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let content = toml::to_string(self).map_err(std::io::Error::other)?;
+        let tmp_path = path.with_extension("tmp");
+        std::fs::write(&tmp_path, content)?;
+        std::fs::rename(&tmp_path, path)?;
+
+        info!("Saved greeter state to {:?}", path);
+        Ok(())
+    }
+
+    /// Session slug the given user last logged into
+    pub fn session_for(&self, username: &str) -> Option<&str> {
+        self.sessions.get(username).map(|s| s.as_str())
+    }
+
+    /// Record a successful login
+    pub fn remember(&mut self, username: &str, session_slug: &str) {
+        self.last_user = Some(username.to_string());
+        self.sessions
+            .insert(username.to_string(), session_slug.to_string());
+    }
+}
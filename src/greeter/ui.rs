@@ -78,10 +78,10 @@ pub fn draw(frame: &mut Frame, app: &App) {
     }
 
     if app.show_help {
-        widgets::draw_help(frame, layout.content);
+        widgets::draw_help(frame, layout.content, &app.theme, &app.catalog);
     }
 
     if let Some(ref confirm) = app.confirm_action {
-        widgets::draw_confirm_dialog(frame, layout.content, confirm);
+        widgets::draw_confirm_dialog(frame, layout.content, confirm, &app.theme, &app.catalog);
     }
 }
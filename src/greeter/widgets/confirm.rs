@@ -1,23 +1,26 @@
 use crate::greeter::ConfirmAction;
 use crate::greeter::ui::Layout;
+use crate::i18n::Catalog;
+use crate::ui::Theme;
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
-pub fn draw_confirm_dialog(frame: &mut Frame, area: Rect, action: &ConfirmAction) {
+pub fn draw_confirm_dialog(frame: &mut Frame, area: Rect, action: &ConfirmAction, theme: &Theme, catalog: &Catalog) {
     let (title, message) = match action {
-        ConfirmAction::Reboot => ("Reboot", "Are you sure you want to reboot?"),
-        ConfirmAction::Poweroff => ("Shutdown", "Are you sure you want to shut down?"),
+        ConfirmAction::Reboot => ("confirm-reboot-title", "confirm-reboot"),
+        ConfirmAction::Poweroff => ("confirm-poweroff-title", "confirm-poweroff"),
     };
+    let (title, message) = (catalog.tr(title), catalog.tr(message));
 
     let text = vec![
         Line::from(""),
-        Line::from(Span::styled(message, Style::default().fg(Color::White))),
+        Line::from(Span::styled(message, theme.style())),
         Line::from(""),
         Line::from(vec![
-            Span::styled("  y", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
-            Span::raw(" - Yes    "),
-            Span::styled("n", Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)),
-            Span::raw(" - No"),
+            Span::styled("  y", theme.success_style().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(" - {}    ", catalog.tr("confirm-yes"))),
+            Span::styled("n", theme.error_style().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(" - {}", catalog.tr("confirm-no"))),
         ]),
     ];
 
@@ -25,13 +28,16 @@ pub fn draw_confirm_dialog(frame: &mut Frame, area: Rect, action: &ConfirmAction
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Yellow))
+        .border_style(theme.primary_style())
         .title(format!(" {} ", title))
-        .title_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+        .title_style(theme.primary_style().add_modifier(Modifier::BOLD));
 
     frame.render_widget(Clear, dialog_area);
     frame.render_widget(
-        Paragraph::new(text).block(block).alignment(Alignment::Center),
+        Paragraph::new(text)
+            .block(block)
+            .style(theme.style())
+            .alignment(Alignment::Center),
         dialog_area,
     );
 }
@@ -1,20 +1,33 @@
 use crate::greeter::App;
-use chrono::Local;
+use chrono::{Datelike, Weekday};
 use ratatui::prelude::*;
 use ratatui::widgets::Paragraph;
 
 pub fn draw_header(frame: &mut Frame, area: Rect, app: &App) {
-    let time = Local::now().format("%H:%M").to_string();
-    let date = Local::now().format("%A, %B %d").to_string();
-
-    let hostname = hostname::get()
-        .map(|h| h.to_string_lossy().to_string())
-        .unwrap_or_else(|_| "hypercube".to_string());
+    let now = app.now();
+    let time = now.format("%H:%M").to_string();
+    let weekday = match now.weekday() {
+        Weekday::Mon => "weekday-mon",
+        Weekday::Tue => "weekday-tue",
+        Weekday::Wed => "weekday-wed",
+        Weekday::Thu => "weekday-thu",
+        Weekday::Fri => "weekday-fri",
+        Weekday::Sat => "weekday-sat",
+        Weekday::Sun => "weekday-sun",
+    };
+    let date = app.catalog.tr_args(
+        "header-date",
+        &[
+            ("weekday", app.catalog.tr(weekday).into()),
+            ("month", app.catalog.tr(&format!("month-{}", now.month())).into()),
+            ("day", format!("{:02}", now.day()).into()),
+        ],
+    );
 
     // Left side: hostname
     let left = Paragraph::new(Line::from(vec![
         Span::raw(" "),
-        Span::styled(&hostname, app.theme.primary_style().add_modifier(Modifier::BOLD)),
+        Span::styled(&app.hostname, app.theme.primary_style().add_modifier(Modifier::BOLD)),
     ]));
     frame.render_widget(left, area);
 
@@ -1,47 +1,60 @@
 use crate::greeter::ui::Layout;
+use crate::i18n::Catalog;
+use crate::ui::Theme;
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
-pub fn draw_help(frame: &mut Frame, area: Rect) {
+pub fn draw_help(frame: &mut Frame, area: Rect, theme: &Theme, catalog: &Catalog) {
+    let heading = |id: &str| Line::from(Span::styled(catalog.tr(id), theme.primary_style().add_modifier(Modifier::BOLD)));
+    let key = |keys: &str, id: &str| Line::from(format!("  {keys:<9}{}", catalog.tr(id)));
+    let command = |command: &str, id: &str| Line::from(format!("  {command:<18}{}", catalog.tr(id)));
+
     let help_text = vec![
-        Line::from(Span::styled("Normal Mode", Style::default().add_modifier(Modifier::BOLD))),
-        Line::from("  h/l      Move cursor left/right"),
-        Line::from("  j/k      Move between fields"),
-        Line::from("  i        Enter insert mode"),
-        Line::from("  a        Enter insert mode (after cursor)"),
-        Line::from("  :        Enter command mode"),
-        Line::from("  x        Delete character"),
-        Line::from("  dd       Clear field"),
-        Line::from("  Enter    Login"),
+        heading("help-normal-mode"),
+        key("h/l", "help-cursor"),
+        key("w/b/e", "help-words"),
+        key("f/t{c}", "help-find"),
+        key("j/k", "help-fields"),
+        key("i/a/I/A", "help-insert"),
+        key(":", "help-command"),
+        key("x/D/dd", "help-delete"),
+        key("d/c{m}", "help-operators"),
+        key("r/~", "help-replace"),
+        key("u/Ctrl+r", "help-undo"),
+        key("Enter", "help-login"),
         Line::from(""),
-        Line::from(Span::styled("Insert Mode", Style::default().add_modifier(Modifier::BOLD))),
-        Line::from("  Escape   Return to normal mode"),
-        Line::from("  Enter    Submit / next field"),
+        heading("help-insert-mode"),
+        key("Escape", "help-escape"),
+        key("Enter", "help-submit"),
         Line::from(""),
-        Line::from(Span::styled("Commands", Style::default().add_modifier(Modifier::BOLD))),
-        Line::from("  :session [name]   Select session"),
-        Line::from("  :user [name]      Select user"),
-        Line::from("  :reboot           Reboot system"),
-        Line::from("  :poweroff         Shutdown system"),
-        Line::from("  :help             Show this help"),
-        Line::from("  :q                Login / quit"),
+        heading("help-commands"),
+        command(":session [name]", "help-cmd-session"),
+        command(":user [name]", "help-cmd-user"),
+        command(":cancel", "help-cmd-cancel"),
+        command(":reboot", "help-cmd-reboot"),
+        command(":poweroff", "help-cmd-poweroff"),
+        command(":help", "help-cmd-help"),
+        command(":q", "help-cmd-quit"),
+        command("Tab / Up / Down", "help-cmd-complete"),
         Line::from(""),
-        Line::from(Span::styled("Press Escape to close", Style::default().fg(Color::DarkGray))),
+        Line::from(Span::styled(catalog.tr("help-close"), theme.muted_style())),
     ];
 
+    // Wide enough for the longest line, which depends on the language
+    let longest = help_text.iter().map(Line::width).max().unwrap_or(0) as u16;
     let height = help_text.len() as u16 + 2;
-    let width = 45u16.min(area.width.saturating_sub(4));
+    let width = (longest + 4).max(45).min(area.width.saturating_sub(4));
     let help_area = Layout::centered_box(area, width, height);
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray))
-        .title(" Help ")
-        .title_style(Style::default().fg(Color::Yellow));
+        .border_style(theme.border_style())
+        .title(format!(" {} ", catalog.tr("help-title")))
+        .title_style(theme.primary_style());
 
     frame.render_widget(Clear, help_area);
     frame.render_widget(
-        Paragraph::new(help_text).block(block),
+        Paragraph::new(help_text).block(block).style(theme.style()),
         help_area,
     );
 }
@@ -5,44 +5,55 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
 pub fn draw_login_form(frame: &mut Frame, area: Rect, app: &App) {
+    // PAM messages and the pending prompt are shown below the password field
+    let conversation_rows = if app.auth_messages.is_empty() && app.auth_prompt.is_none() {
+        0
+    } else {
+        1 + app.auth_messages.len() as u16 + if app.auth_prompt.is_some() { 2 } else { 0 }
+    };
+
     let form_width = 50u16.min(area.width.saturating_sub(4));
-    let form_height = 12u16;
+    let form_height = 12u16 + conversation_rows;
     let form_area = Layout::centered_box(area, form_width, form_height);
 
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(app.theme.border_style())
-        .title(" Login ")
+        .title(format!(" {} ", app.catalog.tr("login-title")))
         .title_style(app.theme.primary_style());
 
     frame.render_widget(Clear, form_area);
     frame.render_widget(block, form_area);
 
+    // Greeting sits on the line above the form
+    let greeting = &app.config.general.greeting;
+    if !greeting.is_empty() && form_area.y > area.y {
+        let greeting_area = Rect::new(area.x, form_area.y - 1, area.width, 1);
+        frame.render_widget(
+            Paragraph::new(greeting.as_str())
+                .style(app.theme.primary_style().add_modifier(Modifier::BOLD))
+                .alignment(Alignment::Center),
+            greeting_area,
+        );
+    }
+
     let inner = form_area.inner(Margin::new(2, 1));
 
+    // Session, spacer, username label/input, spacer, password label/input,
+    // conversation rows, spacer, hint
     let chunks = ratatui::layout::Layout::default()
         .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(1), // Session
-            Constraint::Length(1), // Spacer
-            Constraint::Length(1), // Username label
-            Constraint::Length(1), // Username input
-            Constraint::Length(1), // Spacer
-            Constraint::Length(1), // Password label
-            Constraint::Length(1), // Password input
-            Constraint::Length(1), // Spacer
-            Constraint::Length(1), // Hint
-        ])
+        .constraints(vec![Constraint::Length(1); 9 + conversation_rows as usize])
         .split(inner);
 
     // Session indicator
     let session_name = app
         .sessions
         .get(app.selected_session)
-        .map(|s| s.name.as_str())
-        .unwrap_or("(no session)");
+        .map(|s| s.name.clone())
+        .unwrap_or_else(|| app.catalog.tr("login-no-session"));
     let session_line = Line::from(vec![
-        Span::styled("Session: ", app.theme.muted_style()),
+        Span::styled(format!("{} ", app.catalog.tr("login-session")), app.theme.muted_style()),
         Span::styled(session_name, app.theme.secondary_style()),
         Span::styled(" (F3)", app.theme.muted_style()),
     ]);
@@ -57,9 +68,9 @@ pub fn draw_login_form(frame: &mut Frame, area: Rect, app: &App) {
     };
 
     let username_label = Line::from(vec![
-        Span::styled("Username", username_style),
+        Span::styled(app.catalog.tr("login-username"), username_style),
         if username_focused {
-            Span::styled(" (i to edit)", app.theme.muted_style())
+            Span::styled(format!(" {}", app.catalog.tr("login-edit-hint")), app.theme.muted_style())
         } else {
             Span::raw("")
         },
@@ -84,9 +95,9 @@ pub fn draw_login_form(frame: &mut Frame, area: Rect, app: &App) {
     };
 
     let password_label = Line::from(vec![
-        Span::styled("Password", password_style),
+        Span::styled(app.catalog.tr("login-password"), password_style),
         if password_focused {
-            Span::styled(" (i to edit)", app.theme.muted_style())
+            Span::styled(format!(" {}", app.catalog.tr("login-edit-hint")), app.theme.muted_style())
         } else {
             Span::raw("")
         },
@@ -103,13 +114,51 @@ pub fn draw_login_form(frame: &mut Frame, area: Rect, app: &App) {
     );
     frame.render_widget(Paragraph::new(password_content), chunks[6]);
 
+    // PAM conversation: info/error messages followed by the pending prompt
+    let mut row = 8;
+    for message in &app.auth_messages {
+        let style = if message.is_error {
+            app.theme.error_style()
+        } else {
+            app.theme.secondary_style()
+        };
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(message.text.as_str(), style))),
+            chunks[row],
+        );
+        row += 1;
+    }
+
+    if let Some(ref prompt) = app.auth_prompt {
+        let prompt_focused = app.focus == FocusField::Prompt;
+        let prompt_style = if prompt_focused {
+            app.theme.primary_style()
+        } else {
+            app.theme.muted_style()
+        };
+        let label = Line::from(Span::styled(prompt.text.trim(), prompt_style));
+        frame.render_widget(Paragraph::new(label), chunks[row]);
+
+        let prompt_display = prompt.input.display('*');
+        let prompt_content = render_input_field(
+            &prompt_display,
+            prompt.input.cursor(),
+            prompt_focused,
+            app.vim_mode == VimMode::Insert,
+            &app.theme,
+        );
+        frame.render_widget(Paragraph::new(prompt_content), chunks[row + 1]);
+    }
+
     // Hint line (only show when no message panel is visible)
     if app.message.is_none() && !app.working {
-        let hint = Line::from(Span::styled(
-            "Press Enter to login, :help for commands",
-            app.theme.muted_style(),
-        ));
-        frame.render_widget(Paragraph::new(hint), chunks[8]);
+        let hint_text = if app.auth_prompt.is_some() {
+            app.catalog.tr("login-hint-prompt")
+        } else {
+            app.catalog.tr("login-hint")
+        };
+        let hint = Line::from(Span::styled(hint_text, app.theme.muted_style()));
+        frame.render_widget(Paragraph::new(hint), chunks[chunks.len() - 1]);
     }
 }
 
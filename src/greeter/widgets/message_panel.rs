@@ -4,20 +4,20 @@ use ratatui::widgets::{Block, Borders, Paragraph};
 
 pub fn draw_message_panel(frame: &mut Frame, area: Rect, app: &App) {
     let (text, is_error) = match &app.message {
-        Some(m) => (m.text.as_str(), m.is_error),
-        None if app.working => ("Authenticating...", false),
+        Some(m) => (m.text.clone(), m.is_error),
+        None if app.working => (app.catalog.tr("message-authenticating"), false),
         None => return,
     };
 
     let (title, border_style, text_style) = if is_error {
         (
-            " Error ",
+            app.catalog.tr("message-error"),
             app.theme.error_style(),
             app.theme.error_style(),
         )
     } else {
         (
-            " Info ",
+            app.catalog.tr("message-info"),
             app.theme.secondary_style(),
             app.theme.style(),
         )
@@ -26,13 +26,15 @@ pub fn draw_message_panel(frame: &mut Frame, area: Rect, app: &App) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(border_style)
-        .title(title)
+        .title(format!(" {title} "))
         .title_style(border_style.add_modifier(Modifier::BOLD));
 
-    let content = Line::from(vec![
-        Span::styled(text, text_style),
-        Span::styled(" (press any key to dismiss)", app.theme.muted_style()),
-    ]);
+    let mut spans = vec![Span::styled(text, text_style)];
+    // Keys don't dismiss anything while greetd is still working
+    if !app.working {
+        spans.push(Span::styled(format!(" {}", app.catalog.tr("message-dismiss")), app.theme.muted_style()));
+    }
+    let content = Line::from(spans);
 
     let paragraph = Paragraph::new(content)
         .block(block)
@@ -30,7 +30,7 @@ pub fn draw_session_picker(frame: &mut Frame, area: Rect, app: &App) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(app.theme.border_style())
-        .title(" Sessions (j/k to select, Enter to confirm) ")
+        .title(format!(" {} ", app.catalog.tr("picker-sessions")))
         .title_style(app.theme.primary_style());
 
     frame.render_widget(Clear, picker_area);
@@ -69,7 +69,7 @@ pub fn draw_user_picker(frame: &mut Frame, area: Rect, app: &App) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(app.theme.border_style())
-        .title(" Users (j/k to select, Enter to confirm) ")
+        .title(format!(" {} ", app.catalog.tr("picker-users")))
         .title_style(app.theme.primary_style());
 
     frame.render_widget(Clear, picker_area);
@@ -1,4 +1,5 @@
 use crate::greeter::App;
+use crate::ui::wildmenu_line;
 use crate::vim::VimMode;
 use ratatui::prelude::*;
 use ratatui::widgets::Paragraph;
@@ -16,7 +17,7 @@ pub fn draw_status_bar(frame: &mut Frame, area: Rect, app: &App) {
     // Show command buffer in command mode
     let cmd_content;
     if app.vim_mode == VimMode::Command {
-        cmd_content = app.command_buffer.content().to_string();
+        cmd_content = app.command_line.content().to_string();
         left_spans.push(Span::styled(":", app.theme.primary_style()));
         left_spans.push(Span::raw(cmd_content.as_str()));
         left_spans.push(Span::styled("│", app.theme.primary_style()));
@@ -26,7 +27,7 @@ pub fn draw_status_bar(frame: &mut Frame, area: Rect, app: &App) {
 
     // Right side: keybinding hints
     let demo_indicator = if app.demo_mode {
-        Span::styled(" [DEMO] ", app.theme.error_style())
+        Span::styled(format!(" [{}] ", app.catalog.tr("status-demo")), app.theme.error_style())
     } else {
         Span::raw("")
     };
@@ -34,20 +35,26 @@ pub fn draw_status_bar(frame: &mut Frame, area: Rect, app: &App) {
     let hints = if app.vim_mode == VimMode::Normal {
         vec![
             Span::styled("F2", app.theme.secondary_style()),
-            Span::styled(":users ", app.theme.muted_style()),
+            Span::styled(format!(":{} ", app.catalog.tr("status-users")), app.theme.muted_style()),
             Span::styled("F3", app.theme.secondary_style()),
-            Span::styled(":sessions ", app.theme.muted_style()),
+            Span::styled(format!(":{} ", app.catalog.tr("status-sessions")), app.theme.muted_style()),
             Span::styled("F12", app.theme.secondary_style()),
-            Span::styled(":power ", app.theme.muted_style()),
+            Span::styled(format!(":{} ", app.catalog.tr("status-power")), app.theme.muted_style()),
         ]
     } else {
         vec![]
     };
 
-    let right_spans: Vec<Span> = std::iter::once(demo_indicator)
-        .chain(hints.into_iter())
-        .collect();
-    let right = Line::from(right_spans);
+    let right = match app.command_line.completion() {
+        Some(completion) if app.vim_mode == VimMode::Command => {
+            wildmenu_line(completion, &app.theme, area.width as usize / 2)
+        }
+        _ => Line::from(
+            std::iter::once(demo_indicator)
+                .chain(hints)
+                .collect::<Vec<Span>>(),
+        ),
+    };
 
     // Render left-aligned
     frame.render_widget(
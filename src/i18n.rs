@@ -0,0 +1,141 @@
+//! Translated UI text: Fluent catalogs embedded at build time, picked by locale
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use std::fmt;
+use std::sync::Arc;
+use tracing::{debug, warn};
+use unic_langid::LanguageIdentifier;
+
+pub use fluent_bundle::FluentValue;
+
+/// Which binary's messages a catalog holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Domain {
+    Greeter,
+    Onboard,
+}
+
+/// Languages with catalogs and their `(greeter, onboard)` sources; English comes
+/// first and fills in any message another language lacks
+const CATALOGS: &[(&str, &str, &str)] = &[
+    ("en", include_str!("../locales/en/greeter.ftl"), include_str!("../locales/en/onboard.ftl")),
+    ("de", include_str!("../locales/de/greeter.ftl"), include_str!("../locales/de/onboard.ftl")),
+    ("fr", include_str!("../locales/fr/greeter.ftl"), include_str!("../locales/fr/onboard.ftl")),
+];
+
+/// Languages that have a catalog, as language subtags
+pub fn languages() -> impl Iterator<Item = &'static str> {
+    CATALOGS.iter().map(|(language, _, _)| *language)
+}
+
+/// Messages of one domain in one language, falling back to English
+#[derive(Clone)]
+pub struct Catalog {
+    language: &'static str,
+    /// The language's bundle, then English unless that is the language
+    bundles: Arc<Vec<FluentBundle<FluentResource>>>,
+}
+
+impl Catalog {
+    pub fn english(domain: Domain) -> Self {
+        Self::for_language(domain, "en")
+    }
+
+    /// Catalog for a POSIX locale such as `de_DE.UTF-8`; English when there is none
+    pub fn for_locale(domain: Domain, locale: &str) -> Self {
+        let language = language_of(locale)
+            .and_then(|id| languages().find(|language| *language == id.language.as_str()))
+            .unwrap_or("en");
+        Self::for_language(domain, language)
+    }
+
+    /// Catalog for the locale in `LC_ALL`, `LC_MESSAGES` or `LANG`, whichever is set first
+    pub fn from_env(domain: Domain) -> Self {
+        let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .unwrap_or_default();
+        Self::for_locale(domain, &locale)
+    }
+
+    fn for_language(domain: Domain, language: &'static str) -> Self {
+        let mut bundles = vec![bundle(domain, language)];
+        if language != "en" {
+            bundles.push(bundle(domain, "en"));
+        }
+        Self { language, bundles: Arc::new(bundles) }
+    }
+
+    /// Language subtag of the catalog, e.g. `de`
+    pub fn language(&self) -> &'static str {
+        self.language
+    }
+
+    /// The message `id`, or the id itself when no catalog has it
+    pub fn tr(&self, id: &str) -> String {
+        self.format(id, None)
+    }
+
+    /// The message `id` with its `{ $name }` placeables filled from `args`
+    pub fn tr_args(&self, id: &str, args: &[(&str, FluentValue<'_>)]) -> String {
+        let mut fluent_args = FluentArgs::with_capacity(args.len());
+        for (name, value) in args {
+            fluent_args.set(*name, value.clone());
+        }
+        self.format(id, Some(&fluent_args))
+    }
+
+    fn format(&self, id: &str, args: Option<&FluentArgs>) -> String {
+        for bundle in self.bundles.iter() {
+            let Some(pattern) = bundle.get_message(id).and_then(|message| message.value()) else {
+                continue;
+            };
+            let mut errors = Vec::new();
+            let text = bundle.format_pattern(pattern, args, &mut errors);
+            if !errors.is_empty() {
+                debug!("Formatting {} ({}): {:?}", id, self.language, errors);
+            }
+            return text.into_owned();
+        }
+        warn!("No message {} in any catalog", id);
+        id.to_string()
+    }
+}
+
+impl fmt::Debug for Catalog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Catalog").field("language", &self.language).finish()
+    }
+}
+
+/// Language of a POSIX locale: `de_DE.UTF-8@euro` is `de-DE`; `C` and `POSIX` have none
+fn language_of(locale: &str) -> Option<LanguageIdentifier> {
+    let tag = locale.split(['.', '@']).next()?.replace('_', "-");
+    tag.parse().ok()
+}
+
+fn bundle(domain: Domain, language: &str) -> FluentBundle<FluentResource> {
+    let source = CATALOGS
+        .iter()
+        .find(|(name, _, _)| *name == language)
+        .map(|(_, greeter, onboard)| match domain {
+            Domain::Greeter => *greeter,
+            Domain::Onboard => *onboard,
+        })
+        .unwrap_or_default();
+
+    let id: LanguageIdentifier = language.parse().unwrap_or_default();
+    let mut bundle = FluentBundle::new_concurrent(vec![id]);
+    // Unicode isolation marks would show up as stray characters in a terminal
+    bundle.set_use_isolating(false);
+    let resource = FluentResource::try_new(source.to_string()).unwrap_or_else(|(resource, errors)| {
+        warn!("Errors in the {} catalog: {:?}", language, errors);
+        resource
+    });
+    if let Err(errors) = bundle.add_resource(resource) {
+        warn!("Errors in the {} catalog: {:?}", language, errors);
+    }
+    bundle
+}
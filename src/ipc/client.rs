@@ -1,31 +1,66 @@
+use super::codec::{read_frame, write_frame};
 use crate::error::{Result, HypercubeError};
-use greetd_ipc::{codec::SyncCodec, AuthMessageType, ErrorType, Request, Response};
-use std::os::unix::net::UnixStream;
-use tracing::{debug, error, info};
+use greetd_ipc::{AuthMessageType, ErrorType, Request, Response};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::net::UnixStream;
+use tracing::{debug, error, info, warn};
+
+/// Default time to wait for greetd to answer a single request
+///
+/// A request can wait on a PAM module (a fingerprint reader waiting for a finger), so the
+/// timeout has to be longer than the slowest module takes: hitting it drops the connection
+/// and the greetd session with it.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
 
 pub enum AuthResponse {
+    /// PAM asks for hidden input (password, OTP)
     PromptSecret(String),
+    /// PAM asks for visible input
     PromptVisible(String),
+    /// Informational PAM message, acknowledged with an empty response
     Info(String),
+    /// Non-fatal PAM error message, acknowledged with an empty response
+    ErrorMessage(String),
+    /// Authentication failed or greetd rejected the request
     Error(String),
     Success,
 }
 
+/// A single step of the greetd conversation, sent with [`GreetdClient::request`]
+pub enum AuthRequest {
+    CreateSession(String),
+    /// Answer to the last auth message (`None` acknowledges info/error messages)
+    PostResponse(Option<String>),
+    StartSession { cmd: Vec<String>, env: Vec<String> },
+    CancelSession,
+}
+
 pub struct GreetdClient {
     stream: Option<UnixStream>,
+    socket_path: PathBuf,
+    timeout: Duration,
     demo_mode: bool,
 }
 
 impl GreetdClient {
-    pub async fn connect() -> Result<Self> {
+    pub async fn connect(timeout: Duration) -> Result<Self> {
         let socket_path =
             std::env::var("GREETD_SOCK").map_err(|_| HypercubeError::SocketNotFound)?;
+        Self::connect_to(socket_path, timeout).await
+    }
+
+    /// Connect to a greetd socket at an explicit path
+    pub async fn connect_to<P: AsRef<Path>>(socket_path: P, timeout: Duration) -> Result<Self> {
+        let socket_path = socket_path.as_ref().to_path_buf();
 
-        info!("Connecting to greetd socket: {}", socket_path);
-        let stream = UnixStream::connect(&socket_path)?;
+        info!("Connecting to greetd socket: {:?}", socket_path);
+        let stream = UnixStream::connect(&socket_path).await?;
 
         Ok(Self {
             stream: Some(stream),
+            socket_path,
+            timeout,
             demo_mode: false,
         })
     }
@@ -34,18 +69,44 @@ impl GreetdClient {
         info!("Running in demo mode");
         Self {
             stream: None,
+            socket_path: PathBuf::new(),
+            timeout: DEFAULT_TIMEOUT,
             demo_mode: true,
         }
     }
 
+    /// Send one conversation step; a started or cancelled session reports `Success`
+    pub async fn request(&mut self, request: AuthRequest) -> Result<AuthResponse> {
+        match request {
+            AuthRequest::CreateSession(username) => self.create_session(&username).await,
+            AuthRequest::PostResponse(response) => self.post_auth_response(response).await,
+            AuthRequest::StartSession { cmd, env } => {
+                self.start_session(cmd, env).await?;
+                Ok(AuthResponse::Success)
+            }
+            AuthRequest::CancelSession => {
+                self.cancel_session().await?;
+                Ok(AuthResponse::Success)
+            }
+        }
+    }
+
     pub async fn create_session(&mut self, username: &str) -> Result<AuthResponse> {
         if self.demo_mode {
             return Ok(AuthResponse::PromptSecret("Password: ".to_string()));
         }
 
-        let response = self.send(Request::CreateSession {
-            username: username.to_string(),
-        })?;
+        // A new session can start on a fresh connection, unlike any other request
+        if self.stream.is_none() {
+            info!("Reconnecting to greetd socket: {:?}", self.socket_path);
+            self.stream = Some(UnixStream::connect(&self.socket_path).await?);
+        }
+
+        let response = self
+            .send(Request::CreateSession {
+                username: username.to_string(),
+            })
+            .await?;
 
         self.handle_response(response)
     }
@@ -60,7 +121,7 @@ impl GreetdClient {
             ));
         }
 
-        let resp = self.send(Request::PostAuthMessageResponse { response })?;
+        let resp = self.send(Request::PostAuthMessageResponse { response }).await?;
         self.handle_response(resp)
     }
 
@@ -73,7 +134,7 @@ impl GreetdClient {
             return Ok(());
         }
 
-        let response = self.send(Request::StartSession { cmd, env })?;
+        let response = self.send(Request::StartSession { cmd, env }).await?;
 
         match response {
             Response::Success => Ok(()),
@@ -95,7 +156,7 @@ impl GreetdClient {
             return Ok(());
         }
 
-        let response = self.send(Request::CancelSession)?;
+        let response = self.send(Request::CancelSession).await?;
 
         match response {
             Response::Success => Ok(()),
@@ -104,18 +165,35 @@ impl GreetdClient {
         }
     }
 
-    fn send(&mut self, request: Request) -> Result<Response> {
+    async fn send(&mut self, request: Request) -> Result<Response> {
+        // A timed-out or failed request leaves the stream mid-message, so it was dropped.
+        // greetd ties the session to the connection, which makes it gone as well.
         let stream = self
             .stream
             .as_mut()
-            .ok_or(HypercubeError::SocketNotFound)?;
+            .ok_or(HypercubeError::ConnectionLost)?;
 
         debug!("Sending request: {:?}", request);
-        request.write_to(stream)?;
-
-        let response = Response::read_from(stream)?;
-        debug!("Received response: {:?}", response);
-        Ok(response)
+        let exchange = async {
+            write_frame(stream, &request).await?;
+            read_frame::<_, Response>(stream).await
+        };
+
+        match tokio::time::timeout(self.timeout, exchange).await {
+            Ok(Ok(response)) => {
+                debug!("Received response: {:?}", response);
+                Ok(response)
+            }
+            Ok(Err(e)) => {
+                self.stream = None;
+                Err(e)
+            }
+            Err(_) => {
+                warn!("greetd did not answer within {:?}", self.timeout);
+                self.stream = None;
+                Err(HypercubeError::Timeout(self.timeout))
+            }
+        }
     }
 
     fn handle_response(&self, response: Response) -> Result<AuthResponse> {
@@ -128,7 +206,7 @@ impl GreetdClient {
                 AuthMessageType::Secret => Ok(AuthResponse::PromptSecret(auth_message)),
                 AuthMessageType::Visible => Ok(AuthResponse::PromptVisible(auth_message)),
                 AuthMessageType::Info => Ok(AuthResponse::Info(auth_message)),
-                AuthMessageType::Error => Ok(AuthResponse::Error(auth_message)),
+                AuthMessageType::Error => Ok(AuthResponse::ErrorMessage(auth_message)),
             },
             Response::Error {
                 error_type,
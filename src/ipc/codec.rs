@@ -0,0 +1,42 @@
+use crate::error::{HypercubeError, Result};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// Upper bound for a single message; greetd messages are a few hundred bytes
+const MAX_FRAME_LEN: usize = 1024 * 1024;
+
+/// Write a message using greetd's framing: native-endian u32 length, then JSON
+pub async fn write_frame<W, T>(writer: &mut W, message: &T) -> Result<()>
+where
+    W: AsyncWrite + Unpin,
+    T: Serialize,
+{
+    let body = serde_json::to_vec(message)?;
+    let len = u32::try_from(body.len())
+        .map_err(|_| HypercubeError::Protocol("message too large".to_string()))?;
+
+    writer.write_all(&len.to_ne_bytes()).await?;
+    writer.write_all(&body).await?;
+    writer.flush().await?;
+    Ok(())
+}
+
+/// Read one length-prefixed JSON message
+pub async fn read_frame<R, T>(reader: &mut R) -> Result<T>
+where
+    R: AsyncRead + Unpin,
+    T: DeserializeOwned,
+{
+    let mut len_bytes = [0u8; 4];
+    reader.read_exact(&mut len_bytes).await?;
+
+    let len = u32::from_ne_bytes(len_bytes) as usize;
+    if len > MAX_FRAME_LEN {
+        return Err(HypercubeError::Protocol(format!("frame of {len} bytes exceeds limit")));
+    }
+
+    let mut body = vec![0u8; len];
+    reader.read_exact(&mut body).await?;
+    Ok(serde_json::from_slice(&body)?)
+}
@@ -0,0 +1,215 @@
+//! A scriptable stand-in for greetd, used by the integration tests and `--mock-greetd`
+
+use super::codec::{read_frame, write_frame};
+use crate::error::{HypercubeError, Result};
+use greetd_ipc::{AuthMessageType, ErrorType, Request, Response};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::net::{UnixListener, UnixStream};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+static SOCKET_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Conversation played back for every `create_session`
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct MockScript {
+    /// Usernames that may log in (empty accepts everyone)
+    pub users: Vec<String>,
+    /// Auth messages sent in order; the login succeeds after the last one
+    pub steps: Vec<MockStep>,
+    /// Make `start_session` fail with this description
+    pub start_session_error: Option<String>,
+}
+
+impl MockScript {
+    pub fn load_from<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| HypercubeError::Config(format!("{}: {}", path.display(), e)))?;
+        toml::from_str(&content)
+            .map_err(|e| HypercubeError::Config(format!("{}: {}", path.display(), e)))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MockStep {
+    pub kind: MockStepKind,
+    pub message: String,
+    /// Required answer; any other answer fails authentication
+    pub expect: Option<String>,
+    /// Wait this long before sending the message, to simulate slow PAM modules
+    #[serde(default)]
+    pub delay_ms: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MockStepKind {
+    Secret,
+    Visible,
+    Info,
+    Error,
+}
+
+impl From<MockStepKind> for AuthMessageType {
+    fn from(kind: MockStepKind) -> Self {
+        match kind {
+            MockStepKind::Secret => AuthMessageType::Secret,
+            MockStepKind::Visible => AuthMessageType::Visible,
+            MockStepKind::Info => AuthMessageType::Info,
+            MockStepKind::Error => AuthMessageType::Error,
+        }
+    }
+}
+
+/// A fake greetd daemon listening on a temporary Unix socket
+///
+/// The socket is removed and the server stopped when this is dropped.
+pub struct MockGreetd {
+    socket_path: PathBuf,
+    task: JoinHandle<()>,
+}
+
+impl MockGreetd {
+    pub fn start(script: MockScript) -> Result<Self> {
+        let socket_path = std::env::temp_dir().join(format!(
+            "hypercube-mock-greetd-{}-{}.sock",
+            std::process::id(),
+            SOCKET_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = std::fs::remove_file(&socket_path);
+
+        let listener = UnixListener::bind(&socket_path)?;
+        info!("Mock greetd listening on {:?}", socket_path);
+
+        let task = tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, _)) => {
+                        tokio::spawn(serve(stream, script.clone()));
+                    }
+                    Err(e) => {
+                        warn!("Mock greetd accept failed: {}", e);
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok(Self { socket_path, task })
+    }
+
+    pub fn socket_path(&self) -> &Path {
+        &self.socket_path
+    }
+}
+
+impl Drop for MockGreetd {
+    fn drop(&mut self) {
+        self.task.abort();
+        let _ = std::fs::remove_file(&self.socket_path);
+    }
+}
+
+/// Progress of the login on one connection
+enum Conversation {
+    Idle,
+    /// Waiting for the answer to `steps[n]`
+    Step(usize),
+    Authenticated,
+}
+
+async fn serve(mut stream: UnixStream, script: MockScript) {
+    let mut conversation = Conversation::Idle;
+
+    loop {
+        let request: Request = match read_frame(&mut stream).await {
+            Ok(request) => request,
+            Err(e) => {
+                debug!("Mock greetd client went away: {}", e);
+                return;
+            }
+        };
+        debug!("Mock greetd received {:?}", request);
+
+        let response = handle_request(&script, &mut conversation, request).await;
+        if let Err(e) = write_frame(&mut stream, &response).await {
+            debug!("Mock greetd failed to reply: {}", e);
+            return;
+        }
+    }
+}
+
+async fn handle_request(
+    script: &MockScript,
+    conversation: &mut Conversation,
+    request: Request,
+) -> Response {
+    match (request, &*conversation) {
+        (Request::CreateSession { username }, Conversation::Idle) => {
+            if !script.users.is_empty() && !script.users.contains(&username) {
+                return auth_error();
+            }
+            next_step(script, conversation, 0).await
+        }
+        (Request::CreateSession { .. }, _) => error("a session is already being created"),
+        (Request::PostAuthMessageResponse { response }, Conversation::Step(n)) => {
+            let n = *n;
+            if let Some(expected) = &script.steps[n].expect
+                && response.as_ref() != Some(expected)
+            {
+                *conversation = Conversation::Idle;
+                return auth_error();
+            }
+            next_step(script, conversation, n + 1).await
+        }
+        (Request::PostAuthMessageResponse { .. }, _) => error("no auth message to respond to"),
+        (Request::StartSession { .. }, Conversation::Authenticated) => {
+            *conversation = Conversation::Idle;
+            match &script.start_session_error {
+                Some(description) => error(description),
+                None => Response::Success,
+            }
+        }
+        (Request::StartSession { .. }, _) => error("session not authenticated"),
+        (Request::CancelSession, _) => {
+            *conversation = Conversation::Idle;
+            Response::Success
+        }
+    }
+}
+
+async fn next_step(script: &MockScript, conversation: &mut Conversation, n: usize) -> Response {
+    let Some(step) = script.steps.get(n) else {
+        *conversation = Conversation::Authenticated;
+        return Response::Success;
+    };
+
+    if step.delay_ms > 0 {
+        tokio::time::sleep(Duration::from_millis(step.delay_ms)).await;
+    }
+
+    *conversation = Conversation::Step(n);
+    Response::AuthMessage {
+        auth_message_type: step.kind.into(),
+        auth_message: step.message.clone(),
+    }
+}
+
+fn auth_error() -> Response {
+    Response::Error {
+        error_type: ErrorType::AuthError,
+        description: "pam_authenticate: AUTH_ERR".to_string(),
+    }
+}
+
+fn error(description: &str) -> Response {
+    Response::Error {
+        error_type: ErrorType::Error,
+        description: description.to_string(),
+    }
+}
@@ -1,3 +1,5 @@
 mod client;
+pub mod codec;
+pub mod mock;
 
-pub use client::{AuthResponse, GreetdClient};
+pub use client::{AuthRequest, AuthResponse, GreetdClient, DEFAULT_TIMEOUT};
@@ -0,0 +1,113 @@
+//! Config files layered from vendor defaults, the admin's file and drop-ins
+
+use serde::de::DeserializeOwned;
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+/// Where the image ships its defaults
+pub const VENDOR_DIR: &str = "/usr/share/hypercube";
+/// Where the admin's config and drop-in directories live
+pub const ADMIN_DIR: &str = "/etc/hypercube";
+
+/// Existing files making up the `name` config, lowest priority first:
+/// `{vendor}/{name}.toml`, `{admin}/{name}.toml`, then `{admin}/{name}.d/*.toml`
+/// in lexical order
+pub fn layer_paths(vendor: &Path, admin: &Path, name: &str) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = [vendor, admin]
+        .iter()
+        .map(|dir| dir.join(format!("{name}.toml")))
+        .filter(|path| path.is_file())
+        .collect();
+
+    let mut drop_ins: Vec<PathBuf> = std::fs::read_dir(admin.join(format!("{name}.d")))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml") && path.is_file())
+        .collect();
+    drop_ins.sort();
+    paths.extend(drop_ins);
+    paths
+}
+
+/// Several config files merged into one table
+#[derive(Debug, Clone, Default)]
+pub struct Layers {
+    pub table: Table,
+    /// Files merged, lowest priority first
+    pub sources: Vec<PathBuf>,
+}
+
+impl Layers {
+    /// Merge `paths` in order. Tables merge key by key; arrays are replaced, except
+    /// arrays of tables named in `keyed` as `(dotted.path, key)`, whose entries
+    /// merge into the entry with the same key or are appended.
+    pub fn read(paths: &[PathBuf], keyed: &[(&str, &str)]) -> Result<Self, String> {
+        let mut layers = Self::default();
+        for path in paths {
+            let content = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+            let table: Table = content.parse().map_err(|e: toml::de::Error| match e.span() {
+                Some(span) => {
+                    let (line, column) = line_column(&content, span.start);
+                    format!("{}:{line}:{column}: {}", path.display(), e.message().trim())
+                }
+                None => format!("{}: {}", path.display(), e.message().trim()),
+            })?;
+            merge(&mut layers.table, table, "", keyed);
+            layers.sources.push(path.clone());
+        }
+        Ok(layers)
+    }
+
+    /// The merged config, or why it does not fit `T`
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, String> {
+        Value::Table(self.table.clone()).try_into().map_err(|e: toml::de::Error| {
+            let sources: Vec<String> = self.sources.iter().map(|p| p.display().to_string()).collect();
+            format!("{}: {}", sources.join(" + "), e.message().trim())
+        })
+    }
+
+    /// The merged config as TOML, headed by the files it came from
+    pub fn to_toml(&self) -> String {
+        if self.sources.is_empty() {
+            return "# No config files; everything is at its default\n".to_string();
+        }
+        let mut out = String::from("# Merged from:\n");
+        for path in &self.sources {
+            out.push_str(&format!("#   {}\n", path.display()));
+        }
+        out.push('\n');
+        out.push_str(&toml::to_string(&self.table).unwrap_or_default());
+        out
+    }
+}
+
+/// Merge `overlay` into `base`, which sits at `path` in the document
+fn merge(base: &mut Table, overlay: Table, path: &str, keyed: &[(&str, &str)]) {
+    for (name, value) in overlay {
+        let path = if path.is_empty() { name.clone() } else { format!("{path}.{name}") };
+        let key = keyed.iter().find(|(keyed_path, _)| *keyed_path == path).map(|&(_, key)| key);
+        match (base.get_mut(&name), value, key) {
+            (Some(Value::Table(base)), Value::Table(overlay), _) => merge(base, overlay, &path, keyed),
+            (Some(Value::Array(base)), Value::Array(overlay), Some(key)) => {
+                for entry in overlay {
+                    let existing = entry.get(key).and_then(|id| base.iter().position(|e| e.get(key) == Some(id)));
+                    match (existing.map(|i| &mut base[i]), entry) {
+                        (Some(Value::Table(existing)), Value::Table(entry)) => merge(existing, entry, &path, keyed),
+                        (_, entry) => base.push(entry),
+                    }
+                }
+            }
+            (_, value, _) => {
+                base.insert(name, value);
+            }
+        }
+    }
+}
+
+pub(crate) fn line_column(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset.min(content.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+    (line, column)
+}
@@ -5,4 +5,6 @@ pub mod system;
 pub mod ui;
 pub mod vim;
 pub mod greeter;
+pub mod i18n;
+pub mod layers;
 pub mod onboard;
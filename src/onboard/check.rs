@@ -0,0 +1,255 @@
+//! Problems in an onboard config that would otherwise only show up at first boot
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::fmt;
+use std::path::Path;
+use toml::Value;
+use toml_edit::{ImDocument, Item, TableLike};
+
+use super::config::{FormField, OnboardConfig, StepOption};
+use crate::layers::{line_column, Layers};
+
+/// Values `[completion] action` understands
+const COMPLETION_ACTIONS: [&str; 3] = ["reboot", "poweroff", "exit"];
+
+/// One problem found in a config file, with its position when known
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigProblem {
+    /// 1-based line and column
+    pub position: Option<(usize, usize)>,
+    pub message: String,
+}
+
+impl ConfigProblem {
+    fn new(message: impl Into<String>) -> Self {
+        Self { position: None, message: message.into() }
+    }
+
+    /// `path:line:column: message`, the way compilers report errors
+    pub fn in_file(&self, path: &Path) -> String {
+        match self.position {
+            Some(_) => format!("{}:{self}", path.display()),
+            None => format!("{}: {self}", path.display()),
+        }
+    }
+}
+
+impl fmt::Display for ConfigProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.position {
+            Some((line, column)) => write!(f, "{line}:{column}: {}", self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+/// Parse `content` as a config, reporting unknown keys, and check what parsed
+pub fn check_str(content: &str) -> Result<OnboardConfig, Vec<ConfigProblem>> {
+    let error = |e: toml::de::Error| {
+        let position = e.span().map(|span| line_column(content, span.start));
+        vec![ConfigProblem { position, message: e.message().trim().to_string() }]
+    };
+    let (config, unknown) = unknown_keys(|| toml::Deserializer::new(content)).map_err(error)?;
+    let document = ImDocument::parse(content).ok();
+    let mut problems: Vec<ConfigProblem> = unknown.iter()
+        .map(|path| ConfigProblem {
+            position: document.as_ref()
+                .and_then(|doc| key_offset(doc.as_item(), path))
+                .map(|offset| line_column(content, offset)),
+            message: format!("unknown key `{}`", dotted(path)),
+        })
+        .collect();
+    problems.extend(config.problems());
+    if problems.is_empty() { Ok(config) } else { Err(problems) }
+}
+
+/// [`check_str`] on a file; unlike loading, a missing file is a problem
+pub fn check_file<P: AsRef<Path>>(path: P) -> Result<OnboardConfig, Vec<ConfigProblem>> {
+    let content = std::fs::read_to_string(path).map_err(|e| vec![ConfigProblem::new(e.to_string())])?;
+    check_str(&content)
+}
+
+/// [`check_str`] on config files already merged; unknown keys have no position
+pub fn check_layers(layers: &Layers) -> Result<OnboardConfig, Vec<ConfigProblem>> {
+    let (config, unknown) = unknown_keys(|| Value::Table(layers.table.clone())).map_err(|e| vec![ConfigProblem::new(e.message().trim())])?;
+    let mut problems: Vec<ConfigProblem> = unknown.iter()
+        .map(|path| ConfigProblem::new(format!("unknown key `{}`", dotted(path))))
+        .collect();
+    problems.extend(config.problems());
+    if problems.is_empty() { Ok(config) } else { Err(problems) }
+}
+
+/// One step of the way to a key: a table key or an array index
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// Every `[[steps]]` key. Step kinds are read through the buffering serde does for
+/// internally tagged enums, which hides unknown keys from `serde_ignored`, so steps
+/// are read a second time as this.
+#[derive(Deserialize, Default)]
+#[serde(default)]
+#[allow(dead_code)]
+struct StepKeys {
+    id: Option<Value>,
+    title: Option<Value>,
+    description: Option<Value>,
+    after: Option<Value>,
+    required: Option<Value>,
+    kind: Option<Value>,
+    options: Vec<StepOption>,
+    fields: Vec<FormField>,
+    program: Option<Value>,
+    args: Option<Value>,
+}
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+struct Steps {
+    #[serde(default)]
+    steps: Vec<StepKeys>,
+}
+
+/// Deserialize a config, collecting the paths of keys it does not know
+fn unknown_keys<'de, D, F>(deserializer: F) -> Result<(OnboardConfig, Vec<Vec<Segment>>), D::Error>
+where
+    D: serde::Deserializer<'de>,
+    F: Fn() -> D,
+{
+    let (config, mut unknown): (OnboardConfig, _) = ignored(deserializer())?;
+    let (_, steps): (Steps, _) = ignored(deserializer())?;
+    let in_steps = |path: &Vec<Segment>| path.first() == Some(&Segment::Key("steps".to_string()));
+    unknown.extend(steps.into_iter().filter(in_steps));
+    Ok((config, unknown))
+}
+
+fn ignored<'de, D, T>(deserializer: D) -> Result<(T, Vec<Vec<Segment>>), D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    let mut unknown = Vec::new();
+    let value = serde_ignored::deserialize(deserializer, |path| unknown.push(segments(&path)))?;
+    Ok((value, unknown))
+}
+
+fn segments(path: &serde_ignored::Path) -> Vec<Segment> {
+    use serde_ignored::Path;
+    match path {
+        Path::Root => Vec::new(),
+        Path::Seq { parent, index } => {
+            let mut segments = segments(parent);
+            segments.push(Segment::Index(*index));
+            segments
+        }
+        Path::Map { parent, key } => {
+            let mut segments = segments(parent);
+            segments.push(Segment::Key(key.clone()));
+            segments
+        }
+        Path::Some { parent } | Path::NewtypeStruct { parent } | Path::NewtypeVariant { parent } => segments(parent),
+    }
+}
+
+/// The path as written in TOML, e.g. `updates[0].packages[1].requried`
+fn dotted(path: &[Segment]) -> String {
+    let mut out = String::new();
+    for segment in path {
+        match segment {
+            Segment::Key(key) if out.is_empty() => out.push_str(key),
+            Segment::Key(key) => out.push_str(&format!(".{key}")),
+            Segment::Index(index) => out.push_str(&format!("[{index}]")),
+        }
+    }
+    out
+}
+
+/// Where the last key of `path` is written in the document
+fn key_offset(root: &Item, path: &[Segment]) -> Option<usize> {
+    let mut table: Option<&dyn TableLike> = root.as_table_like();
+    let mut item: Option<&Item> = None;
+    let mut offset = None;
+    for segment in path {
+        match segment {
+            Segment::Key(name) => {
+                let (key, next) = table.or_else(|| item?.as_table_like())?.get_key_value(name)?;
+                offset = key.span().map(|span| span.start).or(offset);
+                (table, item) = (None, Some(next));
+            }
+            Segment::Index(index) => {
+                let array = item?;
+                table = match array.as_array_of_tables() {
+                    Some(tables) => Some(tables.get(*index)? as &dyn TableLike),
+                    None => array.as_array()?.get(*index)?.as_inline_table().map(|t| t as &dyn TableLike),
+                };
+                item = None;
+            }
+        }
+    }
+    offset
+}
+
+impl OnboardConfig {
+    /// Settings that parse but cannot work as written
+    pub fn problems(&self) -> Vec<ConfigProblem> {
+        let mut problems = Vec::new();
+        let mut problem = |message: String| problems.push(ConfigProblem::new(message));
+
+        let locale = &self.locale;
+        if !locale.available.is_empty() && !locale.available.contains(&locale.default_locale) {
+            problem(format!("locale.default_locale {:?} is not in locale.available", locale.default_locale));
+        }
+        let keyboard = &self.keyboard;
+        if !keyboard.available.is_empty() && !keyboard.available.contains(&keyboard.default_layout) {
+            problem(format!("keyboard.default_layout {:?} is not in keyboard.available", keyboard.default_layout));
+        }
+        if !COMPLETION_ACTIONS.contains(&self.completion.action.as_str()) {
+            problem(format!(
+                "completion.action {:?} is not one of {}",
+                self.completion.action,
+                COMPLETION_ACTIONS.join(", ")
+            ));
+        }
+
+        let mut titles = HashSet::new();
+        for category in &self.updates {
+            for package in &category.packages {
+                let at = format!("package {:?} in {:?}", package.title, category.name);
+                if !titles.insert(package.title.as_str()) {
+                    problem(format!("{at}: another package has the same title"));
+                }
+                if package.commands.is_empty() {
+                    problem(format!("{at}: commands is empty"));
+                }
+                for command in &package.commands {
+                    match command.command.first() {
+                        None => problem(format!("{at}: command {:?} is empty", command.name)),
+                        Some(program) if command.sudo && program != "sudo" => problem(format!(
+                            "{at}: command {:?} has sudo = true but does not start with sudo",
+                            command.name
+                        )),
+                        Some(_) if command.sudo && command.command.get(1).is_some_and(|arg| arg.starts_with('-') && arg != "--") => {
+                            problem(format!("{at}: command {:?} passes options to sudo, which are not supported", command.name))
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+        for category in &self.updates {
+            for package in &category.packages {
+                for dep in package.depends_on.iter().filter(|dep| !titles.contains(dep.as_str())) {
+                    problem(format!(
+                        "package {:?} in {:?}: depends_on {dep:?} is not a package",
+                        package.title, category.name
+                    ));
+                }
+            }
+        }
+        problems
+    }
+}
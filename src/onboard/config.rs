@@ -1,39 +1,111 @@
+use regex::Regex;
 use serde::Deserialize;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tracing::info;
 
-const DEFAULT_CONFIG_PATH: &str = "/etc/hypercube/onboard.toml";
+use super::progress::ProgressFormat;
+use crate::layers::{self, Layers};
+use crate::ui::ThemeConfig;
 
-#[derive(Debug, Clone, Deserialize)]
+const DEFAULT_STATE_PATH: &str = "/var/lib/hypercube/onboard-state.toml";
+const DEFAULT_REPORT_PATH: &str = "/var/log/hypercube/onboard-report.json";
+
+/// Arrays of tables whose entries later layers override by key rather than replace
+const KEYED_ARRAYS: [(&str, &str); 3] = [("updates", "name"), ("updates.packages", "title"), ("steps", "id")];
+
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default)]
 pub struct OnboardConfig {
     pub general: GeneralConfig,
     pub network: NetworkConfig,
     pub user: UserConfig,
+    pub hostname: HostnameConfig,
     pub locale: LocaleConfig,
     pub keyboard: KeyboardConfig,
     pub preferences: PreferencesConfig,
     pub completion: CompletionConfig,
+    pub theme: ThemeConfig,
     /// Update categories for the Update step
     #[serde(default)]
     pub updates: Vec<UpdateCategory>,
+    /// Extra steps defined here rather than in code
+    #[serde(default)]
+    pub steps: Vec<CustomStep>,
 }
 
-impl Default for OnboardConfig {
-    fn default() -> Self {
-        Self {
-            general: GeneralConfig::default(),
-            network: NetworkConfig::default(),
-            user: UserConfig::default(),
-            locale: LocaleConfig::default(),
-            keyboard: KeyboardConfig::default(),
-            preferences: PreferencesConfig::default(),
-            completion: CompletionConfig::default(),
-            updates: Vec::new(),
-        }
+/// A step defined in the config, e.g. accepting a license or choosing a desktop
+#[derive(Debug, Clone, Deserialize)]
+pub struct CustomStep {
+    /// Names the step's results in commands (`{id}`, `{id.field}`) and in `after`
+    pub id: String,
+    /// Name in the sidebar
+    pub title: String,
+    /// Text shown at the top of the step
+    #[serde(default)]
+    pub description: String,
+    /// Built-in step (`user`, `locale`, ...) or custom step id to follow;
+    /// unset places the step just before Review
+    pub after: Option<String>,
+    /// Review cannot run until this step is done
+    #[serde(default)]
+    pub required: bool,
+    #[serde(flatten)]
+    pub kind: CustomStepKind,
+}
+
+/// What a custom step asks for
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum CustomStepKind {
+    /// A page to read and continue from
+    Info,
+    /// Pick one of the options
+    Choice { options: Vec<StepOption> },
+    /// Pick any number of the options
+    MultiChoice { options: Vec<StepOption> },
+    /// Fill in text fields
+    Form { fields: Vec<FormField> },
+    /// Run an external program; the step is done once it exits successfully
+    Program {
+        program: String,
+        #[serde(default)]
+        args: Vec<String>,
+    },
+}
+
+/// One option of a choice step
+#[derive(Debug, Clone, Deserialize)]
+pub struct StepOption {
+    /// Value handed to commands
+    pub value: String,
+    /// Text shown instead of the value
+    pub label: Option<String>,
+    /// Selected to begin with
+    #[serde(default)]
+    pub default: bool,
+}
+
+impl StepOption {
+    pub fn label(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.value)
     }
 }
 
+/// One text field of a form step
+#[derive(Debug, Clone, Deserialize)]
+pub struct FormField {
+    /// Names the value in commands, as `{step.name}`
+    pub name: String,
+    pub label: String,
+    #[serde(default)]
+    pub default: String,
+    /// Regex the whole value must match
+    #[serde(default, deserialize_with = "deserialize_pattern")]
+    pub pattern: Option<Regex>,
+    /// Shown when the value does not match `pattern`
+    pub message: Option<String>,
+}
+
 /// An update category containing packages to install
 #[derive(Debug, Clone, Deserialize)]
 pub struct UpdateCategory {
@@ -65,6 +137,19 @@ pub struct PackageItem {
     pub required: bool,
     /// Commands to install this package
     pub commands: Vec<CommandConfig>,
+    /// Titles of packages that must be installed first (e.g. "Homebrew")
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    /// Packages in the same group never install at the same time,
+    /// e.g. two that take the same package manager lock
+    pub group: Option<String>,
+}
+
+impl CommandConfig {
+    /// The command as one string, identifying it in the saved progress
+    pub fn command_line(&self) -> String {
+        self.command.join(" ")
+    }
 }
 
 impl PackageItem {
@@ -88,11 +173,67 @@ pub struct CommandConfig {
     /// Whether this command requires sudo (will prompt for password)
     #[serde(default)]
     pub sudo: bool,
+    /// How to read progress from the output; detected from the command when unset
+    #[serde(default)]
+    pub progress: Option<ProgressFormat>,
+    /// Regex for `progress = "percent-regex"`, with a `percent` group and optionally
+    /// `current` and `total` groups (an unnamed first group is the percentage)
+    #[serde(default, deserialize_with = "deserialize_pattern")]
+    pub progress_pattern: Option<Regex>,
+    /// Extra attempts before the command counts as failed
+    #[serde(default)]
+    pub retries: u32,
+    /// Seconds to wait between attempts
+    #[serde(default = "default_retry_delay")]
+    pub retry_delay: u64,
+    /// Keep running the remaining commands when this one fails, instead of
+    /// stopping for the user to retry or skip it
+    #[serde(default)]
+    pub continue_on_error: bool,
+}
+
+fn default_retry_delay() -> u64 {
+    5
+}
+
+fn deserialize_pattern<'de, D>(deserializer: D) -> Result<Option<Regex>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let Some(pattern) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+    Regex::new(&pattern).map(Some).map_err(serde::de::Error::custom)
 }
 
 impl OnboardConfig {
+    /// Load the vendor config, /etc/hypercube/onboard.toml and its drop-ins, merged
     pub fn load() -> Result<Self, super::error::OnboardError> {
-        Self::load_from(DEFAULT_CONFIG_PATH)
+        Self::read_layers(&Self::layer_paths())?.deserialize().map_err(super::error::OnboardError::Config)
+    }
+
+    /// Existing config files, lowest priority first
+    pub fn layer_paths() -> Vec<PathBuf> {
+        layers::layer_paths(Path::new(layers::VENDOR_DIR), Path::new(layers::ADMIN_DIR), "onboard")
+    }
+
+    /// Merge config files; `[[updates]]` are matched by `name`, their packages by
+    /// `title` and `[[steps]]` by `id`
+    pub fn read_layers(paths: &[PathBuf]) -> Result<Layers, super::error::OnboardError> {
+        let layers = Layers::read(paths, &KEYED_ARRAYS).map_err(super::error::OnboardError::Config)?;
+        info!("Loaded config from {:?}", layers.sources);
+        Ok(layers)
+    }
+
+    /// Like [`load`](Self::load), but also fail on unknown keys and the problems
+    /// [`problems`](Self::problems) finds
+    pub fn load_checked() -> Result<Self, super::error::OnboardError> {
+        let layers = Self::read_layers(&Self::layer_paths())?;
+        super::check::check_layers(&layers).map_err(|problems| {
+            let sources: Vec<String> = layers.sources.iter().map(|p| p.display().to_string()).collect();
+            let problems: Vec<String> = problems.iter().map(|p| format!("{}: {p}", sources.join(" + "))).collect();
+            super::error::OnboardError::Config(problems.join("\n"))
+        })
     }
 
     pub fn load_from<P: AsRef<Path>>(path: P) -> Result<Self, super::error::OnboardError> {
@@ -108,6 +249,17 @@ impl OnboardConfig {
         info!("Loaded config from {:?}", path);
         Ok(config)
     }
+
+    /// Load a config that must exist and pass [`check_file`](super::check_file)
+    pub fn load_strict<P: AsRef<Path>>(path: P) -> Result<Self, super::error::OnboardError> {
+        let path = path.as_ref();
+        let config = super::check::check_file(path).map_err(|problems| {
+            let problems: Vec<String> = problems.iter().map(|p| p.in_file(path)).collect();
+            super::error::OnboardError::Config(problems.join("\n"))
+        })?;
+        info!("Loaded config from {:?}", path);
+        Ok(config)
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -119,6 +271,37 @@ pub struct GeneralConfig {
     /// When true, no system commands are executed, mock data is used, and
     /// reboot transitions to the login screen instead of actually rebooting
     pub dryrun: bool,
+    /// Where progress is saved so an interrupted setup can resume
+    pub state_file: String,
+    /// How many packages the Update step may install at once
+    pub parallel_installs: usize,
+    /// How commands marked `sudo = true` get root
+    pub sudo_policy: SudoPolicy,
+    /// How locale, keymap, time and hostname settings reach the system
+    pub system_backend: SystemBackend,
+}
+
+/// How commands marked `sudo = true` get root
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SudoPolicy {
+    /// Run them as root directly, since onboard already is
+    #[default]
+    Root,
+    /// Run them through sudo as the new user, who enters their password first
+    Password,
+}
+
+/// How locale, keymap, time and hostname settings reach the system
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SystemBackend {
+    /// systemd's locale1, timedate1 and hostname1 services, falling back to
+    /// `commands` when the system bus is unreachable
+    #[default]
+    Dbus,
+    /// localectl, timedatectl and hostnamectl
+    Commands,
 }
 
 impl Default for GeneralConfig {
@@ -127,6 +310,10 @@ impl Default for GeneralConfig {
             title: "System Setup".to_string(),
             subtitle: "Welcome to your new system".to_string(),
             dryrun: false,
+            state_file: DEFAULT_STATE_PATH.to_string(),
+            parallel_installs: 1,
+            sudo_policy: SudoPolicy::Root,
+            system_backend: SystemBackend::Dbus,
         }
     }
 }
@@ -169,6 +356,24 @@ impl Default for UserConfig {
     }
 }
 
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct HostnameConfig {
+    pub enabled: bool,
+    /// Suggested hostname; `{serial}` is the machine's serial number and
+    /// `{username}` the account being created
+    pub default: String,
+}
+
+impl Default for HostnameConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            default: "hypercube-{serial}".to_string(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct LocaleConfig {
@@ -230,6 +435,8 @@ impl Default for PreferencesConfig {
 pub struct CompletionConfig {
     pub action: String,
     pub remove_initial_session: bool,
+    /// Where the JSON install report goes, with a text summary beside it; empty for none
+    pub report: String,
 }
 
 impl Default for CompletionConfig {
@@ -237,6 +444,7 @@ impl Default for CompletionConfig {
         Self {
             action: "reboot".to_string(),
             remove_initial_session: true,
+            report: DEFAULT_REPORT_PATH.to_string(),
         }
     }
 }
@@ -0,0 +1,160 @@
+//! Steps declared with `[[steps]]` in the config, and the values they hand to commands
+
+use regex::Regex;
+use std::collections::BTreeMap;
+
+use super::config::{CustomStep, CustomStepKind, FormField};
+use super::steps::StepId;
+
+/// Values of finished custom steps, by placeholder name (`desktop`, `mdm.email`)
+pub type StepValues = BTreeMap<String, Vec<String>>;
+
+/// Name a built-in step goes by in `after`
+fn builtin_name(id: StepId) -> Option<&'static str> {
+    Some(match id {
+        StepId::Network => "network",
+        StepId::User => "user",
+        StepId::Hostname => "hostname",
+        StepId::Locale => "locale",
+        StepId::Keyboard => "keyboard",
+        StepId::Preferences => "preferences",
+        StepId::Review => "review",
+        StepId::Update => "update",
+        StepId::Reboot | StepId::Custom(_) => return None,
+    })
+}
+
+/// Where each custom step goes among `order`, the built-in steps: after the step named
+/// in `after`, behind custom steps already placed there, or else just before Review.
+/// Returns the full order of step ids.
+pub fn place(order: Vec<StepId>, steps: &[CustomStep]) -> Vec<StepId> {
+    let mut order = order;
+    for (idx, step) in steps.iter().enumerate() {
+        let anchor = step.after.as_deref().and_then(|after| {
+            let after = after.to_lowercase();
+            order.iter().position(|id| match id {
+                StepId::Custom(other) => steps[*other].id.to_lowercase() == after,
+                id => builtin_name(*id) == Some(after.as_str()),
+            })
+        });
+        let at = match anchor {
+            Some(mut at) => {
+                // Keep config order among steps sharing an anchor
+                while matches!(order.get(at + 1), Some(StepId::Custom(other)) if steps[*other].after == step.after) {
+                    at += 1;
+                }
+                at + 1
+            }
+            None => order.iter().position(|id| *id == StepId::Review).unwrap_or(order.len()),
+        };
+        order.insert(at, StepId::Custom(idx));
+    }
+    order
+}
+
+/// Values of steps nobody answered: the default options and field defaults
+pub fn defaults(steps: &[CustomStep]) -> StepValues {
+    let mut values = StepValues::new();
+    for step in steps {
+        match &step.kind {
+            CustomStepKind::Choice { options } | CustomStepKind::MultiChoice { options } => {
+                let chosen = options.iter().filter(|o| o.default).map(|o| o.value.clone()).collect();
+                values.insert(step.id.clone(), chosen);
+            }
+            CustomStepKind::Form { fields } => {
+                for field in fields {
+                    values.insert(format!("{}.{}", step.id, field.name), vec![field.default.clone()]);
+                }
+            }
+            CustomStepKind::Info | CustomStepKind::Program { .. } => {}
+        }
+    }
+    values
+}
+
+/// Why `value` does not fit `field`, if it does not
+pub fn check_field(field: &FormField, value: &str) -> Result<(), String> {
+    let Some(pattern) = &field.pattern else {
+        return Ok(());
+    };
+    // Anchored, so the pattern has to cover the whole value
+    let whole = Regex::new(&format!("^(?:{})$", pattern.as_str())).map_err(|e| e.to_string())?;
+    if whole.is_match(value) {
+        Ok(())
+    } else {
+        Err(field.message.clone().unwrap_or_else(|| format!("{} is not valid", field.label)))
+    }
+}
+
+/// `cmd` with `{name}` placeholders of finished steps filled in. An argument that is
+/// just a placeholder becomes one argument per value, so a multi-choice step can
+/// list packages; inside a longer argument the values are joined with spaces.
+/// Unknown placeholders are left alone.
+pub fn expand(cmd: &[String], values: &StepValues) -> Vec<String> {
+    let mut expanded = Vec::with_capacity(cmd.len());
+    for arg in cmd {
+        let whole = arg.strip_prefix('{').and_then(|a| a.strip_suffix('}'));
+        if let Some(list) = whole.and_then(|name| values.get(name)) {
+            expanded.extend(list.iter().cloned());
+            continue;
+        }
+        let mut arg = arg.clone();
+        for (name, list) in values {
+            arg = arg.replace(&format!("{{{name}}}"), &list.join(" "));
+        }
+        expanded.push(arg);
+    }
+    expanded
+}
+
+/// Values for `step` from an answer file entry: a string for a choice, a list for a
+/// multi-choice, a table of fields for a form, and `true` for an info or program step
+/// that was taken care of
+pub fn answer_values(step: &CustomStep, answer: &toml::Value) -> Result<StepValues, String> {
+    let mut values = StepValues::new();
+    let known = |options: &[super::config::StepOption], value: &str| {
+        if options.iter().any(|o| o.value == value) {
+            Ok(value.to_string())
+        } else {
+            Err(format!("unknown option: {value}"))
+        }
+    };
+
+    match (&step.kind, answer) {
+        (CustomStepKind::Info | CustomStepKind::Program { .. }, toml::Value::Boolean(true)) => {}
+        (CustomStepKind::Choice { options }, toml::Value::String(value)) => {
+            values.insert(step.id.clone(), vec![known(options, value)?]);
+        }
+        (CustomStepKind::MultiChoice { options }, toml::Value::Array(list)) => {
+            let chosen = list.iter()
+                .map(|v| v.as_str().ok_or_else(|| "expected a list of strings".to_string()))
+                .map(|v| v.and_then(|v| known(options, v)))
+                .collect::<Result<Vec<_>, _>>()?;
+            values.insert(step.id.clone(), chosen);
+        }
+        (CustomStepKind::Form { fields }, toml::Value::Table(table)) => {
+            for field in fields {
+                let value = match table.get(&field.name) {
+                    Some(toml::Value::String(value)) => value.clone(),
+                    Some(_) => return Err(format!("{} must be a string", field.name)),
+                    None => field.default.clone(),
+                };
+                check_field(field, &value)?;
+                values.insert(format!("{}.{}", step.id, field.name), vec![value]);
+            }
+            if let Some(name) = table.keys().find(|k| !fields.iter().any(|f| &f.name == *k)) {
+                return Err(format!("unknown field: {name}"));
+            }
+        }
+        (kind, _) => {
+            let expected = match kind {
+                CustomStepKind::Info | CustomStepKind::Program { .. } => "true",
+                CustomStepKind::Choice { .. } => "one of the options",
+                CustomStepKind::MultiChoice { .. } => "a list of options",
+                CustomStepKind::Form { .. } => "a table of fields",
+            };
+            return Err(format!("expected {expected}"));
+        }
+    }
+    Ok(values)
+}
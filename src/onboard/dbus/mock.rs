@@ -0,0 +1,245 @@
+//! A private bus with stand-ins for systemd's locale1, timedate1 and hostname1,
+//! used by the integration tests of [`DbusService`](super::DbusService)
+
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tracing::info;
+use zbus::blocking::{connection, Connection};
+use zbus::{interface, DBusError};
+
+use crate::onboard::error::{OnboardError, Result};
+
+static BUS_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Which services are on the bus and what they accept
+#[derive(Debug, Clone)]
+pub struct MockSystemd {
+    pub locale1: bool,
+    pub timedate1: bool,
+    pub hostname1: bool,
+    /// Locales `SetLocale` accepts (empty accepts any)
+    pub locales: Vec<String>,
+    /// What `ListTimezones` returns and `SetTimezone` accepts
+    pub timezones: Vec<String>,
+    /// Whether `SetNTP` works, as on a machine with an NTP service installed
+    pub ntp: bool,
+    /// Refuse every call, as polkit does for an unprivileged caller
+    pub deny: bool,
+}
+
+impl Default for MockSystemd {
+    fn default() -> Self {
+        Self {
+            locale1: true,
+            timedate1: true,
+            hostname1: true,
+            locales: Vec::new(),
+            timezones: vec!["America/New_York".to_string(), "Europe/Berlin".to_string(), "UTC".to_string()],
+            ntp: true,
+            deny: false,
+        }
+    }
+}
+
+/// Errors the stand-ins reply with, named as systemd names them
+#[derive(Debug, DBusError)]
+#[zbus(prefix = "org.freedesktop")]
+enum MockError {
+    #[zbus(error)]
+    ZBus(zbus::Error),
+    #[zbus(name = "DBus.Error.AccessDenied")]
+    AccessDenied(String),
+    #[zbus(name = "DBus.Error.InvalidArgs")]
+    InvalidArgs(String),
+    #[zbus(name = "timedate1.NoNTPSupport")]
+    NoNtpSupport(String),
+}
+
+/// What the stand-ins share: the script and the calls made so far
+struct Stand {
+    script: MockSystemd,
+    calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl Stand {
+    /// Record `call` unless the script refuses everything
+    fn call(&self, call: String) -> std::result::Result<(), MockError> {
+        if self.script.deny {
+            return Err(MockError::AccessDenied("Access denied".to_string()));
+        }
+        self.calls.lock().unwrap().push(call);
+        Ok(())
+    }
+}
+
+struct Locale1(Arc<Stand>);
+
+#[interface(name = "org.freedesktop.locale1")]
+impl Locale1 {
+    fn set_locale(&self, locale: Vec<String>, _interactive: bool) -> std::result::Result<(), MockError> {
+        let locales = &self.0.script.locales;
+        for assignment in &locale {
+            let value = assignment.split_once('=').map_or(assignment.as_str(), |(_, value)| value);
+            if !locales.is_empty() && !locales.iter().any(|l| l == value) {
+                return Err(MockError::InvalidArgs(format!("Locale {value} not installed, refusing.")));
+            }
+        }
+        self.0.call(format!("SetLocale {}", locale.join(" ")))
+    }
+
+    #[zbus(name = "SetVConsoleKeyboard")]
+    fn set_vconsole_keyboard(&self, keymap: String, toggle: String, convert: bool, _interactive: bool) -> std::result::Result<(), MockError> {
+        self.0.call(format!("SetVConsoleKeyboard {keymap:?} {toggle:?} convert={convert}"))
+    }
+}
+
+struct Timedate1(Arc<Stand>);
+
+#[interface(name = "org.freedesktop.timedate1")]
+impl Timedate1 {
+    fn set_timezone(&self, timezone: String, _interactive: bool) -> std::result::Result<(), MockError> {
+        if !self.0.script.timezones.contains(&timezone) {
+            return Err(MockError::InvalidArgs(format!("Invalid or not installed time zone '{timezone}'")));
+        }
+        self.0.call(format!("SetTimezone {timezone}"))
+    }
+
+    #[zbus(name = "SetNTP")]
+    fn set_ntp(&self, use_ntp: bool, _interactive: bool) -> std::result::Result<(), MockError> {
+        if !self.0.script.ntp {
+            return Err(MockError::NoNtpSupport("NTP not supported".to_string()));
+        }
+        self.0.call(format!("SetNTP {use_ntp}"))
+    }
+
+    fn list_timezones(&self) -> Vec<String> {
+        self.0.script.timezones.clone()
+    }
+}
+
+struct Hostname1(Arc<Stand>);
+
+#[interface(name = "org.freedesktop.hostname1")]
+impl Hostname1 {
+    fn set_hostname(&self, hostname: String, _interactive: bool) -> std::result::Result<(), MockError> {
+        self.0.call(format!("SetHostname {hostname:?}"))
+    }
+
+    fn set_static_hostname(&self, hostname: String, _interactive: bool) -> std::result::Result<(), MockError> {
+        self.0.call(format!("SetStaticHostname {hostname:?}"))
+    }
+
+    fn set_pretty_hostname(&self, hostname: String, _interactive: bool) -> std::result::Result<(), MockError> {
+        self.0.call(format!("SetPrettyHostname {hostname:?}"))
+    }
+}
+
+/// A `dbus-daemon` of our own with the scripted services on it, stopped when dropped
+pub struct MockBus {
+    daemon: Child,
+    dir: PathBuf,
+    address: String,
+    calls: Arc<Mutex<Vec<String>>>,
+    _services: Vec<Connection>,
+}
+
+impl MockBus {
+    /// Start the bus and the services `script` asks for. Fails with
+    /// [`std::io::ErrorKind::NotFound`] when `dbus-daemon` is not installed.
+    pub fn start(script: MockSystemd) -> Result<Self> {
+        let dir = std::env::temp_dir().join(format!(
+            "hypercube-mock-bus-{}-{}",
+            std::process::id(),
+            BUS_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&dir)?;
+        let config = dir.join("bus.conf");
+        std::fs::write(&config, bus_config(&dir))?;
+
+        let mut daemon = Command::new("dbus-daemon")
+            .arg(format!("--config-file={}", config.display()))
+            .args(["--nofork", "--print-address"])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+
+        // The address is printed once the bus is listening
+        let mut address = String::new();
+        if let Some(stdout) = daemon.stdout.take() {
+            BufReader::new(stdout).read_line(&mut address)?;
+        }
+        let address = address.trim().to_string();
+        let mut bus = Self { daemon, dir, address, calls: Arc::default(), _services: Vec::new() };
+        if bus.address.is_empty() {
+            return Err(OnboardError::Dbus("dbus-daemon exited without an address".to_string()));
+        }
+        info!("Mock bus listening on {}", bus.address);
+
+        let stand = Arc::new(Stand { script: script.clone(), calls: bus.calls.clone() });
+        if script.locale1 {
+            let service = bus.serve("locale1", Locale1(stand.clone()))?;
+            bus._services.push(service);
+        }
+        if script.timedate1 {
+            let service = bus.serve("timedate1", Timedate1(stand.clone()))?;
+            bus._services.push(service);
+        }
+        if script.hostname1 {
+            let service = bus.serve("hostname1", Hostname1(stand))?;
+            bus._services.push(service);
+        }
+        Ok(bus)
+    }
+
+    fn serve<I: zbus::object_server::Interface>(&self, name: &str, iface: I) -> Result<Connection> {
+        Ok(connection::Builder::address(self.address.as_str())?
+            .name(format!("org.freedesktop.{name}"))?
+            .serve_at(format!("/org/freedesktop/{name}"), iface)?
+            .build()?)
+    }
+
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// A client connection to the bus, for [`DbusService::new`](super::DbusService::new)
+    pub fn connect(&self) -> Result<Connection> {
+        Ok(connection::Builder::address(self.address.as_str())?.build()?)
+    }
+
+    /// Calls the services accepted, in order, e.g. `SetTimezone Europe/Berlin`
+    pub fn calls(&self) -> Vec<String> {
+        self.calls.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MockBus {
+    fn drop(&mut self) {
+        let _ = self.daemon.kill();
+        let _ = self.daemon.wait();
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// A session-type bus on a socket in `dir` that lets anyone call anything and own any name
+fn bus_config(dir: &std::path::Path) -> String {
+    format!(
+        r#"<!DOCTYPE busconfig PUBLIC "-//freedesktop//DTD D-Bus Bus Configuration 1.0//EN"
+ "http://www.freedesktop.org/standards/dbus/1.0/busconfig.dtd">
+<busconfig>
+  <type>session</type>
+  <listen>unix:path={}/bus</listen>
+  <auth>EXTERNAL</auth>
+  <policy context="default">
+    <allow send_destination="*"/>
+    <allow receive_sender="*"/>
+    <allow own="*"/>
+  </policy>
+</busconfig>
+"#,
+        dir.display()
+    )
+}
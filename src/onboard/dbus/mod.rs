@@ -0,0 +1,187 @@
+//! Backend that applies locale, keymap, time and hostname settings through systemd's
+//! locale1, timedate1 and hostname1 D-Bus services rather than their command-line tools,
+//! so a refusal comes back with the service's own error name and message
+
+pub mod mock;
+
+use tracing::{info, warn};
+use zbus::blocking::Connection;
+use zbus::proxy;
+
+use super::error::{OnboardError, Result};
+use super::service::{LiveService, OnboardService, Password, Secret, ServiceOp};
+
+#[proxy(
+    interface = "org.freedesktop.locale1",
+    default_service = "org.freedesktop.locale1",
+    default_path = "/org/freedesktop/locale1"
+)]
+trait Locale1 {
+    fn set_locale(&self, locale: &[&str], interactive: bool) -> zbus::Result<()>;
+
+    #[zbus(name = "SetVConsoleKeyboard")]
+    fn set_vconsole_keyboard(&self, keymap: &str, keymap_toggle: &str, convert: bool, interactive: bool) -> zbus::Result<()>;
+}
+
+#[proxy(
+    interface = "org.freedesktop.timedate1",
+    default_service = "org.freedesktop.timedate1",
+    default_path = "/org/freedesktop/timedate1"
+)]
+trait Timedate1 {
+    fn set_timezone(&self, timezone: &str, interactive: bool) -> zbus::Result<()>;
+
+    #[zbus(name = "SetNTP")]
+    fn set_ntp(&self, use_ntp: bool, interactive: bool) -> zbus::Result<()>;
+
+    fn list_timezones(&self) -> zbus::Result<Vec<String>>;
+}
+
+#[proxy(
+    interface = "org.freedesktop.hostname1",
+    default_service = "org.freedesktop.hostname1",
+    default_path = "/org/freedesktop/hostname1"
+)]
+trait Hostname1 {
+    fn set_hostname(&self, hostname: &str, interactive: bool) -> zbus::Result<()>;
+    fn set_static_hostname(&self, hostname: &str, interactive: bool) -> zbus::Result<()>;
+    fn set_pretty_hostname(&self, hostname: &str, interactive: bool) -> zbus::Result<()>;
+}
+
+impl From<zbus::Error> for OnboardError {
+    fn from(e: zbus::Error) -> Self {
+        match e {
+            zbus::Error::MethodError(name, message, _) => OnboardError::DbusMethod {
+                name: name.to_string(),
+                message: message.unwrap_or_default(),
+            },
+            e => OnboardError::Dbus(e.to_string()),
+        }
+    }
+}
+
+/// Live service that talks to systemd over D-Bus for settings it has a service for,
+/// and runs commands like [`LiveService`] for the rest
+pub struct DbusService {
+    connection: Connection,
+    commands: LiveService,
+}
+
+impl DbusService {
+    /// Use the system bus, where systemd's services live
+    pub fn system() -> Result<Self> {
+        Ok(Self::new(Connection::system()?))
+    }
+
+    /// Use a given bus, e.g. a private one with stand-in services
+    pub fn new(connection: Connection) -> Self {
+        Self { connection, commands: LiveService }
+    }
+
+    fn locale1(&self) -> Result<Locale1ProxyBlocking<'_>> {
+        Ok(Locale1ProxyBlocking::new(&self.connection)?)
+    }
+
+    fn timedate1(&self) -> Result<Timedate1ProxyBlocking<'_>> {
+        Ok(Timedate1ProxyBlocking::new(&self.connection)?)
+    }
+
+    fn hostname1(&self) -> Result<Hostname1ProxyBlocking<'_>> {
+        Ok(Hostname1ProxyBlocking::new(&self.connection)?)
+    }
+}
+
+impl OnboardService for DbusService {
+    fn check_network(&self) -> bool {
+        self.commands.check_network()
+    }
+
+    // locale1 has no list methods; localectl reads the files itself
+    fn list_locales(&self) -> Vec<String> {
+        self.commands.list_locales()
+    }
+
+    fn list_keymaps(&self) -> Vec<String> {
+        self.commands.list_keymaps()
+    }
+
+    fn list_timezones(&self) -> Vec<String> {
+        match self.timedate1().and_then(|timedate| Ok(timedate.list_timezones()?)) {
+            Ok(timezones) => timezones,
+            Err(e) => {
+                warn!("Failed to list timezones over D-Bus ({}), using timedatectl", e);
+                self.commands.list_timezones()
+            }
+        }
+    }
+
+    fn user_exists(&self, username: &str) -> bool {
+        self.commands.user_exists(username)
+    }
+
+    fn machine_serial(&self) -> Option<String> {
+        self.commands.machine_serial()
+    }
+
+    fn create_user(&self, username: &str, password: &Password, groups: &[String], shell: &str) -> Result<()> {
+        self.commands.create_user(username, password, groups, shell)
+    }
+
+    fn set_password(&self, username: &str, password: &Password) -> Result<()> {
+        self.commands.set_password(username, password)
+    }
+
+    fn set_locale(&self, locale: &str) -> Result<()> {
+        info!("Setting locale to: {}", locale);
+        Ok(self.locale1()?.set_locale(&[&format!("LANG={locale}")], false)?)
+    }
+
+    fn set_keymap(&self, keymap: &str) -> Result<()> {
+        info!("Setting keymap to: {}", keymap);
+        // Like localectl set-keymap, which also converts it to an X11 layout
+        Ok(self.locale1()?.set_vconsole_keyboard(keymap, "", true, false)?)
+    }
+
+    fn set_timezone(&self, timezone: &str) -> Result<()> {
+        info!("Setting timezone to: {}", timezone);
+        Ok(self.timedate1()?.set_timezone(timezone, false)?)
+    }
+
+    fn set_hostname(&self, hostname: &str, pretty: Option<&str>) -> Result<()> {
+        info!("Setting hostname to: {} ({:?})", hostname, pretty);
+        // Like hostnamectl set-hostname: static and transient, and any pretty one cleared
+        let hostname1 = self.hostname1()?;
+        hostname1.set_static_hostname(hostname, false)?;
+        hostname1.set_hostname(hostname, false)?;
+        Ok(hostname1.set_pretty_hostname(pretty.unwrap_or(""), false)?)
+    }
+
+    fn set_ntp(&self, enabled: bool) -> Result<()> {
+        info!("Setting NTP to: {}", enabled);
+        Ok(self.timedate1()?.set_ntp(enabled, false)?)
+    }
+
+    fn create_keyring(&self, username: &str, password: &Secret) -> Result<()> {
+        self.commands.create_keyring(username, password)
+    }
+
+    fn run_command_as_user(&self, username: &str, cmd: &[String], on_line: &dyn Fn(&str)) -> Result<String> {
+        self.commands.run_command_as_user(username, cmd, on_line)
+    }
+
+    fn run_command_as_root(&self, cmd: &[String], on_line: &dyn Fn(&str)) -> Result<String> {
+        self.commands.run_command_as_root(cmd, on_line)
+    }
+
+    fn run_command_as_user_with_sudo(&self, username: &str, cmd: &[String], password: &Secret, on_line: &dyn Fn(&str)) -> Result<String> {
+        self.commands.run_command_as_user_with_sudo(username, cmd, password, on_line)
+    }
+
+    fn remove_initial_session(&self) -> Result<()> {
+        self.commands.remove_initial_session()
+    }
+
+    fn command_string(&self, op: &ServiceOp) -> String {
+        self.commands.command_string(op)
+    }
+}
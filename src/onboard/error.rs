@@ -8,11 +8,39 @@ pub enum OnboardError {
     #[error("Command failed: {0}")]
     Command(String),
 
+    /// A command ran and exited unsuccessfully; `code` is unset when a signal ended it
+    #[error("Command failed: {output}")]
+    Exited { code: Option<i32>, output: String },
+
+    /// A D-Bus service refused a call, e.g. `org.freedesktop.DBus.Error.AccessDenied`
+    #[error("{name}: {message}")]
+    DbusMethod { name: String, message: String },
+
+    /// The bus itself failed, e.g. it could not be reached
+    #[error("D-Bus error: {0}")]
+    Dbus(String),
+
     #[error("User creation failed: {0}")]
     UserCreation(String),
 
+    #[error("Invalid answer file: {0}")]
+    Answers(String),
+
+    #[error("Invalid config: {0}")]
+    Config(String),
+
     #[error("TOML parse error: {0}")]
     TomlParse(#[from] toml::de::Error),
 }
 
+impl OnboardError {
+    /// Exit code of a command that ran and failed
+    pub fn exit_code(&self) -> Option<i32> {
+        match self {
+            OnboardError::Exited { code, .. } => *code,
+            _ => None,
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, OnboardError>;
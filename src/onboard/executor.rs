@@ -1,8 +1,12 @@
-use std::io::Write;
-use std::process::{Command, Stdio};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::mpsc;
+use std::thread;
 use tracing::{debug, info, warn};
+use zeroize::Zeroizing;
 
 use super::error::{OnboardError, Result};
+use super::service::{Password, Secret};
 
 /// Check if network is connected by testing DNS resolution
 pub fn check_network(demo_mode: bool) -> bool {
@@ -285,8 +289,105 @@ pub fn set_timezone(timezone: &str) -> Result<()> {
     }
 }
 
+/// Set the static hostname, and the pretty hostname when given
+pub fn set_hostname(hostname: &str, pretty: Option<&str>) -> Result<()> {
+    info!("Setting hostname to: {} ({:?})", hostname, pretty);
+
+    // Sets the static and transient hostname and clears any pretty one
+    let status = Command::new("hostnamectl")
+        .args(["set-hostname", hostname])
+        .status()?;
+    if !status.success() {
+        return Err(OnboardError::Command(format!(
+            "hostnamectl set-hostname failed with code {:?}",
+            status.code()
+        )));
+    }
+
+    if let Some(pretty) = pretty {
+        let status = Command::new("hostnamectl")
+            .args(["set-hostname", "--pretty", pretty])
+            .status()?;
+        if !status.success() {
+            return Err(OnboardError::Command(format!(
+                "hostnamectl set-hostname --pretty failed with code {:?}",
+                status.code()
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Turn network time synchronization on or off
+pub fn set_ntp(enabled: bool) -> Result<()> {
+    info!("Setting NTP to: {}", enabled);
+
+    let status = Command::new("timedatectl")
+        .args(["set-ntp", if enabled { "true" } else { "false" }])
+        .status()?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(OnboardError::Command(format!(
+            "timedatectl set-ntp failed with code {:?}",
+            status.code()
+        )))
+    }
+}
+
+/// Create the login keyring of a user, protected by their password. gnome-keyring
+/// creates it on unlock when it does not exist yet; the daemon only lives as long as
+/// its private session bus.
+pub fn create_keyring(username: &str, password: &Secret) -> Result<()> {
+    info!("Creating login keyring for {}", username);
+
+    let cmd: Vec<String> = ["dbus-run-session", "--", "gnome-keyring-daemon", "--unlock", "--components=secrets"]
+        .into_iter()
+        .map(String::from)
+        .collect();
+    // The daemon takes everything on stdin as the password, so no newline
+    let input = Zeroizing::new(password.expose().as_bytes().to_vec());
+    let output = run_streaming(&mut as_user(username, &cmd)?, Some(&input), &|_| {})?;
+    command_result(output).map(|_| ())
+}
+
+/// Values firmware leaves in the DMI serial when the vendor never set one
+const PLACEHOLDER_SERIALS: &[&str] = &[
+    "To Be Filled By O.E.M.",
+    "System Serial Number",
+    "Default string",
+    "Not Specified",
+    "None",
+    "0",
+];
+
+/// The DMI product serial, or the start of the machine ID when the firmware has none
+pub fn machine_serial() -> Option<String> {
+    let dmi = std::fs::read_to_string("/sys/class/dmi/id/product_serial")
+        .ok()
+        .map(|serial| serial.trim().to_string())
+        .filter(|serial| !serial.is_empty() && !PLACEHOLDER_SERIALS.iter().any(|p| serial.eq_ignore_ascii_case(p)));
+    dmi.or_else(|| {
+        let id = std::fs::read_to_string("/etc/machine-id").ok()?;
+        Some(id.trim().chars().take(8).collect::<String>()).filter(|id| !id.is_empty())
+    })
+}
+
+/// Check whether an account already exists
+pub fn user_exists(username: &str) -> bool {
+    Command::new("getent")
+        .args(["passwd", username])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|s| s.success())
+        .unwrap_or(false)
+}
+
 /// Create a new user account
-pub fn create_user(username: &str, password: &str, groups: &[String], shell: &str) -> Result<()> {
+pub fn create_user(username: &str, password: &Password, groups: &[String], shell: &str) -> Result<()> {
     info!("Creating user: {}", username);
 
     // Build useradd command
@@ -316,15 +417,28 @@ pub fn create_user(username: &str, password: &str, groups: &[String], shell: &st
         )));
     }
 
-    // Set password via chpasswd
+    set_password(username, password)?;
+
+    info!("User {} created successfully", username);
+    Ok(())
+}
+
+/// Set the password of an existing account via chpasswd
+pub fn set_password(username: &str, password: &Password) -> Result<()> {
     info!("Setting password for user: {}", username);
 
+    let (secret, chpasswd_args): (&str, &[&str]) = match password {
+        Password::Plain(password) => (password, &[]),
+        Password::Hashed(hash) => (hash, &["-e"]),
+    };
+
     let mut child = Command::new("chpasswd")
+        .args(chpasswd_args)
         .stdin(Stdio::piped())
         .spawn()?;
 
     if let Some(mut stdin) = child.stdin.take() {
-        writeln!(stdin, "{}:{}", username, password)?;
+        writeln!(stdin, "{}:{}", username, secret)?;
     }
 
     let status = child.wait()?;
@@ -335,82 +449,197 @@ pub fn create_user(username: &str, password: &str, groups: &[String], shell: &st
         )));
     }
 
-    info!("User {} created successfully", username);
     Ok(())
 }
 
-/// Run a command as a specific user (using su)
-pub fn run_command_as_user(username: &str, cmd: &[String]) -> Result<String> {
+/// Login details of an account, from the passwd database
+struct Account {
+    uid: u32,
+    gid: u32,
+    home: String,
+}
+
+fn lookup_account(username: &str) -> Result<Account> {
+    let output = Command::new("getent").args(["passwd", username]).output()?;
+    let entry = String::from_utf8_lossy(&output.stdout);
+    // name:password:uid:gid:gecos:home:shell
+    let fields: Vec<&str> = entry.trim_end().split(':').collect();
+    match fields.as_slice() {
+        [_, _, uid, gid, _, home, ..] if output.status.success() => Ok(Account {
+            uid: uid.parse().map_err(|_| OnboardError::Command(format!("Bad uid for {}", username)))?,
+            gid: gid.parse().map_err(|_| OnboardError::Command(format!("Bad gid for {}", username)))?,
+            home: home.to_string(),
+        }),
+        _ => Err(OnboardError::Command(format!("No such user: {}", username))),
+    }
+}
+
+/// `cmd` run as `username` with only that account's privileges: `setpriv` drops to its
+/// uid, gid and groups with a fresh login environment, then a login shell loads the
+/// profile (e.g. Homebrew's PATH) and execs `cmd`. The arguments are passed through as
+/// they are and never parsed by the shell.
+fn as_user(username: &str, cmd: &[String]) -> Result<Command> {
+    let account = lookup_account(username)?;
+    let mut command = Command::new("setpriv");
+    command
+        .arg(format!("--reuid={}", account.uid))
+        .arg(format!("--regid={}", account.gid))
+        .args(["--init-groups", "--reset-env", "--"])
+        .args(["/bin/sh", "-lc", "exec \"$0\" \"$@\""])
+        .args(cmd)
+        .current_dir(&account.home);
+    Ok(command)
+}
+
+/// The command without a leading `sudo`; onboard provides privileges itself. Options
+/// to `sudo` are refused rather than dropped, since e.g. `-u postgres` would otherwise
+/// run the rest as root.
+pub fn strip_sudo(cmd: &[String]) -> Result<&[String]> {
+    let rest = match cmd.split_first() {
+        Some((first, rest)) if first == "sudo" => rest,
+        _ => return Ok(cmd),
+    };
+    match rest.split_first() {
+        Some((option, rest)) if option == "--" => Ok(rest),
+        Some((option, _)) if option.starts_with('-') => Err(OnboardError::Command(format!(
+            "sudo options are not supported: {option}"
+        ))),
+        _ => Ok(rest),
+    }
+}
+
+/// Run a command as a specific user, passing each output line to `on_line`
+pub fn run_command_as_user(username: &str, cmd: &[String], on_line: &dyn Fn(&str)) -> Result<String> {
     if cmd.is_empty() {
         return Err(OnboardError::Command("Empty command".to_string()));
     }
 
     info!("Running command as {}: {:?}", username, cmd);
 
-    // Use su to run command as user
-    // su -l username -c "command args..."
-    let command_str = cmd.iter()
-        .map(|s| shell_escape::escape(s.into()).to_string())
-        .collect::<Vec<_>>()
-        .join(" ");
+    let output = run_streaming(&mut as_user(username, cmd)?, None, on_line)?;
+    command_result(output)
+}
 
-    let output = Command::new("su")
-        .args(["-l", username, "-c", &command_str])
-        .output()?;
+/// Run a privileged command directly as root, passing each output line to `on_line`
+pub fn run_command_as_root(cmd: &[String], on_line: &dyn Fn(&str)) -> Result<String> {
+    let Some((program, args)) = strip_sudo(cmd)?.split_first() else {
+        return Err(OnboardError::Command("Empty command".to_string()));
+    };
 
-    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+    info!("Running command as root: {:?}", cmd);
 
-    if output.status.success() {
-        Ok(stdout)
-    } else {
-        Err(OnboardError::Command(format!(
-            "Command failed: {}",
-            if stderr.is_empty() { stdout } else { stderr }
-        )))
-    }
+    let output = run_streaming(Command::new(program).args(args), None, on_line)?;
+    command_result(output)
 }
 
-/// Run a command as a specific user with sudo (provides password via stdin)
-pub fn run_command_as_user_with_sudo(username: &str, cmd: &[String], password: &str) -> Result<String> {
+/// Run a privileged command through sudo as a specific user, passing each output
+/// line to `on_line`. The password only travels over sudo's stdin pipe.
+pub fn run_command_as_user_with_sudo(
+    username: &str,
+    cmd: &[String],
+    password: &Secret,
+    on_line: &dyn Fn(&str),
+) -> Result<String> {
+    let cmd = strip_sudo(cmd)?;
     if cmd.is_empty() {
         return Err(OnboardError::Command("Empty command".to_string()));
     }
 
     info!("Running sudo command as {}: {:?}", username, cmd);
 
-    // Build the command string - the first element should be the command, rest are args
-    // We need to run: su -l username -c "echo password | sudo -S command args..."
-    let command_str = cmd.iter()
-        .map(|s| shell_escape::escape(s.into()).to_string())
-        .collect::<Vec<_>>()
-        .join(" ");
+    // An empty prompt keeps sudo out of the output
+    let sudo_cmd: Vec<String> = ["sudo", "-S", "-p", "", "--"]
+        .into_iter()
+        .map(String::from)
+        .chain(cmd.iter().cloned())
+        .collect();
+    // sudo reads the password up to the end of the line
+    let mut input = Zeroizing::new(Vec::with_capacity(password.expose().len() + 1));
+    input.extend_from_slice(password.expose().as_bytes());
+    input.push(b'\n');
+    let output = run_streaming(&mut as_user(username, &sudo_cmd)?, Some(&input), on_line)?;
+    command_result(output)
+}
 
-    // Use -S flag to read password from stdin
-    let sudo_cmd = format!("echo {} | sudo -S {}", shell_escape::escape(password.into()), command_str);
+fn command_result(output: StreamedOutput) -> Result<String> {
+    if output.status.success() {
+        Ok(output.stdout)
+    } else {
+        Err(OnboardError::Exited {
+            code: output.status.code(),
+            output: if output.stderr.is_empty() { output.stdout } else { output.stderr },
+        })
+    }
+}
 
-    let output = Command::new("su")
-        .args(["-l", username, "-c", &sudo_cmd])
-        .output()?;
+/// Everything a streamed command printed, once it has exited
+struct StreamedOutput {
+    status: ExitStatus,
+    stdout: String,
+    stderr: String,
+}
 
-    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+/// Run a command, handing stdout and stderr lines to `on_line` as they are printed.
+/// `input` is written to its stdin, which is closed right after; without it the
+/// command gets no input at all.
+fn run_streaming(command: &mut Command, input: Option<&Zeroizing<Vec<u8>>>, on_line: &dyn Fn(&str)) -> Result<StreamedOutput> {
+    let mut child = command
+        .stdin(if input.is_some() { Stdio::piped() } else { Stdio::null() })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
 
-    if output.status.success() {
-        Ok(stdout)
-    } else {
-        // Filter out password prompt from stderr
-        let filtered_stderr = stderr
-            .lines()
-            .filter(|line| !line.contains("[sudo]") && !line.contains("password"))
-            .collect::<Vec<_>>()
-            .join("\n");
+    if let Some(input) = input
+        && let Some(mut stdin) = child.stdin.take()
+    {
+        // A command that does not read it fails on its own; nothing to report here
+        let _ = stdin.write_all(input);
+    }
 
-        Err(OnboardError::Command(format!(
-            "Command failed: {}",
-            if filtered_stderr.is_empty() { stdout } else { filtered_stderr }
-        )))
+    let (tx, rx) = mpsc::channel();
+    let mut readers = Vec::new();
+    if let Some(stdout) = child.stdout.take() {
+        readers.push(spawn_line_reader(stdout, false, tx.clone()));
     }
+    if let Some(stderr) = child.stderr.take() {
+        readers.push(spawn_line_reader(stderr, true, tx));
+    }
+
+    // Ends once both pipes are closed
+    let mut stdout = String::new();
+    let mut stderr = String::new();
+    for (is_stderr, line) in rx {
+        on_line(&line);
+        let collected = if is_stderr { &mut stderr } else { &mut stdout };
+        collected.push_str(&line);
+        collected.push('\n');
+    }
+    for reader in readers {
+        let _ = reader.join();
+    }
+
+    let status = child.wait()?;
+    Ok(StreamedOutput { status, stdout, stderr })
+}
+
+fn spawn_line_reader<R: Read + Send + 'static>(
+    reader: R,
+    is_stderr: bool,
+    tx: mpsc::Sender<(bool, String)>,
+) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        for line in BufReader::new(reader).split(b'\n') {
+            let Ok(line) = line else {
+                break;
+            };
+            // Progress bars redraw with \r; keep what a terminal would show last
+            let line = String::from_utf8_lossy(&line);
+            let shown = line.trim_end_matches('\r').rsplit('\r').next().unwrap_or_default();
+            if tx.send((is_stderr, shown.to_string())).is_err() {
+                break;
+            }
+        }
+    })
 }
 
 /// Remove the initial_session block from greetd config
@@ -0,0 +1,90 @@
+//! Checking hostnames and filling in the `[hostname]` default
+
+use crate::i18n::Catalog;
+
+/// Longest static hostname the kernel accepts
+const MAX_HOSTNAME_LEN: usize = 64;
+
+/// Longest single label, between dots (RFC 1123)
+const MAX_LABEL_LEN: usize = 63;
+
+/// Why a hostname was refused
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidHostname {
+    Empty,
+    TooLong,
+    EmptyLabel,
+    LabelTooLong,
+    Characters,
+    DashAtEdge,
+}
+
+impl InvalidHostname {
+    pub fn message(self, catalog: &Catalog) -> String {
+        match self {
+            InvalidHostname::Empty => catalog.tr("msg-hostname-required"),
+            InvalidHostname::TooLong => catalog.tr_args("msg-hostname-length", &[("max", MAX_HOSTNAME_LEN.into())]),
+            InvalidHostname::EmptyLabel => catalog.tr("msg-hostname-empty-part"),
+            InvalidHostname::LabelTooLong => catalog.tr_args("msg-hostname-part-length", &[("max", MAX_LABEL_LEN.into())]),
+            InvalidHostname::Characters => catalog.tr("msg-hostname-chars"),
+            InvalidHostname::DashAtEdge => catalog.tr("msg-hostname-dash"),
+        }
+    }
+}
+
+/// Why `hostname` is not a valid static hostname, if it is not. Labels between dots
+/// are letters, digits and dashes, and do not start or end with a dash (RFC 1123).
+pub fn check(hostname: &str) -> Result<(), InvalidHostname> {
+    if hostname.is_empty() {
+        return Err(InvalidHostname::Empty);
+    }
+    if hostname.len() > MAX_HOSTNAME_LEN {
+        return Err(InvalidHostname::TooLong);
+    }
+    for label in hostname.split('.') {
+        if label.is_empty() {
+            return Err(InvalidHostname::EmptyLabel);
+        }
+        if label.len() > MAX_LABEL_LEN {
+            return Err(InvalidHostname::LabelTooLong);
+        }
+        if !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+            return Err(InvalidHostname::Characters);
+        }
+        if label.starts_with('-') || label.ends_with('-') {
+            return Err(InvalidHostname::DashAtEdge);
+        }
+    }
+    Ok(())
+}
+
+/// `text` as a hostname label: lowercase, runs of anything else turned into one dash
+pub fn sanitize(text: &str) -> String {
+    let mut label = String::new();
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() {
+            label.push(c.to_ascii_lowercase());
+        } else if !label.is_empty() && !label.ends_with('-') {
+            label.push('-');
+        }
+    }
+    label.truncate(MAX_LABEL_LEN);
+    label.trim_end_matches('-').to_string()
+}
+
+/// Fill `{serial}` and `{username}` into a `[hostname]` default. Placeholders without a
+/// value are dropped along with the dash next to them.
+pub fn expand(template: &str, serial: Option<&str>, username: &str) -> String {
+    let mut hostname = template.to_string();
+    for (placeholder, value) in [("{serial}", serial.map(sanitize)), ("{username}", Some(sanitize(username)))] {
+        match value.filter(|v| !v.is_empty()) {
+            Some(value) => hostname = hostname.replace(placeholder, &value),
+            None => {
+                for gone in [format!("-{placeholder}"), format!("{placeholder}-"), placeholder.to_string()] {
+                    hostname = hostname.replace(&gone, "");
+                }
+            }
+        }
+    }
+    hostname
+}
@@ -1,37 +1,105 @@
+mod check;
 mod config;
+mod custom;
+pub mod dbus;
 mod error;
 mod executor;
+mod hostname;
+mod progress;
+mod report;
+mod schedule;
 mod service;
+mod state;
 mod steps;
 pub mod ui;
+mod unattended;
 mod widgets;
 
+pub use check::{check_file, check_layers, check_str, ConfigProblem};
 pub use config::OnboardConfig;
-pub use steps::StepResult;
+pub use dbus::DbusService;
+pub use error::OnboardError;
+pub use progress::{Progress, ProgressFormat, ProgressParser};
+pub use report::{CommandRun, InstallReport, RunOutcome, StepReport};
+pub use service::{OnboardService, Password, Secret, ServiceOp};
+pub use state::OnboardState;
+pub use steps::{StepId, StepResult};
+pub use unattended::{run_unattended, Answers, UnattendedError};
 pub use widgets::StatusBarState;
 
 use std::sync::Arc;
 use tokio::sync::mpsc;
 
-use service::{OnboardService, ServiceOp};
+use config::{CustomStep, CustomStepKind};
+use custom::StepValues;
+use schedule::{PackageJob, Schedule};
+use state::StepRecord;
 
 #[derive(Debug)]
 pub enum ExecutionMessage {
     TaskStarted(usize),
     TaskSuccess(usize, Option<String>),
     TaskFailed(usize, String),
+    /// Task not run, with the reason (e.g. a package it depends on was skipped)
+    TaskSkipped(usize, String),
+    /// One line printed by a running task
+    TaskOutput(usize, String),
+    /// An Update command is done, after its TaskSuccess or TaskFailed; for the report
+    CommandFinished {
+        idx: usize,
+        exit_code: Option<i32>,
+        attempts: u32,
+        duration: std::time::Duration,
+    },
     UserCreated(Option<String>),
     ReviewComplete { any_failed: bool },
     UpdateComplete { any_failed: bool },
     StepComplete { step_result: StepResult },
 }
 
+use crate::i18n::{Catalog, Domain};
 use crate::ui::Theme;
-use crate::vim::{InputBuffer, ModeAction, VimMode};
+use crate::vim::{command_names, find_command, CommandLine, CommandSpec, InputBuffer, ModeAction, NormalParser, Parsed, VimMode};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use steps::StepId;
 use tracing::warn;
 
+/// A `:` command typed in the onboard app
+#[derive(Debug, Clone, PartialEq)]
+enum OnboardCommand {
+    Start,
+    Next,
+    Skip,
+    Goto(Option<String>),
+    Cancel,
+    Reboot,
+    Poweroff,
+    Help,
+    Submit,
+    Finish,
+}
+
+/// Every `:` command, its aliases and what it parses to
+const COMMANDS: &[CommandSpec<OnboardCommand>] = &[
+    CommandSpec::new("cancel", &[], |_| OnboardCommand::Cancel),
+    CommandSpec::new("create", &[], |_| OnboardCommand::Submit),
+    CommandSpec::new("done", &[], |_| OnboardCommand::Finish),
+    CommandSpec::new("finish", &[], |_| OnboardCommand::Finish),
+    CommandSpec::new("goto", &[], OnboardCommand::Goto),
+    CommandSpec::new("help", &["h"], |_| OnboardCommand::Help),
+    CommandSpec::new("install", &[], |_| OnboardCommand::Submit),
+    CommandSpec::new("login", &[], |_| OnboardCommand::Finish),
+    CommandSpec::new("next", &["n"], |_| OnboardCommand::Next),
+    CommandSpec::new("poweroff", &[], |_| OnboardCommand::Poweroff),
+    CommandSpec::new("quit", &["q"], |_| OnboardCommand::Cancel),
+    CommandSpec::new("reboot", &[], |_| OnboardCommand::Reboot),
+    CommandSpec::new("run", &[], |_| OnboardCommand::Start),
+    CommandSpec::new("shutdown", &[], |_| OnboardCommand::Poweroff),
+    CommandSpec::new("skip", &["s"], |_| OnboardCommand::Skip),
+    CommandSpec::new("start", &[], |_| OnboardCommand::Start),
+    CommandSpec::new("submit", &[], |_| OnboardCommand::Submit),
+    CommandSpec::new("update", &[], |_| OnboardCommand::Submit),
+];
+
 /// Actions that can be triggered by the onboard app
 #[derive(Debug)]
 pub enum OnboardAction {
@@ -51,6 +119,10 @@ pub enum OnboardAction {
     ExitToLogin,
     /// In dryrun mode: show fake reboot animation then transition to login
     TransitionToLogin,
+    /// Run a failed Review or Update task again, then the tasks after it
+    RetryTask(usize),
+    /// Give up on a failed task and carry on with the rest
+    SkipTask(usize),
 }
 
 /// Which panel is focused
@@ -71,10 +143,31 @@ pub enum ContentFocus {
     Picker,
     /// Focused on an input field (0=username, 1=password, 2=confirm)
     InputField(usize),
+    /// Focused on a toggle: a Preferences setting or a multi-choice option
+    Toggle(usize),
     /// No specific focus (viewing tasks/info)
     None,
 }
 
+/// On/off settings on the Preferences step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreferenceToggle {
+    /// Keep the clock in sync with network time servers
+    Ntp,
+    /// Create a login keyring for the new user
+    Keyring,
+}
+
+impl PreferenceToggle {
+    /// Catalog message with the toggle's label
+    pub fn message_id(&self) -> &'static str {
+        match self {
+            PreferenceToggle::Ntp => "preference-ntp",
+            PreferenceToggle::Keyring => "preference-keyring",
+        }
+    }
+}
+
 /// Message displayed to the user
 pub struct Message {
     pub text: String,
@@ -87,17 +180,21 @@ pub enum ConfirmAction {
     Reboot,
     Poweroff,
     Cancel,
+    /// Offered at startup when an interrupted setup left progress behind
+    Resume,
 }
 
 /// Main onboard application state
 pub struct OnboardApp {
     pub config: OnboardConfig,
     pub theme: Theme,
+    /// UI text, switched when a locale is chosen
+    pub catalog: Catalog,
     service: Arc<dyn OnboardService>,
 
     // Vim mode state
     pub vim_mode: VimMode,
-    pub command_buffer: InputBuffer,
+    pub command_line: CommandLine,
 
     // Panel navigation
     pub panel_focus: PanelFocus,
@@ -116,6 +213,12 @@ pub struct OnboardApp {
     pub username: InputBuffer,
     pub password: InputBuffer,
     pub password_confirm: InputBuffer,
+    /// Hash from an answer file, used while the password fields are empty
+    password_hash: Option<String>,
+
+    // Form fields for the Hostname step
+    pub hostname: InputBuffer,
+    pub pretty_hostname: InputBuffer,
 
     // Sudo password for commands that require it (entered on Update step)
     pub sudo_password: InputBuffer,
@@ -124,13 +227,39 @@ pub struct OnboardApp {
 
     // Task execution state
     pub tasks: Vec<TaskStatus>,
+    /// Step whose tasks are in `tasks`
+    pub task_step: Option<StepId>,
+    // Review tasks as run, so a failed one can be retried alone
+    review_jobs: Vec<ReviewJob>,
+    /// Tasks the user skipped or that failed with `continue_on_error`, for the summary
+    pub skipped_tasks: Vec<String>,
+    /// Update commands run so far, for the install report
+    pub command_runs: Vec<CommandRun>,
     pub current_task: Option<usize>,
     pub is_executing: bool,
 
+    // Output pane on the Update step: task shown, first visible line (None follows the tail),
+    // and the pane height from the last draw
+    pub log_task: usize,
+    pub log_scroll: Option<usize>,
+    pub log_height: usize,
+    /// Output pane opened over the whole step with `o`
+    pub log_expanded: bool,
+
     // Selected values for display
     pub selected_locale: Option<String>,
     pub selected_keyboard: Option<String>,
     pub selected_timezone: Option<String>,
+    pub selected_hostname: Option<String>,
+    pub selected_pretty_hostname: Option<String>,
+    // Preferences toggles, applied when the config offers them
+    pub ntp_sync: bool,
+    pub create_keyring: bool,
+    /// Results of finished custom steps, for command placeholders
+    pub step_values: StepValues,
+    // Custom step being edited: multi-choice options and form fields
+    pub custom_checked: Vec<bool>,
+    pub custom_fields: Vec<InputBuffer>,
 
     // UI state
     pub message: Option<Message>,
@@ -148,6 +277,8 @@ pub struct OnboardApp {
 
     // Created user info (set after user creation step)
     pub created_username: Option<String>,
+    // Account being created, which an interrupted run may have left without a password
+    pub pending_username: Option<String>,
 
     // Step results tracking
     pub step_results: Vec<StepResult>,
@@ -175,6 +306,21 @@ pub struct OnboardApp {
     dryrun_sim_task_idx: usize,
     dryrun_sim_progress: u8,
     dryrun_sim_callback: Option<DryrunCallback>,
+
+    // Partially typed normal-mode command for text fields
+    normal: NormalParser,
+
+    // Progress from an interrupted run, until the user accepts or discards it
+    resume_state: Option<OnboardState>,
+    // Last progress written to the state file
+    saved_state: OnboardState,
+    // Update commands that already succeeded (by command line), and the batch being run
+    pub completed_commands: Vec<String>,
+    running_commands: Vec<config::CommandConfig>,
+    // Packages of the batch being run, with their task indices
+    running_packages: Vec<PackageJob>,
+    // Progress readers for the batch being run, by task index
+    progress_parsers: Vec<Option<ProgressParser>>,
 }
 
 /// Callback to execute after dryrun simulation completes
@@ -184,6 +330,103 @@ pub enum DryrunCallback {
     CompleteReview,
 }
 
+/// One task of the Review step
+#[derive(Clone)]
+enum ReviewJob {
+    CreateUser {
+        username: String,
+        password: Password,
+        groups: Vec<String>,
+        shell: String,
+        /// Created by an earlier run, so only reported
+        already_created: bool,
+        /// An interrupted run may have added the account but not set its password
+        pending: bool,
+    },
+    SetLocale(String),
+    SetKeymap(String),
+    SetTimezone(String),
+    SetHostname {
+        hostname: String,
+        pretty: Option<String>,
+    },
+    SetNtp(bool),
+    CreateKeyring {
+        username: String,
+        password: Arc<Secret>,
+    },
+}
+
+impl ReviewJob {
+    /// Name in the task list
+    fn name(&self, catalog: &Catalog) -> String {
+        match self {
+            ReviewJob::CreateUser { username, .. } => {
+                catalog.tr_args("task-create-user", &[("username", username.as_str().into())])
+            }
+            ReviewJob::SetLocale(locale) => catalog.tr_args("task-set-locale", &[("locale", locale.as_str().into())]),
+            ReviewJob::SetKeymap(keymap) => catalog.tr_args("task-set-keymap", &[("keymap", keymap.as_str().into())]),
+            ReviewJob::SetTimezone(tz) => catalog.tr_args("task-set-timezone", &[("timezone", tz.as_str().into())]),
+            ReviewJob::SetHostname { hostname, .. } => {
+                catalog.tr_args("task-set-hostname", &[("hostname", hostname.as_str().into())])
+            }
+            ReviewJob::SetNtp(true) => catalog.tr("task-ntp-on"),
+            ReviewJob::SetNtp(false) => catalog.tr("task-ntp-off"),
+            ReviewJob::CreateKeyring { username, .. } => {
+                catalog.tr_args("task-create-keyring", &[("username", username.as_str().into())])
+            }
+        }
+    }
+
+    fn op(&self) -> ServiceOp {
+        match self {
+            ReviewJob::CreateUser { username, groups, shell, .. } => ServiceOp::CreateUser {
+                username: username.clone(),
+                groups: groups.clone(),
+                shell: shell.clone(),
+            },
+            ReviewJob::SetLocale(locale) => ServiceOp::SetLocale { locale: locale.clone() },
+            ReviewJob::SetKeymap(keymap) => ServiceOp::SetKeymap { keymap: keymap.clone() },
+            ReviewJob::SetTimezone(tz) => ServiceOp::SetTimezone { timezone: tz.clone() },
+            ReviewJob::SetHostname { hostname, pretty } => ServiceOp::SetHostname {
+                hostname: hostname.clone(),
+                pretty: pretty.clone(),
+            },
+            ReviewJob::SetNtp(enabled) => ServiceOp::SetNtp { enabled: *enabled },
+            ReviewJob::CreateKeyring { username, .. } => ServiceOp::CreateKeyring { username: username.clone() },
+        }
+    }
+
+    /// Apply the job; `true` when there was nothing to do
+    fn run(&self, service: &dyn OnboardService) -> error::Result<bool> {
+        match self {
+            ReviewJob::CreateUser { username, password, groups, shell, already_created, pending } => {
+                if *already_created {
+                    return Ok(true);
+                }
+                if service.user_exists(username) {
+                    if !*pending {
+                        return Err(OnboardError::UserCreation(format!("user '{username}' already exists")));
+                    }
+                    // useradd finished before the interruption, chpasswd may not have
+                    return service.set_password(username, password).map(|()| false);
+                }
+                service.create_user(username, password, groups, shell).map(|()| false)
+            }
+            ReviewJob::SetLocale(locale) => service.set_locale(locale).map(|()| false),
+            ReviewJob::SetKeymap(keymap) => service.set_keymap(keymap).map(|()| false),
+            ReviewJob::SetTimezone(tz) => service.set_timezone(tz).map(|()| false),
+            ReviewJob::SetHostname { hostname, pretty } => {
+                service.set_hostname(hostname, pretty.as_deref()).map(|()| false)
+            }
+            ReviewJob::SetNtp(enabled) => service.set_ntp(*enabled).map(|()| false),
+            ReviewJob::CreateKeyring { username, password } => {
+                service.create_keyring(username, password).map(|()| false)
+            }
+        }
+    }
+}
+
 /// A menu item in the setup wizard
 #[derive(Debug, Clone)]
 pub struct MenuItem {
@@ -199,10 +442,31 @@ pub struct TaskStatus {
     pub name: String,
     pub status: TaskState,
     pub output: Option<String>,
-    /// Progress percentage (0-100) for dryrun simulation
+    /// Progress percentage (0-100), simulated in dryrun and parsed from output otherwise
     pub progress: Option<u8>,
+    /// Item being worked on and how many there are, e.g. packages in a transaction
+    pub count: Option<(u32, u32)>,
     /// System command for status bar display (dryrun mode)
     pub command: Option<String>,
+    /// Lines printed so far, oldest dropped past `MAX_TASK_LOG`
+    pub log: Vec<String>,
+}
+
+/// Output lines kept per task
+const MAX_TASK_LOG: usize = 1000;
+
+impl TaskStatus {
+    /// Latest output line, shown next to the task name
+    pub fn last_line(&self) -> Option<&str> {
+        self.log.last().map(String::as_str)
+    }
+
+    fn push_log(&mut self, line: String) {
+        if self.log.len() >= MAX_TASK_LOG {
+            self.log.remove(0);
+        }
+        self.log.push(line);
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -211,19 +475,32 @@ pub enum TaskState {
     Running,
     Success,
     Failed,
+    /// Given up on by the user after it failed
+    Skipped,
 }
 
 impl OnboardApp {
     pub fn new(config: OnboardConfig) -> Self {
+        // Create the appropriate service based on dryrun mode
+        let svc = service::create_service(config.general.dryrun, config.general.system_backend);
+        Self::with_service(config, svc)
+    }
+
+    /// Build the app on a given system backend instead of the one `dryrun` selects
+    pub fn with_service(config: OnboardConfig, svc: Arc<dyn OnboardService>) -> Self {
         let menu_items = Self::build_menu_items(&config);
         let step_results = Self::initial_step_results(&menu_items);
 
-        // Create the appropriate service based on dryrun mode
-        let svc = service::create_service(config.general.dryrun);
-
         // Check network status immediately
         let network_connected = svc.check_network();
 
+        // Offer to pick up where an interrupted run stopped
+        let resume_state = if config.general.dryrun {
+            None
+        } else {
+            Some(OnboardState::load(&config.general.state_file)).filter(|s| s.has_progress())
+        };
+
         // Initialize per-package selection based on defaults (before moving config)
         let update_package_selected: Vec<Vec<bool>> = config.updates.iter()
             .map(|cat| {
@@ -233,12 +510,16 @@ impl OnboardApp {
             })
             .collect();
 
+        let catalog = Catalog::english(Domain::Onboard);
+        let status_bar = StatusBarState::welcome(&catalog);
+
         Self {
+            theme: config.theme.build(),
             config,
-            theme: Theme::default(),
+            catalog,
             service: svc,
             vim_mode: VimMode::Normal,
-            command_buffer: InputBuffer::new(),
+            command_line: CommandLine::new(),
             panel_focus: PanelFocus::Welcome,
             content_focus: ContentFocus::None,
             menu_items,
@@ -249,17 +530,35 @@ impl OnboardApp {
             username: InputBuffer::new(),
             password: InputBuffer::masked(),
             password_confirm: InputBuffer::masked(),
+            password_hash: None,
+            hostname: InputBuffer::new(),
+            pretty_hostname: InputBuffer::new(),
             sudo_password: InputBuffer::masked(),
             sudo_password_needed: false,
             sudo_password_entered: false,
             tasks: Vec::new(),
+            task_step: None,
+            review_jobs: Vec::new(),
+            skipped_tasks: Vec::new(),
+            command_runs: Vec::new(),
             current_task: None,
             is_executing: false,
+            log_task: 0,
+            log_scroll: None,
+            log_height: 0,
+            log_expanded: false,
             selected_locale: None,
             selected_keyboard: None,
             selected_timezone: None,
+            selected_hostname: None,
+            selected_pretty_hostname: None,
+            ntp_sync: true,
+            create_keyring: true,
+            step_values: StepValues::new(),
+            custom_checked: Vec::new(),
+            custom_fields: Vec::new(),
             message: None,
-            confirm_action: None,
+            confirm_action: resume_state.as_ref().map(|_| ConfirmAction::Resume),
             show_help: false,
             should_exit: false,
             setup_started: false,
@@ -267,6 +566,7 @@ impl OnboardApp {
             spinner_frame: 0,
             network_connected,
             created_username: None,
+            pending_username: None,
             step_results,
             review_completed: false,
             update_completed: false,
@@ -274,11 +574,18 @@ impl OnboardApp {
             update_package_selected,
             update_category_cursor: 0,
             update_package_cursor: None,
-            status_bar: StatusBarState::welcome(),
+            status_bar,
             dryrun_sim_active: false,
             dryrun_sim_task_idx: 0,
             dryrun_sim_progress: 0,
             dryrun_sim_callback: None,
+            normal: NormalParser::new(),
+            resume_state,
+            saved_state: OnboardState::default(),
+            completed_commands: Vec::new(),
+            running_commands: Vec::new(),
+            running_packages: Vec::new(),
+            progress_parsers: Vec::new(),
         }
     }
 
@@ -313,7 +620,17 @@ impl OnboardApp {
             has_form: true,
         });
 
-        // 3. Locale (optional)
+        // 3. Hostname (optional)
+        if config.hostname.enabled {
+            items.push(MenuItem {
+                id: StepId::Hostname,
+                required: false,
+                has_picker: false,
+                has_form: true,
+            });
+        }
+
+        // 4. Locale (optional)
         if config.locale.enabled {
             items.push(MenuItem {
                 id: StepId::Locale,
@@ -323,7 +640,7 @@ impl OnboardApp {
             });
         }
 
-        // 4. Keyboard (optional)
+        // 5. Keyboard (optional)
         if config.keyboard.enabled {
             items.push(MenuItem {
                 id: StepId::Keyboard,
@@ -333,17 +650,18 @@ impl OnboardApp {
             });
         }
 
-        // 5. Preferences/Timezone (optional)
-        if config.preferences.timezone_enabled {
+        // 6. Preferences: timezone, clock sync and keyring (optional)
+        let prefs = &config.preferences;
+        if prefs.timezone_enabled || prefs.ntp_enabled || prefs.keyring_enabled {
             items.push(MenuItem {
                 id: StepId::Preferences,
                 required: false,
-                has_picker: true,
+                has_picker: prefs.timezone_enabled,
                 has_form: false,
             });
         }
 
-        // 6. Review - always present, shows summary and applies config
+        // 7. Review - always present, shows summary and applies config
         items.push(MenuItem {
             id: StepId::Review,
             required: true,
@@ -351,7 +669,7 @@ impl OnboardApp {
             has_form: false,
         });
 
-        // 7. Update - locked until Review completes, only shown if update categories are configured
+        // 8. Update - locked until Review completes, only shown if update categories are configured
         if !config.updates.is_empty() {
             items.push(MenuItem {
                 id: StepId::Update,
@@ -361,7 +679,7 @@ impl OnboardApp {
             });
         }
 
-        // 8. Login - locked until Update completes or is skipped
+        // 9. Login - locked until Update completes or is skipped
         items.push(MenuItem {
             id: StepId::Reboot,
             required: true,
@@ -369,7 +687,64 @@ impl OnboardApp {
             has_form: false,
         });
 
-        items
+        // Steps from [[steps]] slot in among the built-in ones
+        let order = custom::place(items.iter().map(|item| item.id).collect(), &config.steps);
+        let mut builtin = items.into_iter();
+        order.into_iter()
+            .filter_map(|id| match id {
+                StepId::Custom(idx) => {
+                    let step = &config.steps[idx];
+                    Some(MenuItem {
+                        id,
+                        required: step.required,
+                        has_picker: matches!(step.kind, CustomStepKind::Choice { .. }),
+                        has_form: matches!(step.kind, CustomStepKind::Form { .. }),
+                    })
+                }
+                _ => builtin.next(),
+            })
+            .collect()
+    }
+
+    /// Sidebar name of a step
+    pub fn step_name(&self, id: StepId) -> &str {
+        match id {
+            StepId::Custom(idx) => &self.config.steps[idx].title,
+            id => id.short_name(),
+        }
+    }
+
+    /// Step name in the UI language; custom steps keep their configured title
+    pub fn step_title(&self, id: StepId) -> String {
+        match id {
+            StepId::Custom(idx) => self.config.steps[idx].title.clone(),
+            id => self.catalog.tr(id.message_id()),
+        }
+    }
+
+    /// Names `:goto` takes for a step, lowercased: the title in the sidebar, then the
+    /// English name when that differs
+    fn goto_names(&self, id: StepId) -> Vec<String> {
+        let mut names = vec![self.step_title(id).to_lowercase()];
+        let name = self.step_name(id).to_lowercase();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+        names
+    }
+
+    /// Switch the UI text to `catalog`
+    pub fn set_catalog(&mut self, catalog: Catalog) {
+        self.catalog = catalog;
+        self.update_status_bar();
+    }
+
+    /// The `[[steps]]` entry shown now, if the current step is one
+    pub fn custom_step(&self) -> Option<&CustomStep> {
+        match self.current_step_id() {
+            Some(StepId::Custom(idx)) => self.config.steps.get(idx),
+            _ => None,
+        }
     }
 
     /// Get the currently selected menu item
@@ -416,6 +791,12 @@ impl OnboardApp {
     }
 
     pub fn handle_key(&mut self, key: KeyEvent) -> Option<OnboardAction> {
+        let action = self.dispatch_key(key);
+        self.persist();
+        action
+    }
+
+    fn dispatch_key(&mut self, key: KeyEvent) -> Option<OnboardAction> {
         // Clear message on any key (unless working)
         if self.message.is_some() && !self.is_executing {
             self.message = None;
@@ -437,11 +818,21 @@ impl OnboardApp {
             return None;
         }
 
+        // The output pane stays scrollable while commands run
+        if self.handle_log_key(key) {
+            self.update_status_bar();
+            return None;
+        }
+
         // Don't handle input while executing
         if self.is_executing {
             return None;
         }
 
+        if let Some(action) = self.task_action_key(key) {
+            return Some(action);
+        }
+
         // Handle based on vim mode
         let result = match self.vim_mode {
             VimMode::Normal => self.handle_normal_mode(key),
@@ -454,6 +845,92 @@ impl OnboardApp {
         result
     }
 
+    /// Whether the current step is showing its tasks (Review or Update)
+    pub fn shows_task_log(&self) -> bool {
+        self.task_step.is_some() && self.current_step_id() == self.task_step && !self.tasks.is_empty()
+    }
+
+    fn task_keys_active(&self) -> bool {
+        self.shows_task_log()
+            && self.panel_focus == PanelFocus::Content
+            && self.vim_mode == VimMode::Normal
+    }
+
+    /// Selected task, when it failed and waits for the user to retry or skip it
+    pub fn selected_failed_task(&self) -> Option<usize> {
+        let task = self.tasks.get(self.log_task)?;
+        (!self.is_executing && task.status == TaskState::Failed).then_some(self.log_task)
+    }
+
+    /// Scroll the task output pane: j/k line, Ctrl+d/Ctrl+u half page, g/G top/tail,
+    /// J/K next/previous task, o (or Esc) to expand and restore it
+    fn handle_log_key(&mut self, key: KeyEvent) -> bool {
+        if !self.task_keys_active() {
+            return false;
+        }
+
+        let half_page = (self.log_height / 2).max(1) as isize;
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Char('d') if ctrl => self.scroll_log(half_page),
+            KeyCode::Char('u') if ctrl => self.scroll_log(-half_page),
+            KeyCode::Char('j') | KeyCode::Down => self.scroll_log(1),
+            KeyCode::Char('k') | KeyCode::Up => self.scroll_log(-1),
+            KeyCode::Char('g') => self.log_scroll = Some(0),
+            KeyCode::Char('G') => self.log_scroll = None,
+            KeyCode::Char('J') if self.log_task + 1 < self.tasks.len() => {
+                self.log_task += 1;
+                self.log_scroll = None;
+            }
+            KeyCode::Char('K') if self.log_task > 0 => {
+                self.log_task -= 1;
+                self.log_scroll = None;
+            }
+            KeyCode::Char('J' | 'K') => {}
+            KeyCode::Char('o') => self.log_expanded = !self.log_expanded,
+            KeyCode::Esc if self.log_expanded => self.log_expanded = false,
+            _ => return false,
+        }
+        true
+    }
+
+    /// r retries and s skips the selected task after the batch stopped on it
+    fn task_action_key(&mut self, key: KeyEvent) -> Option<OnboardAction> {
+        if !self.task_keys_active() || self.is_executing {
+            return None;
+        }
+        match key.code {
+            KeyCode::Char('r') => self.selected_failed_task().map(OnboardAction::RetryTask),
+            KeyCode::Char('s') => {
+                let task = self.tasks.get(self.log_task)?;
+                matches!(task.status, TaskState::Failed | TaskState::Pending)
+                    .then_some(OnboardAction::SkipTask(self.log_task))
+            }
+            _ => None,
+        }
+    }
+
+    /// First output line to show for the selected task, clamped to the pane
+    pub fn log_top(&self) -> usize {
+        let len = self.tasks.get(self.log_task).map_or(0, |t| t.log.len());
+        let tail = len.saturating_sub(self.log_height);
+        self.log_scroll.map_or(tail, |top| top.min(tail))
+    }
+
+    fn scroll_log(&mut self, delta: isize) {
+        let len = self.tasks.get(self.log_task).map_or(0, |t| t.log.len());
+        let tail = len.saturating_sub(self.log_height);
+        let top = self.log_top().saturating_add_signed(delta).min(tail);
+        // Reaching the bottom resumes following new output
+        self.log_scroll = if top >= tail { None } else { Some(top) };
+    }
+
+    fn reset_log_pane(&mut self) {
+        self.log_task = 0;
+        self.log_scroll = None;
+        self.log_expanded = false;
+    }
+
     fn handle_normal_mode(&mut self, key: KeyEvent) -> Option<OnboardAction> {
         // Handle Ctrl+h/l for panel navigation
         if key.modifiers.contains(KeyModifiers::CONTROL) {
@@ -470,11 +947,32 @@ impl OnboardApp {
             }
         }
 
+        // Vim editing on the focused text field; a bare h/l still moves between panels
+        if self.panel_focus == PanelFocus::Content
+            && matches!(self.content_focus, ContentFocus::InputField(_))
+            && (self.normal.is_pending()
+                || !matches!(key.code, KeyCode::Char('h' | 'l') | KeyCode::Left | KeyCode::Right))
+        {
+            match self.normal.feed(key) {
+                Parsed::Consumed => return None,
+                Parsed::Command(command) => {
+                    if let Some(input) = self.current_input_buffer() {
+                        command.apply(input);
+                    }
+                    if command.enters_insert() {
+                        self.vim_mode = self.vim_mode.transition(ModeAction::EnterInsert);
+                    }
+                    return None;
+                }
+                Parsed::Unhandled => {}
+            }
+        }
+
         match key.code {
             // Enter command mode
             KeyCode::Char(':') => {
                 self.vim_mode = self.vim_mode.transition(ModeAction::EnterCommand);
-                self.command_buffer.clear();
+                self.command_line.clear();
             }
 
             // Navigation
@@ -486,15 +984,10 @@ impl OnboardApp {
             }
 
             // Enter insert mode for text input
-            KeyCode::Char('i') | KeyCode::Char('a') => {
-                if self.panel_focus == PanelFocus::Content {
-                    match self.content_focus {
-                        ContentFocus::InputField(_) | ContentFocus::Picker => {
-                            self.vim_mode = self.vim_mode.transition(ModeAction::EnterInsert);
-                        }
-                        _ => {}
-                    }
-                }
+            KeyCode::Char('i') | KeyCode::Char('a')
+                if self.panel_focus == PanelFocus::Content && self.content_focus == ContentFocus::Picker =>
+            {
+                self.vim_mode = self.vim_mode.transition(ModeAction::EnterInsert);
             }
 
             // Action / Select
@@ -510,15 +1003,10 @@ impl OnboardApp {
             }
 
             // Go back
-            KeyCode::Char('h') | KeyCode::Left => {
-                if self.panel_focus == PanelFocus::Content {
-                    self.focus_sidebar();
-                }
-            }
-            KeyCode::Esc => {
-                if self.panel_focus == PanelFocus::Content {
-                    self.focus_sidebar();
-                }
+            KeyCode::Char('h') | KeyCode::Left | KeyCode::Esc
+                if self.panel_focus == PanelFocus::Content =>
+            {
+                self.focus_sidebar();
             }
 
             // Tab to cycle through fields
@@ -538,33 +1026,42 @@ impl OnboardApp {
             }
 
             // Quick select by number
-            KeyCode::Char(c) if c.is_ascii_digit() => {
-                if self.setup_started && self.panel_focus == PanelFocus::Sidebar {
-                    let num = c.to_digit(10).unwrap() as usize;
-                    if num > 0 && num <= self.menu_items.len() {
-                        self.selected_step = num - 1;
-                        self.load_step_content();
-                    }
+            KeyCode::Char(c)
+                if c.is_ascii_digit() && self.setup_started && self.panel_focus == PanelFocus::Sidebar =>
+            {
+                let num = c.to_digit(10).unwrap() as usize;
+                if num > 0 && num <= self.menu_items.len() {
+                    self.selected_step = num - 1;
+                    self.load_step_content();
                 }
             }
 
             // Space to toggle selection (for Update step packages)
-            KeyCode::Char(' ') => {
-                if self.panel_focus == PanelFocus::Content {
-                    if let Some(StepId::Update) = self.current_step_id() {
-                        self.toggle_update_item();
-                    }
-                }
+            KeyCode::Char(' ')
+                if self.panel_focus == PanelFocus::Content
+                    && self.current_step_id() == Some(StepId::Update) =>
+            {
+                self.toggle_update_item();
             }
 
-            KeyCode::Char(c) => {
+            // Space flips the focused Preferences toggle
+            KeyCode::Char(' ')
                 if self.panel_focus == PanelFocus::Content
-                    && self.content_focus == ContentFocus::Picker
-                {
-                    self.vim_mode = self.vim_mode.transition(ModeAction::EnterInsert);
-                    self.picker_filter.insert(c);
-                    self.picker_selected = 0;
-                }
+                    && matches!(self.content_focus, ContentFocus::Toggle(_)) =>
+            {
+                self.flip_preference_toggle();
+            }
+
+            // Anywhere else digits and Space do nothing, rather than filter a picker
+            KeyCode::Char(c) if c == ' ' || c.is_ascii_digit() => {}
+
+            KeyCode::Char(c)
+                if self.panel_focus == PanelFocus::Content
+                    && self.content_focus == ContentFocus::Picker =>
+            {
+                self.vim_mode = self.vim_mode.transition(ModeAction::EnterInsert);
+                self.picker_filter.insert(c);
+                self.picker_selected = 0;
             }
 
             _ => {}
@@ -583,9 +1080,9 @@ impl OnboardApp {
                         let step_id = self.current_step_id();
 
                         match step_id {
-                            Some(StepId::User) => {
+                            Some(StepId::User | StepId::Hostname | StepId::Custom(_)) => {
                                 // Move to next field, or submit on last field
-                                if field < 2 {
+                                if field + 1 < self.form_field_count() {
                                     self.content_focus = ContentFocus::InputField(field + 1);
                                 } else {
                                     self.vim_mode = VimMode::Normal;
@@ -616,17 +1113,21 @@ impl OnboardApp {
                 }
             }
             KeyCode::Tab => {
-                if let ContentFocus::InputField(field) = self.content_focus {
-                    if field < 2 {
-                        self.content_focus = ContentFocus::InputField(field + 1);
-                    }
+                if let ContentFocus::InputField(field) = self.content_focus
+                    && field + 1 < self.form_field_count()
+                {
+                    self.content_focus = ContentFocus::InputField(field + 1);
                 }
             }
             KeyCode::BackTab => {
-                if let ContentFocus::InputField(field) = self.content_focus {
-                    if field > 0 {
-                        self.content_focus = ContentFocus::InputField(field - 1);
-                    }
+                if let ContentFocus::InputField(field) = self.content_focus
+                    && field > 0
+                {
+                    self.content_focus = ContentFocus::InputField(field - 1);
+                } else if self.content_focus == ContentFocus::Picker && self.toggle_count() > 0 {
+                    // From the timezone filter back up to the Preferences toggles
+                    self.vim_mode = VimMode::Normal;
+                    self.focus_last_toggle();
                 }
             }
             KeyCode::Backspace => {
@@ -673,23 +1174,41 @@ impl OnboardApp {
         match key.code {
             KeyCode::Esc => {
                 self.vim_mode = self.vim_mode.transition(ModeAction::Escape);
-                self.command_buffer.clear();
+                self.command_line.clear();
             }
             KeyCode::Enter => {
-                let cmd = self.command_buffer.content().to_string();
+                let cmd = self.command_line.submit();
                 self.vim_mode = self.vim_mode.transition(ModeAction::Execute);
-                self.command_buffer.clear();
                 return self.execute_command(&cmd);
             }
             KeyCode::Backspace => {
-                if self.command_buffer.is_empty() {
+                if self.command_line.is_empty() {
                     self.vim_mode = self.vim_mode.transition(ModeAction::Escape);
                 } else {
-                    self.command_buffer.delete_back();
+                    self.command_line.delete_back();
                 }
             }
+            KeyCode::Up => {
+                self.command_line.history_prev();
+            }
+            KeyCode::Down => {
+                self.command_line.history_next();
+            }
+            KeyCode::Tab | KeyCode::BackTab => {
+                let step_names: Vec<String> = self.menu_items.iter()
+                    .flat_map(|item| self.goto_names(item.id))
+                    .collect();
+                self.command_line.complete(
+                    &command_names(COMMANDS),
+                    |cmd| match cmd.to_lowercase().as_str() {
+                        "goto" => step_names.clone(),
+                        _ => Vec::new(),
+                    },
+                    key.code == KeyCode::Tab,
+                );
+            }
             KeyCode::Char(c) => {
-                self.command_buffer.insert(c);
+                self.command_line.insert(c);
             }
             _ => {}
         }
@@ -718,10 +1237,16 @@ impl OnboardApp {
                     ConfirmAction::Cancel => {
                         self.should_exit = true;
                     }
+                    ConfirmAction::Resume => {
+                        self.resume();
+                    }
                 }
             }
             KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
                 self.confirm_action = None;
+                if action == ConfirmAction::Resume {
+                    self.discard_resume();
+                }
             }
             _ => {}
         }
@@ -743,6 +1268,8 @@ impl OnboardApp {
                 if item.has_picker {
                     self.content_focus = ContentFocus::Picker;
                     self.vim_mode = self.vim_mode.transition(ModeAction::EnterInsert);
+                } else if self.toggle_count() > 0 {
+                    self.content_focus = ContentFocus::Toggle(0);
                 } else if item.has_form {
                     self.content_focus = ContentFocus::InputField(0);
                 } else {
@@ -765,11 +1292,12 @@ impl OnboardApp {
             }
             PanelFocus::Content => {
                 // Special case for Update step - navigate categories and packages
-                if let Some(StepId::Update) = self.current_step_id() {
-                    if self.tasks.is_empty() && !self.config.updates.is_empty() {
-                        self.navigate_update_down();
-                        return;
-                    }
+                if let Some(StepId::Update) = self.current_step_id()
+                    && self.tasks.is_empty()
+                    && !self.config.updates.is_empty()
+                {
+                    self.navigate_update_down();
+                    return;
                 }
 
                 match self.content_focus {
@@ -780,10 +1308,18 @@ impl OnboardApp {
                         }
                     }
                     ContentFocus::InputField(field) => {
-                        if field < 2 {
+                        if field + 1 < self.form_field_count() {
                             self.content_focus = ContentFocus::InputField(field + 1);
                         }
                     }
+                    ContentFocus::Toggle(idx) => {
+                        if idx + 1 < self.toggle_count() {
+                            self.content_focus = ContentFocus::Toggle(idx + 1);
+                        } else if self.current_item().is_some_and(|item| item.has_picker) {
+                            // The timezone list sits below the toggles
+                            self.content_focus = ContentFocus::Picker;
+                        }
+                    }
                     ContentFocus::None => {}
                 }
             }
@@ -803,17 +1339,20 @@ impl OnboardApp {
             }
             PanelFocus::Content => {
                 // Special case for Update step - navigate categories and packages
-                if let Some(StepId::Update) = self.current_step_id() {
-                    if self.tasks.is_empty() && !self.config.updates.is_empty() {
-                        self.navigate_update_up();
-                        return;
-                    }
+                if let Some(StepId::Update) = self.current_step_id()
+                    && self.tasks.is_empty()
+                    && !self.config.updates.is_empty()
+                {
+                    self.navigate_update_up();
+                    return;
                 }
 
                 match self.content_focus {
                     ContentFocus::Picker => {
                         if self.picker_selected > 0 {
                             self.picker_selected -= 1;
+                        } else {
+                            self.focus_last_toggle();
                         }
                     }
                     ContentFocus::InputField(field) => {
@@ -821,6 +1360,11 @@ impl OnboardApp {
                             self.content_focus = ContentFocus::InputField(field - 1);
                         }
                     }
+                    ContentFocus::Toggle(idx) => {
+                        if idx > 0 {
+                            self.content_focus = ContentFocus::Toggle(idx - 1);
+                        }
+                    }
                     ContentFocus::None => {}
                 }
             }
@@ -899,15 +1443,15 @@ impl OnboardApp {
                 if let Some(packages) = self.update_package_selected.get(cat_idx) {
                     let all_selected = packages.iter().all(|&s| s);
                     let new_value = !all_selected;
-                    if let Some(cat) = self.config.updates.get(cat_idx) {
-                        if let Some(pkg_list) = self.update_package_selected.get_mut(cat_idx) {
-                            for (idx, pkg) in pkg_list.iter_mut().enumerate() {
-                                if cat.packages.get(idx).map(|p| p.required).unwrap_or(false) {
-                                    // Required packages stay selected
-                                    *pkg = true;
-                                } else {
-                                    *pkg = new_value;
-                                }
+                    if let Some(cat) = self.config.updates.get(cat_idx)
+                        && let Some(pkg_list) = self.update_package_selected.get_mut(cat_idx)
+                    {
+                        for (idx, pkg) in pkg_list.iter_mut().enumerate() {
+                            if cat.packages.get(idx).map(|p| p.required).unwrap_or(false) {
+                                // Required packages stay selected
+                                *pkg = true;
+                            } else {
+                                *pkg = new_value;
                             }
                         }
                     }
@@ -920,12 +1464,12 @@ impl OnboardApp {
                     .map(|pkg| pkg.required)
                     .unwrap_or(false);
 
-                if !is_required {
-                    if let Some(pkg_list) = self.update_package_selected.get_mut(cat_idx) {
-                        if let Some(pkg) = pkg_list.get_mut(pkg_idx) {
-                            *pkg = !*pkg;
-                        }
-                    }
+                if !is_required
+                    && let Some(pkg) = self.update_package_selected
+                        .get_mut(cat_idx)
+                        .and_then(|pkgs| pkgs.get_mut(pkg_idx))
+                {
+                    *pkg = !*pkg;
                 }
             }
         }
@@ -965,31 +1509,29 @@ impl OnboardApp {
             PanelFocus::Sidebar => {
                 // Check if step is locked
                 if self.is_current_step_locked() {
-                    self.set_error("This step is locked. Complete previous steps first.".to_string());
+                    self.set_error(self.catalog.tr("msg-step-locked"));
                     return None;
                 }
 
                 // Move to content panel
                 self.focus_content();
                 // If it's a form, enter insert mode
-                if let Some(item) = self.current_item() {
-                    if item.has_form {
-                        self.vim_mode = VimMode::Insert;
-                    }
+                if self.current_item().is_some_and(|item| item.has_form) {
+                    self.vim_mode = VimMode::Insert;
                 }
             }
             PanelFocus::Content => {
                 // Check if step is locked
                 if self.is_current_step_locked() {
-                    self.set_error("This step is locked. Complete previous steps first.".to_string());
+                    self.set_error(self.catalog.tr("msg-step-locked"));
                     return None;
                 }
 
                 // Special case: Update step in dryrun mode doesn't need password
-                if let Some(StepId::Update) = self.current_step_id() {
-                    if self.is_dryrun() {
-                        return Some(OnboardAction::ExecuteUpdate);
-                    }
+                if let Some(StepId::Update) = self.current_step_id()
+                    && self.is_dryrun()
+                {
+                    return Some(OnboardAction::ExecuteUpdate);
                 }
 
                 match self.content_focus {
@@ -1000,6 +1542,9 @@ impl OnboardApp {
                         // Enter insert mode
                         self.vim_mode = VimMode::Insert;
                     }
+                    ContentFocus::Toggle(_) => {
+                        self.save_toggles();
+                    }
                     ContentFocus::None => {
                         if let Some(item) = self.current_item() {
                             match item.id {
@@ -1024,6 +1569,9 @@ impl OnboardApp {
                                     // Exit to greeter login
                                     return Some(OnboardAction::ExitToLogin);
                                 }
+                                StepId::Custom(_) => {
+                                    return self.continue_custom_step();
+                                }
                                 _ => {}
                             }
                         }
@@ -1080,6 +1628,19 @@ impl OnboardApp {
                         .unwrap_or(0);
                     self.picker_filter.clear();
                 }
+                StepId::Hostname if self.hostname.is_empty() => {
+                    // Suggest the configured default until the user types their own
+                    let serial = self.service.machine_serial();
+                    let suggested = hostname::expand(
+                        &self.config.hostname.default,
+                        serial.as_deref(),
+                        self.username.content(),
+                    );
+                    self.hostname.set(&suggested);
+                }
+                StepId::Custom(idx) => {
+                    self.load_custom_step(idx);
+                }
                 StepId::Update => {
                     // Check if any commands need sudo
                     self.sudo_password_needed = self.commands_need_sudo();
@@ -1100,17 +1661,33 @@ impl OnboardApp {
                     StepId::Locale => {
                         self.selected_locale = Some(item.clone());
                         self.step_results[self.selected_step] = StepResult::Completed;
-                        self.set_info(format!("Locale selected: {item}"));
+                        // The wizard speaks the chosen language from here on
+                        self.set_catalog(Catalog::for_locale(Domain::Onboard, &item));
+                        self.set_info(self.catalog.tr_args("msg-locale-selected", &[("value", item.as_str().into())]));
                     }
                     StepId::Keyboard => {
                         self.selected_keyboard = Some(item.clone());
                         self.step_results[self.selected_step] = StepResult::Completed;
-                        self.set_info(format!("Keyboard selected: {item}"));
+                        self.set_info(self.catalog.tr_args("msg-keyboard-selected", &[("value", item.as_str().into())]));
                     }
                     StepId::Preferences => {
                         self.selected_timezone = Some(item.clone());
                         self.step_results[self.selected_step] = StepResult::Completed;
-                        self.set_info(format!("Timezone selected: {item}"));
+                        self.set_info(self.catalog.tr_args("msg-timezone-selected", &[("value", item.as_str().into())]));
+                    }
+                    StepId::Custom(idx) => {
+                        let step = &self.config.steps[idx];
+                        if let CustomStepKind::Choice { options } = &step.kind
+                            && let Some(option) = options.iter().find(|o| o.label() == item)
+                        {
+                            self.step_values.insert(step.id.clone(), vec![option.value.clone()]);
+                            self.step_results[self.selected_step] = StepResult::Completed;
+                            let message = self.catalog.tr_args(
+                                "msg-choice-selected",
+                                &[("step", step.title.as_str().into()), ("value", item.as_str().into())],
+                            );
+                            self.set_info(message);
+                        }
                     }
                     _ => {}
                 }
@@ -1140,10 +1717,16 @@ impl OnboardApp {
                         2 => Some(&mut self.password_confirm),
                         _ => None,
                     },
+                    Some(StepId::Hostname) => match idx {
+                        0 => Some(&mut self.hostname),
+                        1 => Some(&mut self.pretty_hostname),
+                        _ => None,
+                    },
                     Some(StepId::Update) => match idx {
                         0 => Some(&mut self.sudo_password),
                         _ => None,
                     },
+                    Some(StepId::Custom(_)) => self.custom_fields.get_mut(idx),
                     _ => None,
                 }
             }
@@ -1152,6 +1735,196 @@ impl OnboardApp {
         }
     }
 
+    /// Number of text fields on the current step's form
+    fn form_field_count(&self) -> usize {
+        match self.current_step_id() {
+            Some(StepId::User) => 3,
+            Some(StepId::Hostname) => 2,
+            Some(StepId::Custom(_)) => self.custom_fields.len(),
+            _ => 1,
+        }
+    }
+
+    /// Number of toggles on the current step
+    fn toggle_count(&self) -> usize {
+        match self.current_step_id() {
+            Some(StepId::Preferences) => self.preference_toggles().len(),
+            Some(StepId::Custom(_)) => self.custom_checked.len(),
+            _ => 0,
+        }
+    }
+
+    /// Toggles the config offers on the Preferences step, in display order
+    pub fn preference_toggles(&self) -> Vec<PreferenceToggle> {
+        let prefs = &self.config.preferences;
+        let mut toggles = Vec::new();
+        if prefs.ntp_enabled {
+            toggles.push(PreferenceToggle::Ntp);
+        }
+        if prefs.keyring_enabled {
+            toggles.push(PreferenceToggle::Keyring);
+        }
+        toggles
+    }
+
+    pub fn preference_value(&self, toggle: PreferenceToggle) -> bool {
+        match toggle {
+            PreferenceToggle::Ntp => self.ntp_sync,
+            PreferenceToggle::Keyring => self.create_keyring,
+        }
+    }
+
+    fn flip_preference_toggle(&mut self) {
+        let ContentFocus::Toggle(idx) = self.content_focus else {
+            return;
+        };
+        if let Some(StepId::Custom(_)) = self.current_step_id() {
+            if let Some(checked) = self.custom_checked.get_mut(idx) {
+                *checked = !*checked;
+            }
+            return;
+        }
+        match self.preference_toggles().get(idx) {
+            Some(PreferenceToggle::Ntp) => self.ntp_sync = !self.ntp_sync,
+            Some(PreferenceToggle::Keyring) => self.create_keyring = !self.create_keyring,
+            None => {}
+        }
+    }
+
+    fn focus_last_toggle(&mut self) {
+        if let Some(last) = self.toggle_count().checked_sub(1) {
+            self.content_focus = ContentFocus::Toggle(last);
+        }
+    }
+
+    /// Keep the toggles as they are and move on. On Preferences this leaves the
+    /// timezone unset; on a multi-choice step the checked options are its values.
+    fn save_toggles(&mut self) {
+        match self.custom_step() {
+            Some(step) => {
+                let CustomStepKind::MultiChoice { options } = &step.kind else {
+                    return;
+                };
+                let chosen = options.iter()
+                    .zip(&self.custom_checked)
+                    .filter(|(_, checked)| **checked)
+                    .map(|(option, _)| option.value.clone())
+                    .collect();
+                let message = self.catalog.tr_args("msg-step-saved", &[("step", step.title.as_str().into())]);
+                self.step_values.insert(step.id.clone(), chosen);
+                self.set_info(message);
+            }
+            None => self.set_info(self.catalog.tr("msg-preferences-saved")),
+        }
+        self.step_results[self.selected_step] = StepResult::Completed;
+        self.advance_to_next_step();
+    }
+
+    /// Fill the picker, toggles or fields of a custom step from its saved values or defaults
+    fn load_custom_step(&mut self, idx: usize) {
+        let step = &self.config.steps[idx];
+        let saved = self.step_values.get(&step.id);
+        self.custom_checked.clear();
+        self.custom_fields.clear();
+
+        match &step.kind {
+            CustomStepKind::Choice { options } => {
+                self.picker_items = options.iter().map(|o| o.label().to_string()).collect();
+                self.picker_selected = options.iter()
+                    .position(|o| match saved {
+                        Some(values) => values.contains(&o.value),
+                        None => o.default,
+                    })
+                    .unwrap_or(0);
+                self.picker_filter.clear();
+            }
+            CustomStepKind::MultiChoice { options } => {
+                self.custom_checked = options.iter()
+                    .map(|o| saved.map_or(o.default, |values| values.contains(&o.value)))
+                    .collect();
+            }
+            CustomStepKind::Form { fields } => {
+                self.custom_fields = fields.iter()
+                    .map(|field| {
+                        let key = format!("{}.{}", step.id, field.name);
+                        let value = self.step_values.get(&key)
+                            .and_then(|values| values.first())
+                            .unwrap_or(&field.default);
+                        let mut buffer = InputBuffer::new();
+                        buffer.set(value);
+                        buffer
+                    })
+                    .collect();
+            }
+            CustomStepKind::Info | CustomStepKind::Program { .. } => {}
+        }
+    }
+
+    /// Enter on an info or program step: carry on, or run the program
+    fn continue_custom_step(&mut self) -> Option<OnboardAction> {
+        let step = self.custom_step()?;
+        match &step.kind {
+            CustomStepKind::Info => {
+                self.step_results[self.selected_step] = StepResult::Completed;
+                self.advance_to_next_step();
+            }
+            CustomStepKind::Program { program, args } if !self.is_dryrun() => {
+                return Some(OnboardAction::LaunchExternal(program.clone(), args.clone()));
+            }
+            CustomStepKind::Program { program, .. } => {
+                let message = self.catalog.tr_args("msg-would-run", &[("program", program.as_str().into())]);
+                self.step_results[self.selected_step] = StepResult::Completed;
+                self.set_info(message);
+                self.advance_to_next_step();
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// An external program launched from the current step has exited
+    pub fn external_program_exited(&mut self, success: bool) {
+        let Some(step) = self.custom_step() else {
+            return;
+        };
+        let CustomStepKind::Program { program, .. } = &step.kind else {
+            return;
+        };
+        if success {
+            self.step_results[self.selected_step] = StepResult::Completed;
+            self.advance_to_next_step();
+        } else {
+            let message = self.catalog.tr_args("msg-program-failed", &[("program", program.as_str().into())]);
+            self.step_results[self.selected_step] = StepResult::Failed;
+            self.set_error(message);
+        }
+    }
+
+    /// Check a custom form and keep its values
+    fn submit_custom_form(&mut self) -> bool {
+        let Some(step) = self.custom_step() else {
+            return false;
+        };
+        let CustomStepKind::Form { fields } = &step.kind else {
+            return false;
+        };
+
+        let mut values = StepValues::new();
+        for (idx, (field, buffer)) in fields.iter().zip(&self.custom_fields).enumerate() {
+            let value = buffer.content().trim();
+            if let Err(reason) = custom::check_field(field, value) {
+                self.content_focus = ContentFocus::InputField(idx);
+                self.set_error(reason);
+                return false;
+            }
+            values.insert(format!("{}.{}", step.id, field.name), vec![value.to_string()]);
+        }
+        let message = self.catalog.tr_args("msg-step-saved", &[("step", step.title.as_str().into())]);
+        self.step_values.extend(values);
+        self.set_info(message);
+        true
+    }
+
     fn handle_char_input(&mut self, c: char) {
         if let ContentFocus::Picker = self.content_focus {
             self.picker_filter.insert(c);
@@ -1202,25 +1975,33 @@ impl OnboardApp {
 
     fn handle_clear_line(&mut self) {
         if let Some(buffer) = self.current_input_buffer() {
+            // Its own undo step, so `u` brings the line back
+            buffer.checkpoint();
             buffer.clear();
+            buffer.checkpoint();
         }
     }
 
     fn execute_command(&mut self, cmd: &str) -> Option<OnboardAction> {
         let cmd = cmd.trim().to_lowercase();
-        let parts: Vec<&str> = cmd.split_whitespace().collect();
-        let cmd_name = parts.first().copied().unwrap_or("");
+        let mut parts = cmd.splitn(2, char::is_whitespace);
+        let cmd_name = parts.next().unwrap_or("");
+        let arg = parts.next().map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
 
-        match cmd_name {
-            "start" | "run" => {
+        let Some(spec) = find_command(COMMANDS, cmd_name) else {
+            self.set_error(self.catalog.tr_args("msg-unknown-command", &[("name", cmd_name.into())]));
+            return None;
+        };
+        match (spec.build)(arg) {
+            OnboardCommand::Start => {
                 if !self.setup_started {
                     self.start_setup();
                 }
             }
-            "next" | "n" => {
+            OnboardCommand::Next => {
                 self.advance_to_next_step();
             }
-            "skip" | "s" => {
+            OnboardCommand::Skip => {
                 let (is_required, step_id) = self.current_item()
                     .map(|item| (item.required, item.id))
                     .unwrap_or((true, StepId::User));
@@ -1234,35 +2015,51 @@ impl OnboardApp {
                     }
                     self.advance_to_next_step();
                 } else {
-                    self.set_error("This step is required".to_string());
+                    self.set_error(self.catalog.tr("msg-step-required"));
+                }
+            }
+            OnboardCommand::Goto(name) => {
+                let name = name.unwrap_or_default();
+                let idx = self.menu_items.iter().position(|item| {
+                    !name.is_empty() && self.goto_names(item.id).iter().any(|n| n.starts_with(&name))
+                });
+                match idx {
+                    _ if !self.setup_started => {
+                        self.set_error(self.catalog.tr("msg-start-setup-first"));
+                    }
+                    Some(idx) => {
+                        self.selected_step = idx;
+                        self.load_step_content();
+                        self.focus_content();
+                    }
+                    None => {
+                        self.set_error(self.catalog.tr_args("msg-unknown-step", &[("name", name.into())]));
+                    }
                 }
             }
-            "cancel" | "q" | "quit" => {
+            OnboardCommand::Cancel => {
                 self.confirm_action = Some(ConfirmAction::Cancel);
             }
-            "reboot" => {
+            OnboardCommand::Reboot => {
                 self.confirm_action = Some(ConfirmAction::Reboot);
             }
-            "poweroff" | "shutdown" => {
+            OnboardCommand::Poweroff => {
                 self.confirm_action = Some(ConfirmAction::Poweroff);
             }
-            "help" | "h" => {
+            OnboardCommand::Help => {
                 self.show_help = true;
             }
-            "submit" | "create" | "install" | "update" => {
+            OnboardCommand::Submit => {
                 // Trigger async step execution
                 return Some(OnboardAction::ExecuteStep);
             }
-            "finish" | "done" | "login" => {
+            OnboardCommand::Finish => {
                 if self.review_completed {
                     return Some(OnboardAction::ExitToLogin);
                 } else {
-                    self.set_error("Complete the Review step first".to_string());
+                    self.set_error(self.catalog.tr("msg-review-first"));
                 }
             }
-            _ => {
-                self.set_error(format!("Unknown command: {cmd_name}"));
-            }
         }
         None
     }
@@ -1286,45 +2083,302 @@ impl OnboardApp {
         let confirm = self.password_confirm.content().to_string();
 
         if username.is_empty() {
-            self.set_error("Username is required".to_string());
+            self.set_error(self.catalog.tr("msg-username-required"));
             return false;
         }
 
         if !username.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-') {
-            self.set_error("Username can only contain letters, numbers, underscore, and dash".to_string());
+            self.set_error(self.catalog.tr("msg-username-chars"));
             return false;
         }
 
         if username.len() > 32 {
-            self.set_error("Username must be 32 characters or less".to_string());
+            self.set_error(self.catalog.tr("msg-username-length"));
             return false;
         }
 
+        // The account already exists from an earlier run, or comes with a hash
+        let already_created = self.created_username.as_deref() == Some(username.as_str());
+        if password.is_empty() && (self.password_hash.is_some() || already_created) {
+            return true;
+        }
+
         if password.is_empty() {
-            self.set_error("Password is required".to_string());
+            self.set_error(self.catalog.tr("msg-password-required"));
             return false;
         }
 
         let min_len = self.config.user.min_password_length;
         if password.len() < min_len {
-            self.set_error(format!("Password must be at least {min_len} characters"));
+            self.set_error(self.catalog.tr_args("msg-password-length", &[("min", min_len.into())]));
             return false;
         }
 
         if password != confirm {
-            self.set_error("Passwords do not match".to_string());
+            self.set_error(self.catalog.tr("msg-password-mismatch"));
+            return false;
+        }
+
+        true
+    }
+
+    /// Check the Hostname form and keep its values for the Review step
+    pub fn validate_hostname_form(&mut self) -> bool {
+        let hostname = self.hostname.content().trim().to_ascii_lowercase();
+        if let Err(reason) = hostname::check(&hostname) {
+            self.set_error(reason.message(&self.catalog));
+            return false;
+        }
+
+        let pretty = self.pretty_hostname.content().trim().to_string();
+        if pretty.chars().any(char::is_control) {
+            self.set_error(self.catalog.tr("msg-pretty-name-control"));
             return false;
         }
 
+        self.selected_hostname = Some(hostname);
+        self.selected_pretty_hostname = Some(pretty).filter(|p| !p.is_empty());
         true
     }
 
+    /// Pre-fill the wizard from an answer file and open the first unanswered step
+    pub fn apply_answers(&mut self, answers: &Answers) -> error::Result<()> {
+        self.start_setup();
+
+        if let Some(ref username) = answers.username {
+            self.username.set(username);
+        }
+        if let Some(ref password) = answers.password {
+            self.password.set(password);
+            self.password_confirm.set(password);
+        }
+        self.password_hash = answers.password_hash.clone();
+
+        if let Some(ref hostname) = answers.hostname {
+            self.hostname.set(hostname);
+            self.pretty_hostname.set(answers.pretty_hostname.as_deref().unwrap_or(""));
+            if !self.validate_hostname_form() {
+                let reason = self.message.take().map(|m| m.text).unwrap_or_default();
+                return Err(OnboardError::Answers(format!("hostname: {reason}")));
+            }
+            self.complete_step(StepId::Hostname);
+        }
+        if let Some(ref locale) = answers.locale {
+            check_answer("locale", locale, &self.service.list_locales())?;
+            self.selected_locale = Some(locale.clone());
+            self.set_catalog(Catalog::for_locale(Domain::Onboard, locale));
+            self.complete_step(StepId::Locale);
+        }
+        if let Some(ref keymap) = answers.keymap {
+            check_answer("keymap", keymap, &self.service.list_keymaps())?;
+            self.selected_keyboard = Some(keymap.clone());
+            self.complete_step(StepId::Keyboard);
+        }
+        if let Some(ref timezone) = answers.timezone {
+            check_answer("timezone", timezone, &self.service.list_timezones())?;
+            self.selected_timezone = Some(timezone.clone());
+            self.complete_step(StepId::Preferences);
+        }
+        self.ntp_sync = answers.ntp.unwrap_or(self.ntp_sync);
+        if answers.keyring == Some(true) && answers.password.is_none() {
+            return Err(OnboardError::Answers("keyring: needs password, a hash cannot unlock it".to_string()));
+        }
+        self.create_keyring = answers.keyring.unwrap_or(self.create_keyring);
+
+        for (id, answer) in &answers.steps {
+            let idx = self.config.steps.iter().position(|step| &step.id == id)
+                .ok_or_else(|| OnboardError::Answers(format!("unknown step: {id}")))?;
+            let values = custom::answer_values(&self.config.steps[idx], answer)
+                .map_err(|reason| OnboardError::Answers(format!("steps.{id}: {reason}")))?;
+            self.step_values.extend(values);
+            self.complete_step(StepId::Custom(idx));
+        }
+
+        if let Some(ref packages) = answers.packages {
+            for title in packages {
+                let known = self.config.updates.iter()
+                    .any(|cat| cat.packages.iter().any(|pkg| &pkg.title == title));
+                if !known {
+                    return Err(OnboardError::Answers(format!("unknown package: {title}")));
+                }
+            }
+            for (cat, selected) in self.config.updates.iter().zip(&mut self.update_package_selected) {
+                for (pkg, sel) in cat.packages.iter().zip(selected.iter_mut()) {
+                    *sel = pkg.required || packages.contains(&pkg.title);
+                }
+            }
+        }
+
+        let has_password = answers.password.is_some() || answers.password_hash.is_some();
+        if answers.username.is_some() && has_password {
+            if !self.validate_user_form() {
+                let reason = self.message.take().map(|m| m.text).unwrap_or_default();
+                return Err(OnboardError::Answers(reason));
+            }
+            self.complete_step(StepId::User);
+        }
+
+        if let Some(idx) = self.step_results.iter().position(|r| *r == StepResult::Pending) {
+            self.selected_step = idx;
+            self.load_step_content();
+        }
+        self.focus_content();
+        Ok(())
+    }
+
+    fn complete_step(&mut self, id: StepId) {
+        if let Some(idx) = self.step_index_by_id(id) {
+            self.step_results[idx] = StepResult::Completed;
+        }
+    }
+
+    /// Whether an interrupted run left progress to resume
+    pub fn can_resume(&self) -> bool {
+        self.resume_state.is_some()
+    }
+
+    /// Restore the progress of an interrupted run and open its first unfinished step
+    pub fn resume(&mut self) {
+        let Some(state) = self.resume_state.take() else {
+            return;
+        };
+        if self.confirm_action == Some(ConfirmAction::Resume) {
+            self.confirm_action = None;
+        }
+        self.start_setup();
+
+        for (item, result) in self.menu_items.iter().zip(self.step_results.iter_mut()) {
+            if let Some(saved) = state.result_for(item.id) {
+                *result = saved;
+            }
+        }
+        if let Some(username) = state.created_username.as_ref().or(state.pending_username.as_ref()) {
+            self.username.set(username);
+        }
+        self.created_username = state.created_username.clone();
+        self.pending_username = state.pending_username.clone();
+        self.selected_locale = state.locale.clone();
+        if let Some(ref locale) = state.locale {
+            self.set_catalog(Catalog::for_locale(Domain::Onboard, locale));
+        }
+        self.selected_keyboard = state.keymap.clone();
+        self.selected_timezone = state.timezone.clone();
+        self.selected_hostname = state.hostname.clone();
+        self.selected_pretty_hostname = state.pretty_hostname.clone();
+        if let Some(ref hostname) = state.hostname {
+            self.hostname.set(hostname);
+        }
+        if let Some(ref pretty) = state.pretty_hostname {
+            self.pretty_hostname.set(pretty);
+        }
+        self.ntp_sync = state.ntp_sync.unwrap_or(self.ntp_sync);
+        self.create_keyring = state.create_keyring.unwrap_or(self.create_keyring);
+        self.step_values = state.step_values.clone();
+        for (cat, selected) in self.config.updates.iter().zip(&mut self.update_package_selected) {
+            for (pkg, sel) in cat.packages.iter().zip(selected.iter_mut()) {
+                *sel = pkg.required || state.packages.contains(&pkg.title);
+            }
+        }
+        self.completed_commands = state.completed_commands.clone();
+        self.command_runs = state.command_runs.clone();
+        self.skipped_tasks = state.skipped_tasks.clone();
+        self.review_completed = state.result_for(StepId::Review) == Some(StepResult::Completed);
+        self.update_completed = matches!(
+            state.result_for(StepId::Update),
+            Some(StepResult::Completed | StepResult::Skipped)
+        );
+        self.saved_state = state;
+
+        let unfinished = self.step_results.iter()
+            .position(|r| matches!(r, StepResult::Pending | StepResult::Failed));
+        if let Some(idx) = unfinished {
+            self.selected_step = idx;
+            self.load_step_content();
+        }
+        self.focus_content();
+        self.set_info(self.catalog.tr("msg-resumed"));
+    }
+
+    /// Start over, forgetting the progress of an interrupted run
+    fn discard_resume(&mut self) {
+        self.resume_state = None;
+        OnboardState::clear(&self.config.general.state_file);
+    }
+
+    /// Current progress, as written to the state file
+    pub fn snapshot_state(&self) -> OnboardState {
+        let packages = self.config.updates.iter()
+            .zip(&self.update_package_selected)
+            .flat_map(|(cat, selected)| {
+                cat.packages.iter()
+                    .zip(selected)
+                    .filter(|(_, sel)| **sel)
+                    .map(|(pkg, _)| pkg.title.clone())
+            })
+            .collect();
+
+        OnboardState {
+            steps: self.menu_items.iter()
+                .zip(&self.step_results)
+                .map(|(item, result)| StepRecord { id: item.id, result: *result })
+                .collect(),
+            created_username: self.created_username.clone(),
+            pending_username: self.pending_username.clone(),
+            locale: self.selected_locale.clone(),
+            keymap: self.selected_keyboard.clone(),
+            timezone: self.selected_timezone.clone(),
+            hostname: self.selected_hostname.clone(),
+            pretty_hostname: self.selected_pretty_hostname.clone(),
+            ntp_sync: Some(self.ntp_sync),
+            create_keyring: Some(self.create_keyring),
+            step_values: self.step_values.clone(),
+            packages,
+            completed_commands: self.completed_commands.clone(),
+            command_runs: self.command_runs.clone(),
+            skipped_tasks: self.skipped_tasks.clone(),
+        }
+    }
+
+    /// Save progress when it changed, so a crash or power loss can resume
+    fn persist(&mut self) {
+        if self.is_dryrun() || !self.setup_started || self.setup_complete || self.resume_state.is_some() {
+            return;
+        }
+
+        let state = self.snapshot_state();
+        if state == self.saved_state {
+            return;
+        }
+        if let Err(e) = state.save(&self.config.general.state_file) {
+            warn!("Failed to save onboard state: {}", e);
+        }
+        self.saved_state = state;
+    }
+
     /// Validate and complete the User form step (no user creation happens here).
     /// User creation is deferred to the Review step.
     /// Returns None always (handled synchronously).
     pub fn start_step_execution(&mut self) -> Option<mpsc::UnboundedReceiver<ExecutionMessage>> {
         let step_id = self.current_step_id()?;
 
+        if step_id == StepId::Hostname {
+            if self.validate_hostname_form() {
+                self.complete_step(StepId::Hostname);
+                let hostname = self.selected_hostname.clone().unwrap_or_default();
+                self.set_info(self.catalog.tr_args("msg-hostname-set", &[("hostname", hostname.as_str().into())]));
+                self.advance_to_next_step();
+            }
+            return None;
+        }
+
+        if let StepId::Custom(_) = step_id {
+            if self.submit_custom_form() {
+                self.step_results[self.selected_step] = StepResult::Completed;
+                self.advance_to_next_step();
+            }
+            return None;
+        }
+
         if step_id != StepId::User {
             return None;
         }
@@ -1350,212 +2404,142 @@ impl OnboardApp {
             return None;
         }
 
-        // In dryrun mode, use tick-based simulation with progress bars
-        if self.is_dryrun() {
-            self.tasks.clear();
-
-            let username = self.username.content().to_string();
-            let groups = self.config.user.groups.clone();
-            let shell = self.config.user.shell.clone();
-
-            self.tasks.push(TaskStatus {
-                name: format!("Creating user '{username}'"),
-                status: TaskState::Pending,
-                output: None,
-                progress: Some(0),
-                command: Some(self.service.command_string(&ServiceOp::CreateUser {
-                    username: username.clone(),
-                    groups,
-                    shell,
-                })),
+        // Required custom steps that come before Review
+        let review_idx = self.step_index_by_id(StepId::Review).unwrap_or(self.menu_items.len());
+        let unfinished = self.menu_items[..review_idx].iter()
+            .zip(&self.step_results)
+            .find(|(item, result)| {
+                matches!(item.id, StepId::Custom(_)) && item.required && **result != StepResult::Completed
             });
-
-            if let Some(ref locale) = self.selected_locale {
-                self.tasks.push(TaskStatus {
-                    name: format!("Setting locale to {locale}"),
-                    status: TaskState::Pending,
-                    output: None,
-                    progress: Some(0),
-                    command: Some(self.service.command_string(&ServiceOp::SetLocale {
-                        locale: locale.clone(),
-                    })),
-                });
-            }
-
-            if let Some(ref keymap) = self.selected_keyboard {
-                self.tasks.push(TaskStatus {
-                    name: format!("Setting keyboard to {keymap}"),
-                    status: TaskState::Pending,
-                    output: None,
-                    progress: Some(0),
-                    command: Some(self.service.command_string(&ServiceOp::SetKeymap {
-                        keymap: keymap.clone(),
-                    })),
-                });
-            }
-
-            if let Some(ref tz) = self.selected_timezone {
-                self.tasks.push(TaskStatus {
-                    name: format!("Setting timezone to {tz}"),
-                    status: TaskState::Pending,
-                    output: None,
-                    progress: Some(0),
-                    command: Some(self.service.command_string(&ServiceOp::SetTimezone {
-                        timezone: tz.clone(),
-                    })),
-                });
-            }
-
-            self.created_username = Some(username);
-            self.start_dryrun_simulation(DryrunCallback::CompleteReview);
+        if let Some((item, _)) = unfinished {
+            let message = self.catalog.tr_args("msg-complete-step-first", &[("step", self.step_title(item.id).into())]);
+            self.set_error(message);
             return None;
         }
 
-        // Real execution
-        self.is_executing = true;
-        self.tasks.clear();
-
         let username = self.username.content().to_string();
-        let password = self.password.content().to_string();
-        let groups = self.config.user.groups.clone();
-        let shell = self.config.user.shell.clone();
-        let locale = self.selected_locale.clone();
-        let keymap = self.selected_keyboard.clone();
-        let timezone = self.selected_timezone.clone();
-
-        // Build task list for UI
-        self.tasks.push(TaskStatus {
-            name: format!("Creating user '{username}'"),
-            status: TaskState::Running,
-            output: None,
-            progress: None,
-            command: None,
-        });
-
-        if let Some(ref l) = locale {
-            self.tasks.push(TaskStatus {
-                name: format!("Setting locale to {l}"),
-                status: TaskState::Pending,
-                output: None,
-                progress: None,
-                command: None,
+        let already_created = self.created_username.as_deref() == Some(username.as_str());
+        let mut jobs = vec![ReviewJob::CreateUser {
+            username: username.clone(),
+            password: match &self.password_hash {
+                Some(hash) if self.password.is_empty() => Password::Hashed(hash.clone()),
+                _ => Password::Plain(self.password.content().to_string()),
+            },
+            groups: self.config.user.groups.clone(),
+            shell: self.config.user.shell.clone(),
+            already_created,
+            pending: self.pending_username.as_deref() == Some(username.as_str()),
+        }];
+        jobs.extend(self.selected_hostname.clone().map(|hostname| ReviewJob::SetHostname {
+            hostname,
+            pretty: self.selected_pretty_hostname.clone(),
+        }));
+        jobs.extend(self.selected_locale.clone().map(ReviewJob::SetLocale));
+        jobs.extend(self.selected_keyboard.clone().map(ReviewJob::SetKeymap));
+        jobs.extend(self.selected_timezone.clone().map(ReviewJob::SetTimezone));
+        if self.config.preferences.ntp_enabled {
+            jobs.push(ReviewJob::SetNtp(self.ntp_sync));
+        }
+        if self.config.preferences.keyring_enabled && self.create_keyring && !self.password.is_empty() {
+            // A password hash cannot unlock it; PAM creates it at first login instead
+            jobs.push(ReviewJob::CreateKeyring {
+                username: username.clone(),
+                password: Arc::new(Secret::new(self.password.content())),
             });
         }
-        if let Some(ref k) = keymap {
-            self.tasks.push(TaskStatus {
-                name: format!("Setting keyboard to {k}"),
+
+        // In dryrun mode, use tick-based simulation with progress bars
+        let dryrun = self.is_dryrun();
+        self.tasks = jobs.iter()
+            .map(|job| TaskStatus {
+                name: job.name(&self.catalog),
                 status: TaskState::Pending,
                 output: None,
-                progress: None,
-                command: None,
-            });
+                progress: dryrun.then_some(0),
+                command: dryrun.then(|| self.service.command_string(&job.op())),
+                count: None,
+                log: Vec::new(),
+            })
+            .collect();
+        self.task_step = Some(StepId::Review);
+        self.reset_log_pane();
+        let indices = (0..jobs.len()).collect();
+        self.review_jobs = jobs;
+
+        if dryrun {
+            self.created_username = Some(username);
+            self.start_dryrun_simulation(DryrunCallback::CompleteReview);
+            return None;
         }
-        if let Some(ref tz) = timezone {
-            self.tasks.push(TaskStatus {
-                name: format!("Setting timezone to {tz}"),
-                status: TaskState::Pending,
-                output: None,
-                progress: None,
-                command: None,
-            });
+
+        // Record the account before useradd runs, so a crash right after it can resume
+        if !already_created {
+            self.pending_username = Some(username);
+            self.persist();
         }
 
+        self.is_executing = true;
+        Some(self.spawn_review_runner(indices))
+    }
+
+    /// Run the given Review tasks in order; a failed user account stops the rest
+    fn spawn_review_runner(&self, indices: Vec<usize>) -> mpsc::UnboundedReceiver<ExecutionMessage> {
+        let jobs: Vec<(usize, ReviewJob)> = indices.into_iter()
+            .filter_map(|idx| Some((idx, self.review_jobs.get(idx)?.clone())))
+            .collect();
         let service = Arc::clone(&self.service);
+        let already_done = self.catalog.tr("task-already-done");
         let (tx, rx) = mpsc::unbounded_channel();
 
         tokio::spawn(async move {
-            // 1. Create user
-            let _ = tx.send(ExecutionMessage::TaskStarted(0));
-            let user_result = tokio::task::spawn_blocking({
-                let service = Arc::clone(&service);
-                let username = username.clone();
-                let password = password.clone();
-                let groups = groups.clone();
-                let shell = shell.clone();
-                move || service.create_user(&username, &password, &groups, &shell)
-            }).await;
-
-            let user_ok = match user_result {
-                Ok(Ok(())) => {
-                    let _ = tx.send(ExecutionMessage::TaskSuccess(0, None));
-                    let _ = tx.send(ExecutionMessage::UserCreated(Some(username)));
-                    true
-                }
-                Ok(Err(e)) => {
-                    let _ = tx.send(ExecutionMessage::TaskFailed(0, e.to_string()));
-                    let _ = tx.send(ExecutionMessage::UserCreated(None));
-                    false
-                }
-                Err(e) => {
-                    let _ = tx.send(ExecutionMessage::TaskFailed(0, e.to_string()));
-                    let _ = tx.send(ExecutionMessage::UserCreated(None));
-                    false
-                }
-            };
-
-            if !user_ok {
-                let _ = tx.send(ExecutionMessage::ReviewComplete { any_failed: true });
-                return;
-            }
-
             let mut any_failed = false;
-            let mut idx: usize = 1;
 
-            // 2. Apply locale
-            if let Some(locale) = locale {
+            for (idx, job) in jobs {
                 let _ = tx.send(ExecutionMessage::TaskStarted(idx));
-                let result = tokio::task::spawn_blocking({
-                    let service = Arc::clone(&service);
-                    let locale = locale.clone();
-                    move || service.set_locale(&locale)
-                }).await;
-                match result {
-                    Ok(Ok(())) => { let _ = tx.send(ExecutionMessage::TaskSuccess(idx, None)); }
-                    Ok(Err(e)) => { any_failed = true; let _ = tx.send(ExecutionMessage::TaskFailed(idx, e.to_string())); }
-                    Err(e) => { any_failed = true; let _ = tx.send(ExecutionMessage::TaskFailed(idx, e.to_string())); }
-                }
-                idx += 1;
-            }
+                let user = match &job {
+                    ReviewJob::CreateUser { username, .. } => Some(username.clone()),
+                    _ => None,
+                };
 
-            // 3. Apply keymap
-            if let Some(keymap) = keymap {
-                let _ = tx.send(ExecutionMessage::TaskStarted(idx));
-                let result = tokio::task::spawn_blocking({
-                    let service = Arc::clone(&service);
-                    let keymap = keymap.clone();
-                    move || service.set_keymap(&keymap)
-                }).await;
+                let service = Arc::clone(&service);
+                let result = tokio::task::spawn_blocking(move || job.run(service.as_ref())).await;
                 match result {
-                    Ok(Ok(())) => { let _ = tx.send(ExecutionMessage::TaskSuccess(idx, None)); }
-                    Ok(Err(e)) => { any_failed = true; let _ = tx.send(ExecutionMessage::TaskFailed(idx, e.to_string())); }
-                    Err(e) => { any_failed = true; let _ = tx.send(ExecutionMessage::TaskFailed(idx, e.to_string())); }
+                    Ok(Ok(done_before)) => {
+                        let note = done_before.then(|| already_done.clone());
+                        let _ = tx.send(ExecutionMessage::TaskSuccess(idx, note));
+                        if user.is_some() {
+                            let _ = tx.send(ExecutionMessage::UserCreated(user.clone()));
+                        }
+                    }
+                    Ok(Err(e)) => {
+                        any_failed = true;
+                        let _ = tx.send(ExecutionMessage::TaskFailed(idx, e.to_string()));
+                    }
+                    Err(e) => {
+                        any_failed = true;
+                        let _ = tx.send(ExecutionMessage::TaskFailed(idx, e.to_string()));
+                    }
                 }
-                idx += 1;
-            }
 
-            // 4. Apply timezone
-            if let Some(tz) = timezone {
-                let _ = tx.send(ExecutionMessage::TaskStarted(idx));
-                let result = tokio::task::spawn_blocking({
-                    let service = Arc::clone(&service);
-                    let tz = tz.clone();
-                    move || service.set_timezone(&tz)
-                }).await;
-                match result {
-                    Ok(Ok(())) => { let _ = tx.send(ExecutionMessage::TaskSuccess(idx, None)); }
-                    Ok(Err(e)) => { any_failed = true; let _ = tx.send(ExecutionMessage::TaskFailed(idx, e.to_string())); }
-                    Err(e) => { any_failed = true; let _ = tx.send(ExecutionMessage::TaskFailed(idx, e.to_string())); }
+                // Nothing else can be configured without the account
+                if any_failed && user.is_some() {
+                    let _ = tx.send(ExecutionMessage::UserCreated(None));
+                    break;
                 }
             }
 
             let _ = tx.send(ExecutionMessage::ReviewComplete { any_failed });
         });
 
-        Some(rx)
+        rx
     }
 
-    /// Check if any selected packages have commands that require sudo
+    /// Check if any selected packages have sudo commands that need the user's password,
+    /// which only `sudo_policy = "password"` asks for
     pub fn commands_need_sudo(&self) -> bool {
+        if self.config.general.sudo_policy != config::SudoPolicy::Password {
+            return false;
+        }
         for (cat_idx, cat) in self.config.updates.iter().enumerate() {
             for (pkg_idx, pkg) in cat.packages.iter().enumerate() {
                 let selected = self.update_package_selected
@@ -1571,9 +2555,12 @@ impl OnboardApp {
         false
     }
 
-    /// Get all commands from selected packages
-    fn selected_commands(&self) -> Vec<config::CommandConfig> {
-        let mut commands = Vec::new();
+    /// Get all selected packages, in config order
+    fn selected_packages(&self) -> Vec<config::PackageItem> {
+        let mut values = custom::defaults(&self.config.steps);
+        values.extend(self.step_values.clone());
+
+        let mut packages = Vec::new();
         for (cat_idx, cat) in self.config.updates.iter().enumerate() {
             for (pkg_idx, pkg) in cat.packages.iter().enumerate() {
                 let selected = self.update_package_selected
@@ -1582,11 +2569,16 @@ impl OnboardApp {
                     .copied()
                     .unwrap_or(false);
                 if selected {
-                    commands.extend(pkg.commands.iter().cloned());
+                    // Fill in what custom steps collected
+                    let mut pkg = pkg.clone();
+                    for cmd in &mut pkg.commands {
+                        cmd.command = custom::expand(&cmd.command, &values);
+                    }
+                    packages.push(pkg);
                 }
             }
         }
-        commands
+        packages
     }
 
     /// Check if any package is selected across all categories
@@ -1598,26 +2590,51 @@ impl OnboardApp {
     /// Start Update step execution - run commands from selected packages as the created user.
     /// Returns a receiver for execution messages, or None if handled synchronously.
     pub fn start_update_execution(&mut self) -> Option<mpsc::UnboundedReceiver<ExecutionMessage>> {
-        let commands = self.selected_commands();
+        let selected = self.selected_packages();
 
         // If no packages selected, skip
-        if commands.is_empty() {
+        if selected.is_empty() {
             if let Some(idx) = self.step_index_by_id(StepId::Update) {
                 self.step_results[idx] = StepResult::Skipped;
             }
             self.update_completed = true;
             self.unlock_login_step();
-            self.set_info("No packages selected. Continuing to finish.".to_string());
+            self.set_info(self.catalog.tr("msg-no-packages"));
             self.advance_to_next_step();
             return None;
         }
 
+        // Commands that succeeded before an interruption are not run again
+        let mut commands = Vec::new();
+        let mut packages = Vec::new();
+        for pkg in selected {
+            let first = commands.len();
+            commands.extend(pkg.commands.into_iter()
+                .filter(|cmd| !self.completed_commands.contains(&cmd.command_line())));
+            if commands.len() > first {
+                packages.push(PackageJob {
+                    title: pkg.title,
+                    depends_on: pkg.depends_on,
+                    group: pkg.group,
+                    tasks: (first..commands.len()).collect(),
+                });
+            }
+        }
+        if commands.is_empty() {
+            self.handle_execution_message(ExecutionMessage::UpdateComplete { any_failed: false });
+            return None;
+        }
+
         // In dryrun mode, use the tick-based simulation for progress animation
         if self.is_dryrun() {
             self.tasks.clear();
+            self.task_step = Some(StepId::Update);
+            self.reset_log_pane();
             for cmd_config in &commands {
-                let op = if cmd_config.sudo {
+                let op = if cmd_config.sudo && self.config.general.sudo_policy == config::SudoPolicy::Password {
                     ServiceOp::RunCommandSudo { cmd: cmd_config.command.clone() }
+                } else if cmd_config.sudo {
+                    ServiceOp::RunCommandRoot { cmd: cmd_config.command.clone() }
                 } else {
                     ServiceOp::RunCommand { cmd: cmd_config.command.clone() }
                 };
@@ -1627,29 +2644,34 @@ impl OnboardApp {
                     output: None,
                     progress: Some(0),
                     command: Some(self.service.command_string(&op)),
+                    count: None,
+                    log: Vec::new(),
                 });
             }
+            for pkg in &packages {
+                self.command_runs.extend(pkg.tasks.iter()
+                    .map(|&idx| CommandRun::new(&pkg.title, &commands[idx], RunOutcome::Simulated)));
+            }
             self.start_dryrun_simulation(DryrunCallback::CompleteUpdate);
             return None;
         }
 
         // Real execution requires a user
-        let username = match &self.created_username {
-            Some(u) => u.clone(),
-            None => {
-                self.set_error("User must be created before running commands".to_string());
-                return None;
-            }
-        };
+        if self.created_username.is_none() {
+            self.set_error(self.catalog.tr("msg-user-first"));
+            return None;
+        }
 
         // Check if sudo password is needed but not provided
         if self.commands_need_sudo() && !self.sudo_password_entered {
             self.sudo_password_needed = true;
-            self.set_error("Enter your password for sudo commands".to_string());
+            self.set_error(self.catalog.tr("msg-sudo-password"));
             return None;
         }
 
         self.tasks.clear();
+        self.task_step = Some(StepId::Update);
+        self.reset_log_pane();
         self.is_executing = true;
 
         // Build task list for UI
@@ -1660,52 +2682,287 @@ impl OnboardApp {
                 output: None,
                 progress: None,
                 command: None,
+                count: None,
+                log: Vec::new(),
             });
         }
 
+        self.progress_parsers = commands.iter().map(ProgressParser::for_command).collect();
+        let indices = (0..commands.len()).collect();
+        self.running_commands = commands;
+        self.running_packages = packages;
+        Some(self.spawn_update_runner(indices))
+    }
+
+    /// Titles of configured packages whose commands all succeeded, now or before an interruption
+    fn installed_packages(&self) -> Vec<String> {
+        self.config.updates.iter()
+            .flat_map(|category| &category.packages)
+            .filter(|pkg| pkg.commands.iter().all(|cmd| self.completed_commands.contains(&cmd.command_line())))
+            .map(|pkg| pkg.title.clone())
+            .collect()
+    }
+
+    /// Run the given Update tasks as the created user. Packages start once the packages
+    /// they depend on are installed, up to `parallel_installs` at a time; each runs its
+    /// commands in order, retrying as configured. A failure stops the rest of its package
+    /// and holds back its dependents unless the command has `continue_on_error`.
+    fn spawn_update_runner(&self, indices: Vec<usize>) -> mpsc::UnboundedReceiver<ExecutionMessage> {
+        let mut jobs = Vec::new();
+        let mut installed = self.installed_packages();
+        let mut skipped = Vec::new();
+        for pkg in &self.running_packages {
+            if pkg.tasks.iter().any(|&idx| self.tasks.get(idx).is_some_and(|t| t.status == TaskState::Skipped)) {
+                skipped.push(pkg.title.clone());
+            }
+            let tasks: Vec<usize> = pkg.tasks.iter().copied().filter(|idx| indices.contains(idx)).collect();
+            if !tasks.is_empty() {
+                jobs.push(PackageJob { tasks, ..pkg.clone() });
+            } else if !skipped.contains(&pkg.title) {
+                // Ran earlier in this run, before a retry
+                installed.push(pkg.title.clone());
+            }
+        }
+        let commands = self.running_commands.clone();
+        let parallel = self.config.general.parallel_installs;
         let service = Arc::clone(&self.service);
-        let sudo_pass = self.sudo_password.content().to_string();
+        let username = self.created_username.clone().unwrap_or_default();
+        // Only kept when sudo commands run through sudo rather than as root
+        let sudo_pass = self.commands_need_sudo()
+            .then(|| Arc::new(Secret::new(self.sudo_password.content())));
+        let catalog = self.catalog.clone();
         let (tx, rx) = mpsc::unbounded_channel();
 
         tokio::spawn(async move {
+            let mut schedule = Schedule::new(jobs, installed, skipped, parallel);
+            let mut running = tokio::task::JoinSet::new();
             let mut any_failed = false;
 
-            for (idx, cmd_config) in commands.iter().enumerate() {
-                let _ = tx.send(ExecutionMessage::TaskStarted(idx));
+            loop {
+                // Skipping a package can block the packages depending on it in turn
+                loop {
+                    let blocked = schedule.blocked();
+                    if blocked.is_empty() {
+                        break;
+                    }
+                    for (job, reason) in blocked {
+                        schedule.skip(job);
+                        for &idx in &schedule.job(job).tasks {
+                            let _ = tx.send(ExecutionMessage::TaskSkipped(idx, reason.clone()));
+                        }
+                    }
+                }
+
+                for job in schedule.ready() {
+                    schedule.start(job);
+                    let package: Vec<(usize, config::CommandConfig)> = schedule.job(job).tasks.iter()
+                        .map(|&idx| (idx, commands[idx].clone()))
+                        .collect();
+                    let service = Arc::clone(&service);
+                    let username = username.clone();
+                    let sudo_pass = sudo_pass.clone();
+                    let catalog = catalog.clone();
+                    let tx = tx.clone();
+                    running.spawn(async move {
+                        let (ok, failed) = Self::run_package(service, username, sudo_pass, package, catalog, tx).await;
+                        (job, ok, failed)
+                    });
+                }
+
+                // The package futures never panic; a failed join only happens on shutdown
+                let Some(Ok((job, ok, failed))) = running.join_next().await else {
+                    break;
+                };
+                any_failed |= failed;
+                schedule.finish(job, ok);
+            }
+
+            let _ = tx.send(ExecutionMessage::UpdateComplete { any_failed });
+        });
 
+        rx
+    }
+
+    /// Run one package's commands in order, with sudo commands going through sudo when
+    /// `sudo_pass` is given and running as root otherwise. Returns whether the package
+    /// finished and whether any command failed.
+    async fn run_package(
+        service: Arc<dyn OnboardService>,
+        username: String,
+        sudo_pass: Option<Arc<Secret>>,
+        commands: Vec<(usize, config::CommandConfig)>,
+        catalog: Catalog,
+        tx: mpsc::UnboundedSender<ExecutionMessage>,
+    ) -> (bool, bool) {
+        let mut any_failed = false;
+
+        for (idx, cmd_config) in commands {
+            let _ = tx.send(ExecutionMessage::TaskStarted(idx));
+            let started = std::time::Instant::now();
+
+            let mut attempt = 0;
+            let mut exit_code = None;
+            let error = loop {
                 let service = Arc::clone(&service);
                 let username = username.clone();
                 let sudo_pass = sudo_pass.clone();
                 let command = cmd_config.command.clone();
                 let use_sudo = cmd_config.sudo;
+                let output_tx = tx.clone();
 
                 let result = tokio::task::spawn_blocking(move || {
-                    if use_sudo {
-                        service.run_command_as_user_with_sudo(&username, &command, &sudo_pass)
-                    } else {
-                        service.run_command_as_user(&username, &command)
+                    let on_line = |line: &str| {
+                        let _ = output_tx.send(ExecutionMessage::TaskOutput(idx, line.to_string()));
+                    };
+                    match sudo_pass {
+                        Some(pass) if use_sudo => {
+                            service.run_command_as_user_with_sudo(&username, &command, &pass, &on_line)
+                        }
+                        None if use_sudo => service.run_command_as_root(&command, &on_line),
+                        _ => service.run_command_as_user(&username, &command, &on_line),
                     }
                 }).await;
 
-                match result {
+                let error = match result {
                     Ok(Ok(output)) => {
+                        exit_code = Some(0);
                         let _ = tx.send(ExecutionMessage::TaskSuccess(idx, Some(output)));
+                        break None;
                     }
                     Ok(Err(e)) => {
-                        any_failed = true;
-                        let _ = tx.send(ExecutionMessage::TaskFailed(idx, e.to_string()));
-                    }
-                    Err(e) => {
-                        any_failed = true;
-                        let _ = tx.send(ExecutionMessage::TaskFailed(idx, e.to_string()));
+                        exit_code = e.exit_code();
+                        e.to_string()
                     }
-                }
+                    Err(e) => e.to_string(),
+                };
+                if attempt >= cmd_config.retries {
+                    break Some(error);
+                }
+                attempt += 1;
+                let note = catalog.tr_args("task-retrying", &[
+                    ("error", error.as_str().into()),
+                    ("delay", cmd_config.retry_delay.into()),
+                    ("attempt", (attempt + 1).into()),
+                    ("attempts", (cmd_config.retries + 1).into()),
+                ]);
+                let _ = tx.send(ExecutionMessage::TaskOutput(idx, note));
+                tokio::time::sleep(std::time::Duration::from_secs(cmd_config.retry_delay)).await;
+            };
+
+            let failed = error.is_some();
+            if let Some(error) = error {
+                any_failed = true;
+                let _ = tx.send(ExecutionMessage::TaskFailed(idx, error));
+            }
+            let _ = tx.send(ExecutionMessage::CommandFinished {
+                idx,
+                exit_code,
+                attempts: attempt + 1,
+                duration: started.elapsed(),
+            });
+            if failed && !cmd_config.continue_on_error {
+                return (false, true);
             }
+        }
 
-            let _ = tx.send(ExecutionMessage::UpdateComplete { any_failed });
-        });
+        (true, any_failed)
+    }
+
+    /// Run a failed Review or Update task again, followed by the tasks still pending
+    pub fn retry_task(&mut self, idx: usize) -> Option<mpsc::UnboundedReceiver<ExecutionMessage>> {
+        if self.is_executing || self.tasks.get(idx).map(|t| t.status) != Some(TaskState::Failed) {
+            return None;
+        }
+
+        let task = &mut self.tasks[idx];
+        task.status = TaskState::Pending;
+        task.output = None;
+        task.progress = task.progress.map(|_| 0);
+        task.count = None;
+        task.log.clear();
+        if let Some(cmd) = self.running_commands.get(idx)
+            && let Some(parser) = self.progress_parsers.get_mut(idx)
+        {
+            *parser = ProgressParser::for_command(cmd);
+        }
+        self.message = None;
+        self.log_scroll = None;
+        self.run_pending_tasks()
+    }
+
+    /// Give up on a failed task (or one left pending after a failure) and carry on
+    pub fn skip_task(&mut self, idx: usize) -> Option<mpsc::UnboundedReceiver<ExecutionMessage>> {
+        if self.is_executing {
+            return None;
+        }
+        if self.task_step == Some(StepId::Review) && idx == 0 {
+            self.set_error(self.catalog.tr("msg-user-required"));
+            return None;
+        }
+
+        let task = self.tasks.get_mut(idx)
+            .filter(|t| matches!(t.status, TaskState::Failed | TaskState::Pending))?;
+        task.status = TaskState::Skipped;
+        self.skipped_tasks.push(task.name.clone());
+        self.message = None;
+
+        match self.blocking_task() {
+            Some(idx) if self.tasks[idx].status == TaskState::Failed => {
+                self.report_blocking_task(idx);
+                None
+            }
+            Some(_) => self.run_pending_tasks(),
+            // Nothing left to run: finish the step as if the batch had just ended
+            None => {
+                let done = match self.task_step {
+                    Some(StepId::Review) => ExecutionMessage::ReviewComplete { any_failed: false },
+                    _ => ExecutionMessage::UpdateComplete { any_failed: false },
+                };
+                self.handle_execution_message(done);
+                None
+            }
+        }
+    }
+
+    /// Start the batch again from its pending tasks
+    fn run_pending_tasks(&mut self) -> Option<mpsc::UnboundedReceiver<ExecutionMessage>> {
+        let pending: Vec<usize> = (0..self.tasks.len())
+            .filter(|&idx| self.tasks[idx].status == TaskState::Pending)
+            .collect();
+        if pending.is_empty() {
+            return None;
+        }
+
+        self.is_executing = true;
+        match self.task_step {
+            Some(StepId::Review) => Some(self.spawn_review_runner(pending)),
+            Some(StepId::Update) => Some(self.spawn_update_runner(pending)),
+            _ => {
+                self.is_executing = false;
+                None
+            }
+        }
+    }
+
+    /// Task that keeps the step from finishing: the first one failed without
+    /// `continue_on_error`, otherwise the first one still pending
+    fn blocking_task(&self) -> Option<usize> {
+        let failed = (0..self.tasks.len())
+            .find(|&idx| self.tasks[idx].status == TaskState::Failed && !self.continues_on_error(idx));
+        failed.or_else(|| self.tasks.iter().position(|t| t.status == TaskState::Pending))
+    }
+
+    fn continues_on_error(&self, idx: usize) -> bool {
+        self.task_step == Some(StepId::Update)
+            && self.running_commands.get(idx).is_some_and(|cmd| cmd.continue_on_error)
+    }
 
-        Some(rx)
+    /// Point the user at a failed task and the keys that deal with it
+    fn report_blocking_task(&mut self, idx: usize) {
+        self.log_task = idx;
+        self.log_scroll = None;
+        let name = self.tasks[idx].name.clone();
+        self.set_error(self.catalog.tr_args("msg-task-failed", &[("task", name.as_str().into())]));
     }
 
     fn step_index_by_id(&self, id: StepId) -> Option<usize> {
@@ -1714,12 +2971,24 @@ impl OnboardApp {
 
     /// Finish setup - run completion tasks and trigger final action
     pub async fn finish_setup(&mut self) {
+        let report_path = &self.config.completion.report;
+        if !report_path.is_empty()
+            && let Err(e) = self.report().write(std::path::Path::new(report_path))
+        {
+            warn!("Failed to write install report to {}: {}", report_path, e);
+        }
+
         self.is_executing = true;
         self.tasks.clear();
         self.execute_completion().await;
         self.is_executing = false;
         self.setup_complete = true;
 
+        // Nothing left to resume
+        if !self.is_dryrun() {
+            OnboardState::clear(&self.config.general.state_file);
+        }
+
         // In dryrun mode, always transition to login instead of rebooting/poweroff
         if self.is_dryrun() {
             self.confirm_action = Some(ConfirmAction::Reboot);
@@ -1740,6 +3009,25 @@ impl OnboardApp {
         }
     }
 
+    /// What setup configured and ran so far
+    pub fn report(&self) -> InstallReport {
+        InstallReport {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            finished_at: InstallReport::now(),
+            dryrun: self.is_dryrun(),
+            steps: self.menu_items.iter().zip(&self.step_results)
+                .map(|(item, &result)| StepReport { step: self.step_name(item.id).to_string(), result })
+                .collect(),
+            username: self.created_username.clone(),
+            hostname: self.selected_hostname.clone(),
+            locale: self.selected_locale.clone(),
+            keymap: self.selected_keyboard.clone(),
+            timezone: self.selected_timezone.clone(),
+            commands: self.command_runs.clone(),
+            skipped: self.skipped_tasks.clone(),
+        }
+    }
+
     /// Handle an execution message from a background task
     pub fn handle_execution_message(&mut self, msg: ExecutionMessage) {
         match msg {
@@ -1747,30 +3035,91 @@ impl OnboardApp {
                 if let Some(task) = self.tasks.get_mut(idx) {
                     task.status = TaskState::Running;
                 }
+                // A pane following the tail moves on to the new task
+                if self.log_scroll.is_none() {
+                    self.log_task = idx;
+                }
             }
             ExecutionMessage::TaskSuccess(idx, output) => {
                 if let Some(task) = self.tasks.get_mut(idx) {
                     task.status = TaskState::Success;
                     task.output = output;
+                    // Tools rarely print a final 100%
+                    if task.progress.is_some() {
+                        task.progress = Some(100);
+                    }
+                }
+                if let Some(command) = self.running_commands.get(idx) {
+                    self.completed_commands.push(command.command_line());
                 }
             }
             ExecutionMessage::TaskFailed(idx, error) => {
                 if let Some(task) = self.tasks.get_mut(idx) {
                     task.status = TaskState::Failed;
+                    // Keep the error viewable with `o` when nothing was streamed
+                    if task.log.is_empty() {
+                        for line in error.lines() {
+                            task.push_log(line.to_string());
+                        }
+                    }
                     task.output = Some(error.clone());
                 }
                 self.set_error(error);
             }
+            ExecutionMessage::TaskSkipped(idx, reason) => {
+                if let Some(task) = self.tasks.get_mut(idx) {
+                    task.status = TaskState::Skipped;
+                    task.push_log(reason.clone());
+                    self.skipped_tasks.push(format!("{} ({reason})", task.name));
+                    task.output = Some(reason);
+                }
+            }
+            ExecutionMessage::TaskOutput(idx, line) => {
+                let progress = self.progress_parsers
+                    .get_mut(idx)
+                    .and_then(Option::as_mut)
+                    .and_then(|parser| parser.parse(&line));
+                if let Some(task) = self.tasks.get_mut(idx) {
+                    if let Some(progress) = progress {
+                        task.progress = Some(progress.percent);
+                        task.count = progress.count;
+                    }
+                    task.push_log(line);
+                }
+                // Output does not change the persisted progress
+                return;
+            }
+            ExecutionMessage::CommandFinished { idx, exit_code, attempts, duration } => {
+                let package = self.running_packages.iter().find(|pkg| pkg.tasks.contains(&idx));
+                if let (Some(command), Some(package), Some(task)) =
+                    (self.running_commands.get(idx), package, self.tasks.get(idx))
+                {
+                    let outcome = if task.status == TaskState::Success { RunOutcome::Success } else { RunOutcome::Failed };
+                    // The error is only in the log when the command printed nothing
+                    let mut log = task.log.clone();
+                    if outcome == RunOutcome::Failed
+                        && let Some(error) = &task.output
+                        && log.last().is_none_or(|last| !error.ends_with(last.as_str()))
+                    {
+                        log.extend(error.lines().map(String::from));
+                    }
+                    let run = CommandRun::new(&package.title, command, outcome)
+                        .finished(exit_code, attempts, duration, &log);
+                    self.command_runs.push(run);
+                }
+            }
             ExecutionMessage::UserCreated(username) => {
+                if username.is_some() {
+                    self.pending_username = None;
+                }
                 self.created_username = username;
             }
-            ExecutionMessage::ReviewComplete { any_failed } => {
+            ExecutionMessage::ReviewComplete { .. } => {
                 self.is_executing = false;
                 self.current_task = None;
 
-                if any_failed {
-                    let failed_count = self.tasks.iter().filter(|t| t.status == TaskState::Failed).count();
-                    self.set_error(format!("{} task(s) failed during configuration", failed_count));
+                if let Some(blocking) = self.blocking_task() {
+                    self.report_blocking_task(blocking);
                     if let Some(idx) = self.step_index_by_id(StepId::Review) {
                         self.step_results[idx] = StepResult::Failed;
                     }
@@ -1785,24 +3134,44 @@ impl OnboardApp {
                     if self.step_index_by_id(StepId::Update).is_none() {
                         self.update_completed = true;
                         self.unlock_login_step();
-                        self.set_info("Configuration applied! Reboot to finish setup.".to_string());
+                        self.set_info(self.catalog.tr("msg-applied-reboot"));
                     } else {
                         self.unlock_update_step();
-                        self.set_info("Configuration applied! You can now install packages.".to_string());
+                        self.set_info(self.catalog.tr("msg-applied-install"));
                     }
                     self.advance_to_next_step();
                 }
             }
-            ExecutionMessage::UpdateComplete { any_failed } => {
+            ExecutionMessage::UpdateComplete { .. } => {
                 self.is_executing = false;
                 self.current_task = None;
 
-                if any_failed {
-                    let failed_count = self.tasks.iter().filter(|t| t.status == TaskState::Failed).count();
-                    self.set_error(format!("{} task(s) failed during configuration", failed_count));
+                // Wait for the user to retry or skip a failure that stopped the batch
+                if let Some(blocking) = self.blocking_task() {
+                    self.report_blocking_task(blocking);
+                    if let Some(idx) = self.step_index_by_id(StepId::Update) {
+                        self.step_results[idx] = StepResult::Failed;
+                    }
+                    self.persist();
+                    return;
+                }
+                self.running_commands.clear();
+                self.running_packages.clear();
+                self.progress_parsers.clear();
+                // Nothing else runs through sudo
+                self.sudo_password.clear();
+
+                // Failures let through by `continue_on_error` end up in the summary
+                let failed: Vec<String> = self.tasks.iter()
+                    .filter(|t| t.status == TaskState::Failed)
+                    .map(|t| self.catalog.tr_args("task-failed-summary", &[("task", t.name.as_str().into())]))
+                    .collect();
+                if failed.is_empty() {
+                    self.set_info(self.catalog.tr("msg-commands-completed"));
                 } else {
-                    self.set_info("Commands completed! Click Reboot to finish setup.".to_string());
+                    self.set_error(self.catalog.tr_args("msg-tasks-failed", &[("count", failed.len().into())]));
                 }
+                self.skipped_tasks.extend(failed);
 
                 if let Some(idx) = self.step_index_by_id(StepId::Update) {
                     self.step_results[idx] = StepResult::Completed;
@@ -1824,22 +3193,25 @@ impl OnboardApp {
                 }
             }
         }
+        self.persist();
     }
 
     async fn execute_completion(&mut self) {
         self.tasks.push(TaskStatus {
-            name: "Finishing setup".to_string(),
+            name: self.catalog.tr("task-finishing"),
             status: TaskState::Running,
             output: None,
             progress: None,
             command: None,
+            count: None,
+            log: Vec::new(),
         });
         self.current_task = Some(self.tasks.len() - 1);
 
-        if self.config.completion.remove_initial_session {
-            if let Err(e) = self.service.remove_initial_session() {
-                warn!("Failed to remove initial session: {e}");
-            }
+        if self.config.completion.remove_initial_session
+            && let Err(e) = self.service.remove_initial_session()
+        {
+            warn!("Failed to remove initial session: {e}");
         }
 
         if self.is_dryrun() {
@@ -1904,7 +3276,7 @@ impl OnboardApp {
                         self.update_completed = true;
                         self.unlock_login_step();
                         self.tasks.clear();
-                        self.set_info("Installation complete! Reboot to finish setup.".to_string());
+                        self.set_info(self.catalog.tr("msg-installation-complete"));
                         self.advance_to_next_step();
                     }
                     DryrunCallback::CompleteReview => {
@@ -1918,10 +3290,10 @@ impl OnboardApp {
                         if self.step_index_by_id(StepId::Update).is_none() {
                             self.update_completed = true;
                             self.unlock_login_step();
-                            self.set_info("Configuration applied! Reboot to finish setup.".to_string());
+                            self.set_info(self.catalog.tr("msg-applied-reboot"));
                         } else {
                             self.unlock_update_step();
-                            self.set_info("Configuration applied! Select packages to install.".to_string());
+                            self.set_info(self.catalog.tr("msg-applied-select"));
                         }
                         self.advance_to_next_step();
                     }
@@ -1930,8 +3302,16 @@ impl OnboardApp {
             return;
         }
 
-        // Mark current task as running
-        self.tasks[task_idx].status = TaskState::Running;
+        // Mark current task as running, echoing its command into the output pane
+        if self.tasks[task_idx].status == TaskState::Pending {
+            self.tasks[task_idx].status = TaskState::Running;
+            if let Some(cmd) = self.tasks[task_idx].command.clone() {
+                self.tasks[task_idx].push_log(format!("$ {cmd}"));
+            }
+            if self.log_scroll.is_none() {
+                self.log_task = task_idx;
+            }
+        }
 
         // Advance progress
         self.dryrun_sim_progress += 10;
@@ -1974,20 +3354,24 @@ impl OnboardApp {
                     return;
                 }
             }
-            self.status_bar = StatusBarState::executing();
+            self.status_bar = if self.shows_task_log() && self.panel_focus == PanelFocus::Content {
+                StatusBarState::task_output(&self.catalog, false)
+            } else {
+                StatusBarState::executing(&self.catalog)
+            };
             return;
         }
 
         // Command mode overrides everything
         if self.vim_mode == VimMode::Command {
-            self.status_bar = StatusBarState::command_mode();
+            self.status_bar = StatusBarState::command_mode(&self.catalog);
             return;
         }
 
         // Update based on panel focus and current step
         self.status_bar = match self.panel_focus {
-            PanelFocus::Welcome => StatusBarState::welcome(),
-            PanelFocus::Sidebar => StatusBarState::sidebar_normal(),
+            PanelFocus::Welcome => StatusBarState::welcome(&self.catalog),
+            PanelFocus::Sidebar => StatusBarState::sidebar_normal(&self.catalog),
             PanelFocus::Content => self.content_status_bar(),
         };
     }
@@ -1996,7 +3380,7 @@ impl OnboardApp {
     fn content_status_bar(&self) -> StatusBarState {
         // Check if step is locked
         if self.is_current_step_locked() {
-            return StatusBarState::locked_step();
+            return StatusBarState::locked_step(&self.catalog);
         }
 
         let step_id = match self.current_step_id() {
@@ -2007,33 +3391,57 @@ impl OnboardApp {
         let is_insert = self.vim_mode == VimMode::Insert;
 
         match step_id {
-            StepId::User => {
+            StepId::User | StepId::Hostname => {
                 if is_insert {
-                    StatusBarState::content_form_insert()
+                    StatusBarState::content_form_insert(&self.catalog)
                 } else {
-                    StatusBarState::content_form_normal()
+                    StatusBarState::content_form_normal(&self.catalog)
                 }
             }
+            StepId::Preferences if matches!(self.content_focus, ContentFocus::Toggle(_)) => {
+                StatusBarState::content_toggles(&self.catalog)
+            }
             StepId::Locale | StepId::Keyboard | StepId::Preferences => {
                 if is_insert {
-                    StatusBarState::content_picker_insert()
+                    StatusBarState::content_picker_insert(&self.catalog)
                 } else {
-                    StatusBarState::content_picker_normal()
+                    StatusBarState::content_picker_normal(&self.catalog)
                 }
             }
-            StepId::Network => StatusBarState::network_step(self.network_connected),
-            StepId::Review => StatusBarState::review_step(),
+            StepId::Network => StatusBarState::network_step(&self.catalog, self.network_connected),
+            StepId::Review | StepId::Update if self.shows_task_log() => {
+                StatusBarState::task_output(&self.catalog, self.selected_failed_task().is_some())
+            }
+            StepId::Review => StatusBarState::review_step(&self.catalog),
             StepId::Update => {
                 let needs_password = self.commands_need_sudo()
                     && !self.sudo_password_entered
                     && !self.is_dryrun();
                 if is_insert && needs_password {
-                    StatusBarState::content_form_insert()
+                    StatusBarState::content_form_insert(&self.catalog)
                 } else {
-                    StatusBarState::update_step(needs_password)
+                    StatusBarState::update_step(&self.catalog, needs_password)
                 }
             }
-            StepId::Reboot => StatusBarState::reboot_step(),
+            StepId::Reboot => StatusBarState::reboot_step(&self.catalog),
+            StepId::Custom(idx) => match &self.config.steps[idx].kind {
+                CustomStepKind::Form { .. } if is_insert => StatusBarState::content_form_insert(&self.catalog),
+                CustomStepKind::Form { .. } => StatusBarState::content_form_normal(&self.catalog),
+                CustomStepKind::Choice { .. } if is_insert => StatusBarState::content_picker_insert(&self.catalog),
+                CustomStepKind::Choice { .. } => StatusBarState::content_picker_normal(&self.catalog),
+                CustomStepKind::MultiChoice { .. } => StatusBarState::content_toggles(&self.catalog),
+                CustomStepKind::Info => StatusBarState::info_step(&self.catalog),
+                CustomStepKind::Program { .. } => StatusBarState::program_step(&self.catalog),
+            },
         }
     }
 }
+
+/// Reject answer file values the system does not offer (an empty list accepts anything)
+fn check_answer(kind: &str, value: &str, available: &[String]) -> error::Result<()> {
+    if available.is_empty() || available.iter().any(|v| v == value) {
+        Ok(())
+    } else {
+        Err(OnboardError::Answers(format!("unknown {kind}: {value}")))
+    }
+}
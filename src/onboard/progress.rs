@@ -0,0 +1,174 @@
+//! Turning streamed command output into progress for the Update step's task bars
+
+use regex::Regex;
+use serde::Deserialize;
+use std::sync::LazyLock;
+
+use super::config::CommandConfig;
+
+/// Last percentage on a line, e.g. `45%` or `12.5%`
+static PERCENT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(\d{1,3})(?:\.\d+)?%").unwrap());
+
+/// dnf5 `[ 3/12]`, dnf4 downloads `(3/12):` and dnf4 transactions ending in `3/12`
+static DNF_COUNT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\[\s*(\d+)/(\d+)\]|^\((\d+)/(\d+)\):|\s(\d+)/(\d+)\s*$").unwrap()
+});
+
+/// `Installing 1/2…`, `Updating 3/5`
+static FLATPAK_COUNT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:Installing|Updating|Uninstalling)\s+(\d+)/(\d+)").unwrap());
+
+/// How a command reports progress, from `progress = "..."` on a command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProgressFormat {
+    Dnf,
+    Flatpak,
+    Brew,
+    /// `progress_pattern`, or the last percentage on each line without one
+    PercentRegex,
+    /// Never show a bar, even if the command would be auto-detected
+    None,
+}
+
+impl ProgressFormat {
+    /// Guess the format from the program being run, looking past `sudo` and `env`
+    pub fn detect(command: &[String]) -> Option<Self> {
+        let program = command
+            .iter()
+            .map(|arg| arg.rsplit('/').next().unwrap_or(arg))
+            .find(|arg| !matches!(*arg, "sudo" | "env") && !arg.starts_with('-') && !arg.contains('='))?;
+        match program {
+            "dnf" | "dnf5" | "yum" => Some(ProgressFormat::Dnf),
+            "flatpak" => Some(ProgressFormat::Flatpak),
+            "brew" => Some(ProgressFormat::Brew),
+            _ => None,
+        }
+    }
+}
+
+/// Overall progress of one task
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    pub percent: u8,
+    /// Item being worked on and how many there are, when the tool says
+    pub count: Option<(u32, u32)>,
+}
+
+/// Reads one command's output line by line and reports progress changes
+#[derive(Debug, Clone)]
+pub struct ProgressParser {
+    format: ProgressFormat,
+    pattern: Option<Regex>,
+    // brew announces its dependencies up front, then pours one bottle each
+    brew_total: Option<u32>,
+    brew_poured: u32,
+}
+
+impl ProgressParser {
+    pub fn new(format: ProgressFormat, pattern: Option<Regex>) -> Self {
+        Self {
+            format,
+            pattern,
+            brew_total: None,
+            brew_poured: 0,
+        }
+    }
+
+    /// Parser for a configured command: its `progress` key, then `progress_pattern`,
+    /// then auto-detection. `None` when no progress can be shown.
+    pub fn for_command(cmd: &CommandConfig) -> Option<Self> {
+        let format = cmd
+            .progress
+            .or_else(|| cmd.progress_pattern.as_ref().map(|_| ProgressFormat::PercentRegex))
+            .or_else(|| ProgressFormat::detect(&cmd.command))?;
+        if format == ProgressFormat::None {
+            return None;
+        }
+        Some(Self::new(format, cmd.progress_pattern.clone()))
+    }
+
+    /// Progress after `line`, or `None` if the line says nothing about it
+    pub fn parse(&mut self, line: &str) -> Option<Progress> {
+        match self.format {
+            ProgressFormat::Dnf => {
+                let count = DNF_COUNT.captures(line).and_then(|c| {
+                    let mut numbers = c.iter().skip(1).flatten().map(|m| m.as_str().parse().ok());
+                    Some((numbers.next()??, numbers.next()??))
+                });
+                combine(count, last_percent(line))
+            }
+            ProgressFormat::Flatpak => {
+                let count = FLATPAK_COUNT
+                    .captures(line)
+                    .and_then(|c| Some((c[1].parse().ok()?, c[2].parse().ok()?)));
+                combine(count, last_percent(line))
+            }
+            ProgressFormat::Brew => self.parse_brew(line),
+            ProgressFormat::PercentRegex => {
+                let pattern = self.pattern.as_ref().unwrap_or(&PERCENT);
+                let caps = pattern.captures(line)?;
+                let number = |name: &str| caps.name(name).and_then(|m| m.as_str().parse::<f32>().ok());
+                let percent = number("percent").or_else(|| {
+                    (caps.len() > 1 && pattern.capture_names().flatten().next().is_none())
+                        .then(|| caps.get(1)?.as_str().parse().ok())
+                        .flatten()
+                });
+                let count = number("current")
+                    .zip(number("total"))
+                    .map(|(current, total)| (current as u32, total as u32));
+                combine(count, percent.map(|p| p.min(100.0) as u8))
+            }
+            ProgressFormat::None => None,
+        }
+    }
+
+    fn parse_brew(&mut self, line: &str) -> Option<Progress> {
+        let line = line.trim();
+        if let Some(deps) = line
+            .strip_prefix("==> Installing dependencies for ")
+            .and_then(|rest| rest.split_once(": "))
+            .map(|(_, deps)| deps)
+        {
+            let count = deps.split(", ").flat_map(|d| d.split(" and ")).count() as u32;
+            // The dependencies, then the formula itself
+            self.brew_total = Some(count + 1);
+            return combine(Some((1, count + 1)), Some(0));
+        }
+        if line.starts_with("==> Pouring ") {
+            self.brew_poured += 1;
+            let total = self.brew_total.unwrap_or(1).max(self.brew_poured);
+            return combine(Some((self.brew_poured, total)), None);
+        }
+        if line.starts_with("==> Summary") || line.starts_with("==> Caveats") {
+            let total = self.brew_total.unwrap_or(1);
+            return combine(Some((total, total)), None);
+        }
+        // curl's bar while a bottle downloads
+        let percent = last_percent(line)?;
+        let total = self.brew_total.unwrap_or(1);
+        combine(Some(((self.brew_poured + 1).min(total), total)), Some(percent))
+    }
+}
+
+fn last_percent(line: &str) -> Option<u8> {
+    let caps = PERCENT.captures_iter(line).last()?;
+    Some(caps[1].parse::<u8>().unwrap_or(100).min(100))
+}
+
+/// Overall percentage from "item n of m" and the percentage of item n.
+/// A count without a percentage means item n is done.
+fn combine(count: Option<(u32, u32)>, percent: Option<u8>) -> Option<Progress> {
+    match count {
+        Some((current, total)) if total > 0 => {
+            let current = current.clamp(1, total);
+            let done = (current - 1) * 100 + percent.unwrap_or(100) as u32;
+            Some(Progress {
+                percent: (done / total).min(100) as u8,
+                count: Some((current, total)),
+            })
+        }
+        _ => percent.map(|percent| Progress { percent, count: None }),
+    }
+}
@@ -0,0 +1,162 @@
+//! What setup did, written when it finishes so support can see it later
+
+use chrono::{Local, SecondsFormat};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use std::path::Path;
+use std::time::Duration;
+
+use super::config::CommandConfig;
+use super::steps::StepResult;
+
+/// Output lines kept per command in the report
+const REPORT_OUTPUT_LINES: usize = 50;
+
+/// Everything a finished setup configured and ran
+#[derive(Debug, Clone, Serialize)]
+pub struct InstallReport {
+    pub version: String,
+    /// RFC 3339 local time
+    pub finished_at: String,
+    pub dryrun: bool,
+    pub steps: Vec<StepReport>,
+    pub username: Option<String>,
+    pub hostname: Option<String>,
+    pub locale: Option<String>,
+    pub keymap: Option<String>,
+    pub timezone: Option<String>,
+    /// Update commands in the order they finished; a retried command shows up once per run
+    pub commands: Vec<CommandRun>,
+    /// Tasks skipped or failed and let through, with the reason when there is one
+    pub skipped: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StepReport {
+    pub step: String,
+    pub result: StepResult,
+}
+
+/// How a command run ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RunOutcome {
+    Success,
+    Failed,
+    /// Not run because of dryrun
+    Simulated,
+}
+
+/// One run of an Update command
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommandRun {
+    pub package: String,
+    pub name: String,
+    pub command: Vec<String>,
+    pub sudo: bool,
+    pub outcome: RunOutcome,
+    /// Unset when the command could not start or a signal ended it
+    pub exit_code: Option<i32>,
+    /// Including retries
+    pub attempts: u32,
+    pub duration_ms: u64,
+    /// Last lines printed
+    pub output: Vec<String>,
+}
+
+impl CommandRun {
+    pub fn new(package: &str, command: &CommandConfig, outcome: RunOutcome) -> Self {
+        Self {
+            package: package.to_string(),
+            name: command.name.clone(),
+            command: command.command.clone(),
+            sudo: command.sudo,
+            outcome,
+            exit_code: None,
+            attempts: 0,
+            duration_ms: 0,
+            output: Vec::new(),
+        }
+    }
+
+    pub fn finished(mut self, exit_code: Option<i32>, attempts: u32, duration: Duration, log: &[String]) -> Self {
+        self.exit_code = exit_code;
+        self.attempts = attempts;
+        self.duration_ms = duration.as_millis() as u64;
+        self.output = log[log.len().saturating_sub(REPORT_OUTPUT_LINES)..].to_vec();
+        self
+    }
+}
+
+impl InstallReport {
+    pub fn now() -> String {
+        Local::now().to_rfc3339_opts(SecondsFormat::Secs, false)
+    }
+
+    /// Plain-text version for people, in the same order as the JSON
+    pub fn summary(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "Hypercube onboarding report (version {})", self.version);
+        let _ = writeln!(out, "Finished {}{}", self.finished_at, if self.dryrun { " (dryrun)" } else { "" });
+        let _ = writeln!(out);
+
+        let _ = writeln!(out, "Steps:");
+        for step in &self.steps {
+            let _ = writeln!(out, "  {:<12} {:?}", step.step, step.result);
+        }
+        let _ = writeln!(out);
+
+        let settings = [
+            ("User", &self.username),
+            ("Hostname", &self.hostname),
+            ("Locale", &self.locale),
+            ("Keymap", &self.keymap),
+            ("Timezone", &self.timezone),
+        ];
+        for (label, value) in settings {
+            let _ = writeln!(out, "{:<10} {}", format!("{label}:"), value.as_deref().unwrap_or("-"));
+        }
+
+        if !self.commands.is_empty() {
+            let _ = writeln!(out);
+            let _ = writeln!(out, "Commands:");
+        }
+        for run in &self.commands {
+            let status = match (run.outcome, run.exit_code) {
+                (RunOutcome::Simulated, _) => "simulated".to_string(),
+                (_, Some(code)) => format!("exit {code}"),
+                (RunOutcome::Success, None) => "ok".to_string(),
+                (RunOutcome::Failed, None) => "failed".to_string(),
+            };
+            let duration = match run.outcome {
+                RunOutcome::Simulated => String::new(),
+                _ => format!(" ({:.1}s)", run.duration_ms as f64 / 1000.0),
+            };
+            let _ = writeln!(out, "  [{status}] {} / {}{duration}: {}", run.package, run.name, run.command.join(" "));
+            if run.outcome == RunOutcome::Failed {
+                for line in &run.output {
+                    let _ = writeln!(out, "      {line}");
+                }
+            }
+        }
+
+        if !self.skipped.is_empty() {
+            let _ = writeln!(out);
+            let _ = writeln!(out, "Skipped:");
+            for task in &self.skipped {
+                let _ = writeln!(out, "  - {task}");
+            }
+        }
+        out
+    }
+
+    /// Write the report as JSON to `path` and the summary next to it, with a `.txt` extension
+    pub fn write(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        std::fs::write(path, json + "\n")?;
+        std::fs::write(path.with_extension("txt"), self.summary())
+    }
+}
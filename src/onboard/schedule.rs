@@ -0,0 +1,143 @@
+//! Ordering selected packages by `depends_on` and running independent ones side by side
+
+/// One selected package and the tasks of its commands
+#[derive(Debug, Clone)]
+pub struct PackageJob {
+    pub title: String,
+    /// Titles of packages that must finish first
+    pub depends_on: Vec<String>,
+    /// Packages sharing a group never run at the same time
+    pub group: Option<String>,
+    /// Task indices of the package's commands, run in order
+    pub tasks: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JobState {
+    Waiting,
+    Running,
+    Done,
+    /// Failed in this run; dependents wait for a retry or skip
+    Failed,
+    /// Will not be installed; dependents are skipped too
+    Skipped,
+    /// Not part of this run and not installed before
+    Unselected,
+}
+
+/// Decides which packages may start as others finish
+pub struct Schedule {
+    jobs: Vec<PackageJob>,
+    state: Vec<JobState>,
+    /// Packages outside this run that are installed already
+    installed: Vec<String>,
+    /// Packages skipped earlier, in whole or in part
+    skipped: Vec<String>,
+    parallel: usize,
+}
+
+impl Schedule {
+    pub fn new(jobs: Vec<PackageJob>, installed: Vec<String>, skipped: Vec<String>, parallel: usize) -> Self {
+        let state = vec![JobState::Waiting; jobs.len()];
+        Self {
+            jobs,
+            state,
+            installed,
+            skipped,
+            parallel: parallel.max(1),
+        }
+    }
+
+    pub fn job(&self, idx: usize) -> &PackageJob {
+        &self.jobs[idx]
+    }
+
+    /// Waiting packages that may start now, in config order, within the parallelism limit
+    pub fn ready(&self) -> Vec<usize> {
+        let mut running = self.count(JobState::Running);
+        let mut groups: Vec<&str> = self.jobs.iter().zip(&self.state)
+            .filter(|(_, state)| **state == JobState::Running)
+            .filter_map(|(job, _)| job.group.as_deref())
+            .collect();
+
+        let mut ready = Vec::new();
+        for (idx, job) in self.jobs.iter().enumerate() {
+            if running >= self.parallel {
+                break;
+            }
+            if self.state[idx] != JobState::Waiting
+                || !job.depends_on.iter().all(|dep| self.dependency(dep) == JobState::Done)
+                || job.group.as_deref().is_some_and(|g| groups.contains(&g))
+            {
+                continue;
+            }
+            ready.push(idx);
+            running += 1;
+            groups.extend(job.group.as_deref());
+        }
+        ready
+    }
+
+    /// Waiting packages that can never start, with the reason
+    pub fn blocked(&self) -> Vec<(usize, String)> {
+        let waiting = || (0..self.jobs.len()).filter(|&idx| self.state[idx] == JobState::Waiting);
+
+        let blocked: Vec<(usize, String)> = waiting()
+            .filter_map(|idx| {
+                self.jobs[idx].depends_on.iter().find_map(|dep| match self.dependency(dep) {
+                    JobState::Skipped => Some((idx, format!("{dep} was not installed"))),
+                    JobState::Unselected => Some((idx, format!("{dep} was not selected"))),
+                    _ => None,
+                })
+            })
+            .collect();
+        if !blocked.is_empty() {
+            return blocked;
+        }
+
+        // Nothing can move and no failure holds them: the packages wait on each other
+        let stuck = self.count(JobState::Running) == 0
+            && self.count(JobState::Failed) == 0
+            && self.ready().is_empty();
+        if !stuck {
+            return Vec::new();
+        }
+        waiting()
+            .map(|idx| (idx, format!("circular depends_on: {}", self.jobs[idx].depends_on.join(", "))))
+            .collect()
+    }
+
+    pub fn start(&mut self, idx: usize) {
+        self.state[idx] = JobState::Running;
+    }
+
+    /// Record how a package's commands ended
+    pub fn finish(&mut self, idx: usize, ok: bool) {
+        self.state[idx] = if !ok {
+            JobState::Failed
+        } else if self.skipped.contains(&self.jobs[idx].title) {
+            // Some of its commands were skipped before, so it is not fully installed
+            JobState::Skipped
+        } else {
+            JobState::Done
+        };
+    }
+
+    pub fn skip(&mut self, idx: usize) {
+        self.state[idx] = JobState::Skipped;
+    }
+
+    /// Packages outside this run count as done only if they were installed before
+    fn dependency(&self, title: &str) -> JobState {
+        match self.jobs.iter().position(|job| job.title == title) {
+            Some(idx) => self.state[idx],
+            None if self.skipped.iter().any(|s| s == title) => JobState::Skipped,
+            None if self.installed.iter().any(|s| s == title) => JobState::Done,
+            None => JobState::Unselected,
+        }
+    }
+
+    fn count(&self, state: JobState) -> usize {
+        self.state.iter().filter(|s| **s == state).count()
+    }
+}
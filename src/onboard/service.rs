@@ -1,8 +1,42 @@
+use std::fmt;
 use std::sync::Arc;
+use tracing::warn;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
+use super::config::SystemBackend;
+use super::dbus::DbusService;
 use super::error::Result;
 use super::executor;
 
+/// Password for the new account, wiped from memory when dropped
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub enum Password {
+    /// Typed into the wizard
+    Plain(String),
+    /// crypt(3) hash from an answer file, set with `chpasswd -e`
+    Hashed(String),
+}
+
+/// A password handed to a child process over a pipe, wiped from memory when dropped
+#[derive(Clone, Default, Zeroize, ZeroizeOnDrop)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(value: &str) -> Self {
+        Self(value.to_string())
+    }
+
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Secret(***)")
+    }
+}
+
 /// Describes a service operation for command string display
 #[derive(Debug, Clone)]
 pub enum ServiceOp {
@@ -20,9 +54,22 @@ pub enum ServiceOp {
     SetTimezone {
         timezone: String,
     },
+    SetHostname {
+        hostname: String,
+        pretty: Option<String>,
+    },
+    SetNtp {
+        enabled: bool,
+    },
+    CreateKeyring {
+        username: String,
+    },
     RunCommand {
         cmd: Vec<String>,
     },
+    RunCommandRoot {
+        cmd: Vec<String>,
+    },
     RunCommandSudo {
         cmd: Vec<String>,
     },
@@ -36,13 +83,29 @@ pub trait OnboardService: Send + Sync {
     fn list_keymaps(&self) -> Vec<String>;
     fn list_timezones(&self) -> Vec<String>;
 
+    fn user_exists(&self, username: &str) -> bool;
+    /// Serial number for the `{serial}` hostname placeholder, if the machine has one
+    fn machine_serial(&self) -> Option<String>;
+
     // Mutating methods
-    fn create_user(&self, username: &str, password: &str, groups: &[String], shell: &str) -> Result<()>;
+    fn create_user(&self, username: &str, password: &Password, groups: &[String], shell: &str) -> Result<()>;
+    /// Set the password of an account that already exists
+    fn set_password(&self, username: &str, password: &Password) -> Result<()>;
     fn set_locale(&self, locale: &str) -> Result<()>;
     fn set_keymap(&self, keymap: &str) -> Result<()>;
     fn set_timezone(&self, timezone: &str) -> Result<()>;
-    fn run_command_as_user(&self, username: &str, cmd: &[String]) -> Result<String>;
-    fn run_command_as_user_with_sudo(&self, username: &str, cmd: &[String], password: &str) -> Result<String>;
+    /// Set the static hostname, and the pretty one when given
+    fn set_hostname(&self, hostname: &str, pretty: Option<&str>) -> Result<()>;
+    /// Turn network time synchronization on or off
+    fn set_ntp(&self, enabled: bool) -> Result<()>;
+    /// Create the login keyring of `username`, unlocked by their password
+    fn create_keyring(&self, username: &str, password: &Secret) -> Result<()>;
+    /// Run a command as `username`, handing each line of output to `on_line` as it appears
+    fn run_command_as_user(&self, username: &str, cmd: &[String], on_line: &dyn Fn(&str)) -> Result<String>;
+    /// Run a `sudo = true` command as root, which onboard already is
+    fn run_command_as_root(&self, cmd: &[String], on_line: &dyn Fn(&str)) -> Result<String>;
+    /// Run a `sudo = true` command through sudo as `username`, for `sudo_policy = "password"`
+    fn run_command_as_user_with_sudo(&self, username: &str, cmd: &[String], password: &Secret, on_line: &dyn Fn(&str)) -> Result<String>;
     fn remove_initial_session(&self) -> Result<()>;
 
     // Command description for display
@@ -70,11 +133,24 @@ fn format_command_string(op: &ServiceOp) -> String {
         ServiceOp::SetTimezone { timezone } => {
             format!("timedatectl set-timezone {timezone}")
         }
+        ServiceOp::SetHostname { hostname, pretty } => match pretty {
+            Some(pretty) => format!("hostnamectl set-hostname {hostname} && hostnamectl set-hostname --pretty \"{pretty}\""),
+            None => format!("hostnamectl set-hostname {hostname}"),
+        },
+        ServiceOp::SetNtp { enabled } => {
+            format!("timedatectl set-ntp {enabled}")
+        }
+        ServiceOp::CreateKeyring { username } => {
+            format!("gnome-keyring-daemon --unlock (as {username})")
+        }
         ServiceOp::RunCommand { cmd } => {
             cmd.join(" ")
         }
+        ServiceOp::RunCommandRoot { cmd } => {
+            format!("{} (as root)", executor::strip_sudo(cmd).unwrap_or(cmd).join(" "))
+        }
         ServiceOp::RunCommandSudo { cmd } => {
-            format!("sudo {}", cmd.join(" "))
+            format!("sudo {}", executor::strip_sudo(cmd).unwrap_or(cmd).join(" "))
         }
     }
 }
@@ -99,10 +175,22 @@ impl OnboardService for LiveService {
         executor::list_timezones(false)
     }
 
-    fn create_user(&self, username: &str, password: &str, groups: &[String], shell: &str) -> Result<()> {
+    fn user_exists(&self, username: &str) -> bool {
+        executor::user_exists(username)
+    }
+
+    fn machine_serial(&self) -> Option<String> {
+        executor::machine_serial()
+    }
+
+    fn create_user(&self, username: &str, password: &Password, groups: &[String], shell: &str) -> Result<()> {
         executor::create_user(username, password, groups, shell)
     }
 
+    fn set_password(&self, username: &str, password: &Password) -> Result<()> {
+        executor::set_password(username, password)
+    }
+
     fn set_locale(&self, locale: &str) -> Result<()> {
         executor::set_locale(locale)
     }
@@ -115,12 +203,28 @@ impl OnboardService for LiveService {
         executor::set_timezone(timezone)
     }
 
-    fn run_command_as_user(&self, username: &str, cmd: &[String]) -> Result<String> {
-        executor::run_command_as_user(username, cmd)
+    fn set_hostname(&self, hostname: &str, pretty: Option<&str>) -> Result<()> {
+        executor::set_hostname(hostname, pretty)
+    }
+
+    fn set_ntp(&self, enabled: bool) -> Result<()> {
+        executor::set_ntp(enabled)
+    }
+
+    fn create_keyring(&self, username: &str, password: &Secret) -> Result<()> {
+        executor::create_keyring(username, password)
+    }
+
+    fn run_command_as_user(&self, username: &str, cmd: &[String], on_line: &dyn Fn(&str)) -> Result<String> {
+        executor::run_command_as_user(username, cmd, on_line)
+    }
+
+    fn run_command_as_root(&self, cmd: &[String], on_line: &dyn Fn(&str)) -> Result<String> {
+        executor::run_command_as_root(cmd, on_line)
     }
 
-    fn run_command_as_user_with_sudo(&self, username: &str, cmd: &[String], password: &str) -> Result<String> {
-        executor::run_command_as_user_with_sudo(username, cmd, password)
+    fn run_command_as_user_with_sudo(&self, username: &str, cmd: &[String], password: &Secret, on_line: &dyn Fn(&str)) -> Result<String> {
+        executor::run_command_as_user_with_sudo(username, cmd, password, on_line)
     }
 
     fn remove_initial_session(&self) -> Result<()> {
@@ -152,7 +256,19 @@ impl OnboardService for DryrunService {
         executor::list_timezones(true)
     }
 
-    fn create_user(&self, _username: &str, _password: &str, _groups: &[String], _shell: &str) -> Result<()> {
+    fn user_exists(&self, _username: &str) -> bool {
+        false
+    }
+
+    fn machine_serial(&self) -> Option<String> {
+        Some("PF2XK7QD".to_string())
+    }
+
+    fn create_user(&self, _username: &str, _password: &Password, _groups: &[String], _shell: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn set_password(&self, _username: &str, _password: &Password) -> Result<()> {
         Ok(())
     }
 
@@ -168,11 +284,27 @@ impl OnboardService for DryrunService {
         Ok(())
     }
 
-    fn run_command_as_user(&self, _username: &str, _cmd: &[String]) -> Result<String> {
+    fn set_hostname(&self, _hostname: &str, _pretty: Option<&str>) -> Result<()> {
+        Ok(())
+    }
+
+    fn set_ntp(&self, _enabled: bool) -> Result<()> {
+        Ok(())
+    }
+
+    fn create_keyring(&self, _username: &str, _password: &Secret) -> Result<()> {
+        Ok(())
+    }
+
+    fn run_command_as_user(&self, _username: &str, _cmd: &[String], _on_line: &dyn Fn(&str)) -> Result<String> {
         Ok(String::new())
     }
 
-    fn run_command_as_user_with_sudo(&self, _username: &str, _cmd: &[String], _password: &str) -> Result<String> {
+    fn run_command_as_root(&self, _cmd: &[String], _on_line: &dyn Fn(&str)) -> Result<String> {
+        Ok(String::new())
+    }
+
+    fn run_command_as_user_with_sudo(&self, _username: &str, _cmd: &[String], _password: &Secret, _on_line: &dyn Fn(&str)) -> Result<String> {
         Ok(String::new())
     }
 
@@ -185,11 +317,19 @@ impl OnboardService for DryrunService {
     }
 }
 
-/// Create the appropriate service based on dryrun mode
-pub fn create_service(dryrun: bool) -> Arc<dyn OnboardService> {
+/// Create the appropriate service based on dryrun mode and the configured backend
+pub fn create_service(dryrun: bool, backend: SystemBackend) -> Arc<dyn OnboardService> {
     if dryrun {
-        Arc::new(DryrunService)
-    } else {
-        Arc::new(LiveService)
+        return Arc::new(DryrunService);
+    }
+    match backend {
+        SystemBackend::Dbus => match DbusService::system() {
+            Ok(service) => Arc::new(service),
+            Err(e) => {
+                warn!("System bus unavailable ({}), running commands instead", e);
+                Arc::new(LiveService)
+            }
+        },
+        SystemBackend::Commands => Arc::new(LiveService),
     }
 }
@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use tracing::{debug, info, warn};
+
+use super::report::CommandRun;
+use super::steps::{StepId, StepResult};
+
+/// Setup progress persisted so an interrupted run can resume after a crash or reboot
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OnboardState {
+    pub steps: Vec<StepRecord>,
+    /// Account created by the Review step
+    pub created_username: Option<String>,
+    /// Account the Review step was creating, saved before useradd runs
+    pub pending_username: Option<String>,
+    pub locale: Option<String>,
+    pub keymap: Option<String>,
+    pub timezone: Option<String>,
+    pub hostname: Option<String>,
+    pub pretty_hostname: Option<String>,
+    /// Preferences toggles, when the user had the chance to change them
+    pub ntp_sync: Option<bool>,
+    pub create_keyring: Option<bool>,
+    /// Results of finished `[[steps]]` entries, by placeholder name
+    pub step_values: BTreeMap<String, Vec<String>>,
+    /// Titles of the packages selected on the Update step
+    pub packages: Vec<String>,
+    /// Update commands that already succeeded, by command line
+    pub completed_commands: Vec<String>,
+    /// Every Update command run so far, for the install report
+    pub command_runs: Vec<CommandRun>,
+    /// Tasks skipped so far, for the final summary
+    pub skipped_tasks: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StepRecord {
+    pub id: StepId,
+    pub result: StepResult,
+}
+
+impl OnboardState {
+    /// Load state from disk, falling back to empty state on any error
+    pub fn load<P: AsRef<Path>>(path: P) -> Self {
+        let path = path.as_ref();
+
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) => {
+                debug!("No onboard state at {:?}: {}", path, e);
+                return Self::default();
+            }
+        };
+
+        match toml::from_str(&content) {
+            Ok(state) => state,
+            Err(e) => {
+                warn!("Ignoring corrupt onboard state at {:?}: {}", path, e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Write state to disk, replacing the previous file atomically
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        let path = path.as_ref();
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let content = toml::to_string(self).map_err(std::io::Error::other)?;
+        let tmp_path = path.with_extension("tmp");
+        std::fs::write(&tmp_path, content)?;
+        std::fs::rename(&tmp_path, path)?;
+
+        debug!("Saved onboard state to {:?}", path);
+        Ok(())
+    }
+
+    /// Remove the state file once setup has finished or was restarted
+    pub fn clear<P: AsRef<Path>>(path: P) {
+        let path = path.as_ref();
+        match std::fs::remove_file(path) {
+            Ok(()) => info!("Removed onboard state at {:?}", path),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => warn!("Failed to remove onboard state at {:?}: {}", path, e),
+        }
+    }
+
+    /// Whether anything worth resuming was recorded
+    pub fn has_progress(&self) -> bool {
+        self.created_username.is_some()
+            || self.pending_username.is_some()
+            || self
+                .steps
+                .iter()
+                .any(|s| matches!(s.result, StepResult::Completed | StepResult::Skipped))
+    }
+
+    pub fn result_for(&self, id: StepId) -> Option<StepResult> {
+        self.steps.iter().find(|s| s.id == id).map(|s| s.result)
+    }
+}
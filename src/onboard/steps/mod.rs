@@ -1,7 +1,11 @@
+use serde::{Deserialize, Serialize};
+
 /// Unique identifier for each wizard step
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum StepId {
     User,
+    Hostname,
     Locale,
     Keyboard,
     Network,
@@ -9,6 +13,8 @@ pub enum StepId {
     Review,
     Update,
     Reboot,
+    /// A `[[steps]]` entry of the config, by index
+    Custom(usize),
 }
 
 impl StepId {
@@ -16,18 +22,37 @@ impl StepId {
         match self {
             StepId::Network => "Network",
             StepId::User => "User",
+            StepId::Hostname => "Host",
             StepId::Locale => "Locale",
             StepId::Keyboard => "Keyboard",
             StepId::Preferences => "Prefs",
             StepId::Review => "Review",
             StepId::Update => "Update",
             StepId::Reboot => "Reboot",
+            StepId::Custom(_) => "Custom",
+        }
+    }
+
+    /// Catalog message with the step's sidebar name
+    pub fn message_id(&self) -> &'static str {
+        match self {
+            StepId::Network => "step-network",
+            StepId::User => "step-user",
+            StepId::Hostname => "step-hostname",
+            StepId::Locale => "step-locale",
+            StepId::Keyboard => "step-keyboard",
+            StepId::Preferences => "step-preferences",
+            StepId::Review => "step-review",
+            StepId::Update => "step-update",
+            StepId::Reboot => "step-reboot",
+            StepId::Custom(_) => "step-custom",
         }
     }
 }
 
 /// Result of completing a step
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum StepResult {
     #[default]
     Pending,
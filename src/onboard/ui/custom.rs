@@ -0,0 +1,108 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Paragraph, Wrap},
+};
+
+use super::super::config::CustomStepKind;
+use super::super::{ContentFocus, OnboardApp, PanelFocus};
+use super::{enter_button, picker};
+use super::user::draw_input_field;
+
+pub fn draw_custom_step(frame: &mut Frame, area: Rect, app: &OnboardApp, idx: usize) {
+    if area.height < 10 || area.width < 30 {
+        return;
+    }
+    let step = &app.config.steps[idx];
+    let is_content_focused = app.panel_focus == PanelFocus::Content;
+    let mut y = area.y + 1;
+
+    // Title
+    frame.render_widget(
+        Paragraph::new(step.title.as_str())
+            .style(app.theme.primary_style().add_modifier(Modifier::BOLD)),
+        Rect::new(area.x + 2, y, area.width - 4, 1),
+    );
+    y += 2;
+
+    // Description, wrapped, leaving room for the step's content and button
+    if !step.description.is_empty() {
+        let width = area.width.saturating_sub(4).max(1) as usize;
+        let lines: usize = step.description.lines()
+            .map(|line| line.chars().count().div_ceil(width).max(1))
+            .sum();
+        let max_height = match step.kind {
+            CustomStepKind::Info | CustomStepKind::Program { .. } => area.height.saturating_sub(8),
+            _ => area.height / 3,
+        };
+        let height = (lines as u16).min(max_height);
+        frame.render_widget(
+            Paragraph::new(step.description.as_str())
+                .style(app.theme.style())
+                .wrap(Wrap { trim: false }),
+            Rect::new(area.x + 2, y, area.width - 4, height),
+        );
+        y += height + 1;
+    }
+
+    let button_text = match &step.kind {
+        CustomStepKind::Choice { options } => {
+            // The picker brings its own heading and button
+            let default = options.iter().find(|o| o.default).map(|o| o.label()).unwrap_or("");
+            let rest = Rect::new(area.x, y.saturating_sub(1), area.width, (area.y + area.height).saturating_sub(y - 1));
+            picker::draw_picker(frame, rest, app, &app.catalog.tr("picker-choose-one"), default);
+            return;
+        }
+        CustomStepKind::MultiChoice { options } => {
+            for (i, (option, checked)) in options.iter().zip(&app.custom_checked).enumerate() {
+                if y >= area.y + area.height - 5 {
+                    break;
+                }
+                let is_focused = is_content_focused && app.content_focus == ContentFocus::Toggle(i);
+                let checkbox = if *checked { "[x]" } else { "[ ]" };
+                let prefix = if is_focused { ">" } else { " " };
+                let style = if is_focused {
+                    app.theme.primary_style().add_modifier(Modifier::BOLD)
+                } else {
+                    app.theme.style()
+                };
+                frame.render_widget(
+                    Paragraph::new(format!("{prefix} {checkbox} {}", option.label())).style(style),
+                    Rect::new(area.x + 2, y, area.width - 4, 1),
+                );
+                y += 1;
+            }
+            enter_button(&app.catalog.tr("button-save-next"))
+        }
+        CustomStepKind::Form { fields } => {
+            for (i, (field, buffer)) in fields.iter().zip(&app.custom_fields).enumerate() {
+                if y >= area.y + area.height - 5 {
+                    break;
+                }
+                draw_input_field(frame, Rect::new(area.x, y, area.width, 1), app, &field.label, buffer, false, i);
+                y += 2;
+            }
+            enter_button(&app.catalog.tr("button-save-next"))
+        }
+        CustomStepKind::Info => enter_button(&app.catalog.tr("button-continue")),
+        CustomStepKind::Program { program, args } => {
+            let command = std::iter::once(program).chain(args).cloned().collect::<Vec<_>>().join(" ");
+            frame.render_widget(
+                Paragraph::new(app.catalog.tr_args("custom-runs", &[("command", command.into())])).style(app.theme.muted_style()),
+                Rect::new(area.x + 2, y, area.width - 4, 1),
+            );
+            enter_button(&app.catalog.tr_args("button-run", &[("program", program.as_str().into())]))
+        }
+    };
+
+    // Action button
+    let button_y = area.y + area.height - 4;
+    let button_style = if is_content_focused {
+        app.theme.primary_style().add_modifier(Modifier::BOLD | Modifier::REVERSED)
+    } else {
+        app.theme.muted_style().add_modifier(Modifier::REVERSED)
+    };
+    frame.render_widget(
+        Paragraph::new(button_text.as_str()).style(button_style),
+        Rect::new(area.x + 2, button_y, (button_text.chars().count() as u16).min(area.width - 4), 1),
+    );
+}
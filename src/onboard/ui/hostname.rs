@@ -0,0 +1,59 @@
+use ratatui::{prelude::*, widgets::Paragraph};
+
+use super::super::{OnboardApp, PanelFocus};
+use super::enter_button;
+use super::user::draw_input_field;
+
+pub fn draw_hostname_form(frame: &mut Frame, area: Rect, app: &OnboardApp) {
+    if area.height < 10 || area.width < 30 {
+        return;
+    }
+
+    let is_content_focused = app.panel_focus == PanelFocus::Content;
+    let mut y = area.y + 1;
+
+    // Title
+    frame.render_widget(
+        Paragraph::new(app.catalog.tr("hostname-title"))
+            .style(app.theme.primary_style().add_modifier(Modifier::BOLD)),
+        Rect::new(area.x + 2, y, area.width - 4, 1),
+    );
+    y += 2;
+
+    frame.render_widget(
+        Paragraph::new(app.catalog.tr("hostname-help"))
+            .style(app.theme.muted_style()),
+        Rect::new(area.x + 2, y, area.width - 4, 1),
+    );
+    y += 2;
+
+    let label = app.catalog.tr("field-hostname");
+    draw_input_field(frame, Rect::new(area.x, y, area.width, 1), app, &label, &app.hostname, false, 0);
+    y += 2;
+    let label = app.catalog.tr("field-pretty-name");
+    draw_input_field(frame, Rect::new(area.x, y, area.width, 1), app, &label, &app.pretty_hostname, false, 1);
+    y += 1;
+    frame.render_widget(
+        Paragraph::new(app.catalog.tr("hostname-pretty-help")).style(app.theme.muted_style()),
+        Rect::new(area.x + 14, y, area.width.saturating_sub(18), 1),
+    );
+
+    // Action button area
+    let button_y = area.y + area.height - 4;
+    let is_form_ready = !app.hostname.content().is_empty();
+
+    let button_text = enter_button(&app.catalog.tr("button-set-hostname"));
+    let button_width = button_text.chars().count() as u16;
+    let button_x = area.x + 2;
+
+    let button_style = if is_form_ready && is_content_focused {
+        app.theme.primary_style().add_modifier(Modifier::BOLD | Modifier::REVERSED)
+    } else {
+        app.theme.muted_style().add_modifier(Modifier::REVERSED)
+    };
+
+    frame.render_widget(
+        Paragraph::new(button_text).style(button_style),
+        Rect::new(button_x, button_y, button_width, 1),
+    );
+}
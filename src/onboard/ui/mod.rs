@@ -1,7 +1,11 @@
+mod custom;
+mod hostname;
 mod network;
 mod picker;
+mod preferences;
 mod reboot;
 mod review;
+mod tasks;
 mod update;
 mod user;
 mod welcome;
@@ -13,6 +17,7 @@ use ratatui::{
 
 use super::steps::StepId;
 use super::{ConfirmAction, OnboardApp, PanelFocus};
+use crate::ui::wildmenu_line;
 use crate::vim::VimMode;
 
 /// Main draw function for the onboard wizard
@@ -75,9 +80,9 @@ fn draw_header(frame: &mut Frame, area: Rect, app: &OnboardApp) {
 
     // Right side: network status
     let network_status = if app.network_connected {
-        "[Network: OK] "
+        format!("[{}] ", app.catalog.tr("header-network-ok"))
     } else {
-        "[Network: --] "
+        format!("[{}] ", app.catalog.tr("header-network-none"))
     };
     frame.render_widget(
         Paragraph::new(network_status)
@@ -131,7 +136,7 @@ fn draw_sidebar(frame: &mut Frame, area: Rect, app: &OnboardApp) {
         } else {
             app.theme.border_style()
         })
-        .title(" Steps ");
+        .title(format!(" {} ", app.catalog.tr("sidebar-title")));
 
     let inner = block.inner(area);
     frame.render_widget(Clear, area);
@@ -165,7 +170,7 @@ fn draw_sidebar(frame: &mut Frame, area: Rect, app: &OnboardApp) {
         let required = if item.required { " *" } else { "" };
 
         // Step name
-        let name = item.id.short_name();
+        let name = app.step_title(item.id);
 
         // Build the line text with required marker at end
         let line_text = format!(" {status} {name}{required}");
@@ -204,7 +209,7 @@ fn draw_sidebar(frame: &mut Frame, area: Rect, app: &OnboardApp) {
 
     // Hint at bottom
     if is_focused && inner.height > app.menu_items.len() as u16 + 2 {
-        let hint = "j/k:nav l/Enter:edit";
+        let hint = app.catalog.tr("sidebar-hint");
         let hint_y = inner.y + inner.height - 1;
         frame.render_widget(
             Paragraph::new(hint).style(app.theme.muted_style()),
@@ -238,13 +243,15 @@ fn draw_main_content(frame: &mut Frame, area: Rect, app: &mut OnboardApp) {
     if let Some(item) = app.current_item() {
         match item.id {
             StepId::User => user::draw_user_form(frame, inner, app),
-            StepId::Locale => picker::draw_picker(frame, inner, app, "Select Locale", &app.config.locale.default_locale),
-            StepId::Keyboard => picker::draw_picker(frame, inner, app, "Select Keyboard Layout", &app.config.keyboard.default_layout),
-            StepId::Preferences => picker::draw_picker(frame, inner, app, "Select Timezone", &app.config.preferences.default_timezone),
+            StepId::Hostname => hostname::draw_hostname_form(frame, inner, app),
+            StepId::Locale => picker::draw_picker(frame, inner, app, &app.catalog.tr("picker-locale"), &app.config.locale.default_locale),
+            StepId::Keyboard => picker::draw_picker(frame, inner, app, &app.catalog.tr("picker-keyboard"), &app.config.keyboard.default_layout),
+            StepId::Preferences => preferences::draw_preferences(frame, inner, app),
             StepId::Network => network::draw_network_status(frame, inner, app),
             StepId::Review => review::draw_review_step(frame, inner, app),
             StepId::Update => update::draw_update_step(frame, inner, app),
             StepId::Reboot => reboot::draw_reboot_step(frame, inner, app),
+            StepId::Custom(idx) => custom::draw_custom_step(frame, inner, app, idx),
         }
     }
 }
@@ -253,7 +260,7 @@ fn draw_locked_step(frame: &mut Frame, area: Rect, app: &OnboardApp) {
     let mut y = area.y + area.height / 2 - 2;
 
     frame.render_widget(
-        Paragraph::new("Step Locked")
+        Paragraph::new(app.catalog.tr("locked-title"))
             .style(app.theme.muted_style().add_modifier(Modifier::BOLD))
             .alignment(Alignment::Center),
         Rect::new(area.x, y, area.width, 1),
@@ -261,7 +268,7 @@ fn draw_locked_step(frame: &mut Frame, area: Rect, app: &OnboardApp) {
     y += 2;
 
     frame.render_widget(
-        Paragraph::new("Complete the previous steps to unlock this step.")
+        Paragraph::new(app.catalog.tr("locked-text"))
             .style(app.theme.muted_style())
             .alignment(Alignment::Center),
         Rect::new(area.x, y, area.width, 1),
@@ -277,11 +284,11 @@ fn draw_message(frame: &mut Frame, area: Rect, app: &OnboardApp) {
             let block = Block::default()
                 .borders(Borders::ALL)
                 .border_style(app.theme.secondary_style())
-                .title(" Info ")
+                .title(format!(" {} ", app.catalog.tr("message-info")))
                 .title_style(app.theme.secondary_style().add_modifier(Modifier::BOLD));
 
             let content = Line::from(vec![
-                Span::styled("Please wait...", app.theme.style()),
+                Span::styled(app.catalog.tr("please-wait"), app.theme.style()),
             ]);
 
             let paragraph = Paragraph::new(content)
@@ -295,15 +302,15 @@ fn draw_message(frame: &mut Frame, area: Rect, app: &OnboardApp) {
     };
 
     let (title, border_style, text_style) = if msg.is_error {
-        (" Error ", app.theme.error_style(), app.theme.error_style())
+        ("message-error", app.theme.error_style(), app.theme.error_style())
     } else {
-        (" Info ", app.theme.secondary_style(), app.theme.style())
+        ("message-info", app.theme.secondary_style(), app.theme.style())
     };
 
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(border_style)
-        .title(title)
+        .title(format!(" {} ", app.catalog.tr(title)))
         .title_style(border_style.add_modifier(Modifier::BOLD));
 
     let content = Line::from(vec![
@@ -328,7 +335,7 @@ fn draw_status_bar(frame: &mut Frame, area: Rect, app: &OnboardApp) {
     // Command buffer in command mode, otherwise show left hint
     let after_mode = if app.vim_mode == VimMode::Command {
         Span::styled(
-            format!(":{}", app.command_buffer.content()),
+            format!(":{}", app.command_line.content()),
             app.theme.style(),
         )
     } else if !app.status_bar.left_hint.is_empty() {
@@ -356,20 +363,31 @@ fn draw_status_bar(frame: &mut Frame, area: Rect, app: &OnboardApp) {
         format!("{completed}/{total}  {}", app.status_bar.right_hint)
     };
 
-    frame.render_widget(
-        Paragraph::new(right_text)
-            .style(app.theme.muted_style())
-            .alignment(Alignment::Right),
-        Rect::new(area.x + area.width / 3, area.y, area.width * 2 / 3, 1),
-    );
+    let right_area = Rect::new(area.x + area.width / 3, area.y, area.width * 2 / 3, 1);
+    match app.command_line.completion() {
+        Some(completion) if app.vim_mode == VimMode::Command => {
+            let line = wildmenu_line(completion, &app.theme, area.width as usize / 2);
+            frame.render_widget(Paragraph::new(line).alignment(Alignment::Right), right_area);
+        }
+        _ => {
+            frame.render_widget(
+                Paragraph::new(right_text)
+                    .style(app.theme.muted_style())
+                    .alignment(Alignment::Right),
+                right_area,
+            );
+        }
+    }
 }
 
 fn draw_confirm_dialog(frame: &mut Frame, action: ConfirmAction, app: &OnboardApp) {
     let (title, message) = match action {
-        ConfirmAction::Reboot => ("Reboot", "Are you sure you want to reboot?"),
-        ConfirmAction::Poweroff => ("Power Off", "Are you sure you want to power off?"),
-        ConfirmAction::Cancel => ("Cancel Setup", "Are you sure you want to cancel setup?"),
+        ConfirmAction::Reboot => ("confirm-reboot-title", "confirm-reboot"),
+        ConfirmAction::Poweroff => ("confirm-poweroff-title", "confirm-poweroff"),
+        ConfirmAction::Cancel => ("confirm-cancel-title", "confirm-cancel"),
+        ConfirmAction::Resume => ("confirm-resume-title", "confirm-resume"),
     };
+    let (title, message) = (app.catalog.tr(title), app.catalog.tr(message));
 
     let width = 44.min(frame.area().width - 4);
     let height = 7;
@@ -391,13 +409,10 @@ fn draw_confirm_dialog(frame: &mut Frame, action: ConfirmAction, app: &OnboardAp
         Rect::new(inner.x, inner.y + 1, inner.width, 1),
     );
 
-    let hints = Line::from(vec![
-        Span::styled("[", app.theme.style()),
-        Span::styled("Y", app.theme.primary_style().add_modifier(Modifier::BOLD)),
-        Span::styled("]es / [", app.theme.style()),
-        Span::styled("N", app.theme.primary_style().add_modifier(Modifier::BOLD)),
-        Span::styled("]o", app.theme.style()),
-    ]);
+    let mut hints = answer_hint('Y', &app.catalog.tr("confirm-yes"), app);
+    hints.push(Span::styled(" / ", app.theme.style()));
+    hints.extend(answer_hint('N', &app.catalog.tr("confirm-no"), app));
+    let hints = Line::from(hints);
 
     frame.render_widget(
         Paragraph::new(hints).alignment(Alignment::Center),
@@ -405,37 +420,53 @@ fn draw_confirm_dialog(frame: &mut Frame, action: ConfirmAction, app: &OnboardAp
     );
 }
 
+/// `[Y]es` when the answer starts with its key, `[J] Ja` style otherwise
+fn answer_hint<'a>(key: char, answer: &str, app: &OnboardApp) -> Vec<Span<'a>> {
+    let key_span = Span::styled(key.to_string(), app.theme.primary_style().add_modifier(Modifier::BOLD));
+    let rest = match answer.strip_prefix(|c: char| c.eq_ignore_ascii_case(&key)) {
+        Some(rest) => format!("]{rest}"),
+        None => format!("] {answer}"),
+    };
+    vec![
+        Span::styled("[", app.theme.style()),
+        key_span,
+        Span::styled(rest, app.theme.style()),
+    ]
+}
+
 fn draw_help(frame: &mut Frame, app: &OnboardApp) {
     let width = 60.min(frame.area().width - 4);
     let height = 20.min(frame.area().height - 4);
     let area = center_rect(frame.area(), width, height);
 
+    let catalog = &app.catalog;
+    let key = |keys: &str, id: &str| format!("  {keys:<15}{}", catalog.tr(id));
     let help_text = [
-        "",
-        "Navigation:",
-        "",
-        "  Ctrl+h         Focus sidebar",
-        "  Ctrl+l         Focus content",
-        "  j/k            Navigate up/down",
-        "  h/l            Collapse/Expand",
-        "  Enter          Select / Edit",
-        "  1-9            Quick select step",
-        "",
-        "Vim Modes:",
-        "",
-        "  i              Enter insert mode",
-        "  Esc            Return to normal",
-        "  :              Command mode",
-        "",
-        "Commands: :skip :next :finish :help",
-        "",
-        "Press q or Esc to close",
+        String::new(),
+        catalog.tr("help-navigation"),
+        String::new(),
+        key("Ctrl+h", "help-focus-sidebar"),
+        key("Ctrl+l", "help-focus-content"),
+        key("j/k", "help-up-down"),
+        key("h/l", "help-collapse"),
+        key("Enter", "help-select"),
+        key("1-9", "help-quick-select"),
+        String::new(),
+        catalog.tr("help-vim-modes"),
+        String::new(),
+        key("i/a/I/A", "help-insert"),
+        key("Esc", "help-normal"),
+        key(":", "help-command"),
+        String::new(),
+        format!("{} :skip :next :goto :finish :help", catalog.tr("help-commands")),
+        String::new(),
+        catalog.tr("help-close"),
     ];
 
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(app.theme.border_style())
-        .title(" Help ");
+        .title(format!(" {} ", catalog.tr("help-title")));
 
     let inner = block.inner(area);
     frame.render_widget(Clear, area);
@@ -446,12 +477,17 @@ fn draw_help(frame: &mut Frame, app: &OnboardApp) {
             break;
         }
         frame.render_widget(
-            Paragraph::new(*line).style(app.theme.style()),
+            Paragraph::new(line.as_str()).style(app.theme.style()),
             Rect::new(inner.x, inner.y + i as u16, inner.width, 1),
         );
     }
 }
 
+/// Text of a button pressed with Enter, e.g. ` [Enter] Next `
+pub(super) fn enter_button(label: &str) -> String {
+    format!(" [Enter] {label} ")
+}
+
 pub(crate) fn center_rect(area: Rect, width: u16, height: u16) -> Rect {
     let x = if area.width > width {
         area.x + (area.width - width) / 2
@@ -1,6 +1,7 @@
 use ratatui::{prelude::*, widgets::Paragraph};
 
 use super::super::{OnboardApp, PanelFocus};
+use super::enter_button;
 
 pub fn draw_network_status(frame: &mut Frame, area: Rect, app: &OnboardApp) {
     if area.height < 5 {
@@ -11,7 +12,7 @@ pub fn draw_network_status(frame: &mut Frame, area: Rect, app: &OnboardApp) {
 
     // Title
     frame.render_widget(
-        Paragraph::new("Network Configuration")
+        Paragraph::new(app.catalog.tr("network-title"))
             .style(app.theme.primary_style().add_modifier(Modifier::BOLD)),
         Rect::new(area.x + 2, y, area.width - 4, 1),
     );
@@ -19,9 +20,9 @@ pub fn draw_network_status(frame: &mut Frame, area: Rect, app: &OnboardApp) {
 
     // Status
     let (status_text, status_style) = if app.network_connected {
-        ("Status: Connected", app.theme.secondary_style())
+        (app.catalog.tr("network-status-connected"), app.theme.secondary_style())
     } else {
-        ("Status: Not connected", app.theme.error_style())
+        (app.catalog.tr("network-status-disconnected"), app.theme.error_style())
     };
 
     frame.render_widget(
@@ -34,15 +35,15 @@ pub fn draw_network_status(frame: &mut Frame, area: Rect, app: &OnboardApp) {
 
     if app.network_connected {
         frame.render_widget(
-            Paragraph::new("Network is already connected. This step is complete.")
+            Paragraph::new(app.catalog.tr("network-done"))
                 .style(app.theme.style()),
             Rect::new(area.x + 2, y, area.width - 4, 1),
         );
 
         // Show "Next" button when connected
         let button_y = area.y + area.height - 4;
-        let button_text = " [Enter] Next ";
-        let button_width = button_text.len() as u16;
+        let button_text = enter_button(&app.catalog.tr("button-next"));
+        let button_width = button_text.chars().count() as u16;
 
         let button_style = if is_content_focused {
             app.theme.primary_style().add_modifier(Modifier::BOLD | Modifier::REVERSED)
@@ -56,22 +57,22 @@ pub fn draw_network_status(frame: &mut Frame, area: Rect, app: &OnboardApp) {
         );
     } else {
         frame.render_widget(
-            Paragraph::new(format!("Press Enter to launch {} for WiFi setup", app.config.network.program))
+            Paragraph::new(app.catalog.tr_args("network-launch", &[("program", app.config.network.program.as_str().into())]))
                 .style(app.theme.style()),
             Rect::new(area.x + 2, y, area.width - 4, 1),
         );
         y += 1;
 
         frame.render_widget(
-            Paragraph::new("Or use :skip to continue without network")
+            Paragraph::new(app.catalog.tr("network-skip-hint"))
                 .style(app.theme.muted_style()),
             Rect::new(area.x + 2, y, area.width - 4, 1),
         );
 
         // Show "Configure WiFi" button
         let button_y = area.y + area.height - 4;
-        let button_text = " [Enter] Configure WiFi ";
-        let button_width = button_text.len() as u16;
+        let button_text = enter_button(&app.catalog.tr("button-configure-wifi"));
+        let button_width = button_text.chars().count() as u16;
 
         let button_style = if is_content_focused {
             app.theme.primary_style().add_modifier(Modifier::BOLD | Modifier::REVERSED)
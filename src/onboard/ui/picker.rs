@@ -4,6 +4,7 @@ use ratatui::{
 };
 
 use super::super::{ContentFocus, OnboardApp, PanelFocus};
+use super::enter_button;
 use crate::vim::VimMode;
 
 pub fn draw_picker(frame: &mut Frame, area: Rect, app: &OnboardApp, title: &str, default: &str) {
@@ -26,14 +27,15 @@ pub fn draw_picker(frame: &mut Frame, area: Rect, app: &OnboardApp, title: &str,
     y += 2;
 
     // Filter input
-    let filter_label = "Filter: ";
+    let filter_label = format!("{} ", app.catalog.tr("picker-filter"));
+    let label_width = filter_label.chars().count() as u16;
     frame.render_widget(
         Paragraph::new(filter_label).style(app.theme.style()),
-        Rect::new(area.x + 2, y, filter_label.len() as u16, 1),
+        Rect::new(area.x + 2, y, label_width, 1),
     );
 
-    let filter_x = area.x + 2 + filter_label.len() as u16;
-    let filter_width = area.width.saturating_sub(filter_label.len() as u16 + 4);
+    let filter_x = area.x + 2 + label_width;
+    let filter_width = area.width.saturating_sub(label_width + 4);
     let filter_content = app.picker_filter.content();
 
     if is_insert {
@@ -50,7 +52,7 @@ pub fn draw_picker(frame: &mut Frame, area: Rect, app: &OnboardApp, title: &str,
         frame.render_widget(Paragraph::new(line), Rect::new(filter_x, y, filter_width, 1));
     } else {
         let display = if filter_content.is_empty() {
-            format!("(default: {default})")
+            app.catalog.tr_args("picker-default", &[("default", default.into())])
         } else {
             filter_content.to_string()
         };
@@ -126,8 +128,8 @@ pub fn draw_picker(frame: &mut Frame, area: Rect, app: &OnboardApp, title: &str,
     let has_selection = !filtered.is_empty();
 
     if has_selection {
-        let button_text = " [Enter] Save & Next ";
-        let button_width = button_text.len() as u16;
+        let button_text = enter_button(&app.catalog.tr("button-save-next"));
+        let button_width = button_text.chars().count() as u16;
         let button_x = area.x + 2;
 
         let button_style = if is_picker_focused {
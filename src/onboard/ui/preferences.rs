@@ -0,0 +1,65 @@
+use ratatui::{prelude::*, widgets::Paragraph};
+
+use super::super::{ContentFocus, OnboardApp, PanelFocus};
+use super::{enter_button, picker};
+
+pub fn draw_preferences(frame: &mut Frame, area: Rect, app: &OnboardApp) {
+    let toggles = app.preference_toggles();
+    let has_picker = app.current_item().is_some_and(|item| item.has_picker);
+    let picker_title = app.catalog.tr("picker-timezone");
+    if toggles.is_empty() {
+        picker::draw_picker(frame, area, app, &picker_title, &app.config.preferences.default_timezone);
+        return;
+    }
+    if area.height < 10 || area.width < 30 {
+        return;
+    }
+
+    let is_content_focused = app.panel_focus == PanelFocus::Content;
+    let mut y = area.y + 1;
+
+    // Title
+    frame.render_widget(
+        Paragraph::new(app.catalog.tr("preferences-title"))
+            .style(app.theme.primary_style().add_modifier(Modifier::BOLD)),
+        Rect::new(area.x + 2, y, area.width - 4, 1),
+    );
+    y += 2;
+
+    for (idx, toggle) in toggles.iter().enumerate() {
+        let is_focused = is_content_focused && app.content_focus == ContentFocus::Toggle(idx);
+        let checkbox = if app.preference_value(*toggle) { "[x]" } else { "[ ]" };
+        let prefix = if is_focused { ">" } else { " " };
+
+        let style = if is_focused {
+            app.theme.primary_style().add_modifier(Modifier::BOLD)
+        } else {
+            app.theme.style()
+        };
+        frame.render_widget(
+            Paragraph::new(format!("{prefix} {checkbox} {}", app.catalog.tr(toggle.message_id()))).style(style),
+            Rect::new(area.x + 2, y, area.width - 4, 1),
+        );
+        y += 1;
+    }
+
+    // The timezone list takes the rest, with its own title and button
+    if has_picker {
+        let rest = Rect::new(area.x, y, area.width, (area.y + area.height).saturating_sub(y));
+        picker::draw_picker(frame, rest, app, &picker_title, &app.config.preferences.default_timezone);
+        return;
+    }
+
+    let button_y = area.y + area.height - 4;
+    let button_text = enter_button(&app.catalog.tr("button-save-next"));
+    let button_width = button_text.chars().count() as u16;
+    let button_style = if is_content_focused {
+        app.theme.primary_style().add_modifier(Modifier::BOLD | Modifier::REVERSED)
+    } else {
+        app.theme.muted_style().add_modifier(Modifier::REVERSED)
+    };
+    frame.render_widget(
+        Paragraph::new(button_text).style(button_style),
+        Rect::new(area.x + 2, button_y, button_width, 1),
+    );
+}
@@ -1,6 +1,7 @@
 use ratatui::{prelude::*, widgets::Paragraph};
 
 use super::super::{OnboardApp, PanelFocus};
+use super::enter_button;
 
 pub fn draw_reboot_step(frame: &mut Frame, area: Rect, app: &OnboardApp) {
     if area.height < 8 {
@@ -12,14 +13,14 @@ pub fn draw_reboot_step(frame: &mut Frame, area: Rect, app: &OnboardApp) {
 
     // Title
     frame.render_widget(
-        Paragraph::new("Setup Complete!")
+        Paragraph::new(app.catalog.tr("reboot-title"))
             .style(app.theme.primary_style().add_modifier(Modifier::BOLD)),
         Rect::new(area.x + 2, y, area.width - 4, 1),
     );
     y += 2;
 
     frame.render_widget(
-        Paragraph::new("Your system is configured and ready.")
+        Paragraph::new(app.catalog.tr("reboot-ready"))
             .style(app.theme.style()),
         Rect::new(area.x + 2, y, area.width - 4, 1),
     );
@@ -28,31 +29,51 @@ pub fn draw_reboot_step(frame: &mut Frame, area: Rect, app: &OnboardApp) {
     // Show created user
     if let Some(ref username) = app.created_username {
         frame.render_widget(
-            Paragraph::new(format!("User '{}' has been created.", username))
+            Paragraph::new(app.catalog.tr_args("reboot-user-created", &[("username", username.as_str().into())]))
                 .style(app.theme.secondary_style()),
             Rect::new(area.x + 2, y, area.width - 4, 1),
         );
         y += 1;
     }
 
+    // Tasks left undone, so they can be finished by hand
+    if !app.skipped_tasks.is_empty() {
+        y += 1;
+        frame.render_widget(
+            Paragraph::new(app.catalog.tr("reboot-skipped")).style(app.theme.error_style()),
+            Rect::new(area.x + 2, y, area.width - 4, 1),
+        );
+        y += 1;
+        for task in &app.skipped_tasks {
+            if y + 7 >= area.y + area.height {
+                break;
+            }
+            frame.render_widget(
+                Paragraph::new(format!("  - {task}")).style(app.theme.muted_style()),
+                Rect::new(area.x + 2, y, area.width - 4, 1),
+            );
+            y += 1;
+        }
+    }
+
     y += 1;
     frame.render_widget(
-        Paragraph::new("A reboot is required to apply all changes.")
+        Paragraph::new(app.catalog.tr("reboot-required"))
             .style(app.theme.style()),
         Rect::new(area.x + 2, y, area.width - 4, 1),
     );
     y += 1;
 
     frame.render_widget(
-        Paragraph::new("After rebooting, you will see the login screen.")
+        Paragraph::new(app.catalog.tr("reboot-after"))
             .style(app.theme.muted_style()),
         Rect::new(area.x + 2, y, area.width - 4, 1),
     );
 
     // Action button
     let button_y = area.y + area.height - 4;
-    let button_text = " [Enter] Reboot Now ";
-    let button_width = button_text.len() as u16;
+    let button_text = enter_button(&app.catalog.tr("button-reboot"));
+    let button_width = button_text.chars().count() as u16;
     let button_x = area.x + 2;
 
     let button_style = if is_content_focused {
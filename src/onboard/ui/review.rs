@@ -1,8 +1,9 @@
 use ratatui::{prelude::*, widgets::Paragraph};
 
-use super::super::{OnboardApp, PanelFocus, TaskState};
+use super::super::{OnboardApp, PanelFocus};
+use super::{enter_button, tasks};
 
-pub fn draw_review_step(frame: &mut Frame, area: Rect, app: &OnboardApp) {
+pub fn draw_review_step(frame: &mut Frame, area: Rect, app: &mut OnboardApp) {
     if area.height < 10 {
         return;
     }
@@ -12,105 +13,118 @@ pub fn draw_review_step(frame: &mut Frame, area: Rect, app: &OnboardApp) {
 
     // Title
     frame.render_widget(
-        Paragraph::new("Review & Apply Configuration")
+        Paragraph::new(app.catalog.tr("review-title"))
             .style(app.theme.primary_style().add_modifier(Modifier::BOLD)),
         Rect::new(area.x + 2, y, area.width - 4, 1),
     );
     y += 2;
 
-    // Show summary of selections
+    // Show summary of selections, one "  Label: value" row each
+    let row = |label: &str, value: &str| format!("  {}: {value}", app.catalog.tr(label));
+    let system_default = app.catalog.tr("review-system-default");
     frame.render_widget(
-        Paragraph::new("Configuration Summary:").style(app.theme.style()),
+        Paragraph::new(app.catalog.tr("review-summary")).style(app.theme.style()),
         Rect::new(area.x + 2, y, area.width - 4, 1),
     );
     y += 2;
 
     // Username
     let username = app.username.content();
-    let username_display = if username.is_empty() { "(not set)" } else { username };
+    let username_display = if username.is_empty() { app.catalog.tr("review-not-set") } else { username.to_string() };
     frame.render_widget(
-        Paragraph::new(format!("  User: {}", username_display))
+        Paragraph::new(row("review-user", &username_display))
             .style(if username.is_empty() { app.theme.error_style() } else { app.theme.secondary_style() }),
         Rect::new(area.x + 2, y, area.width - 4, 1),
     );
     y += 1;
 
+    // Hostname
+    let hostname_display = match (&app.selected_hostname, &app.selected_pretty_hostname) {
+        (Some(hostname), Some(pretty)) => format!("{hostname} ({pretty})"),
+        (Some(hostname), None) => hostname.clone(),
+        (None, _) => system_default.clone(),
+    };
+    frame.render_widget(
+        Paragraph::new(row("review-hostname", &hostname_display)).style(app.theme.style()),
+        Rect::new(area.x + 2, y, area.width - 4, 1),
+    );
+    y += 1;
+
     // Locale
-    let locale_display = app.selected_locale.as_deref().unwrap_or("(system default)");
+    let locale_display = app.selected_locale.as_deref().unwrap_or(&system_default);
     frame.render_widget(
-        Paragraph::new(format!("  Locale: {}", locale_display)).style(app.theme.style()),
+        Paragraph::new(row("review-locale", locale_display)).style(app.theme.style()),
         Rect::new(area.x + 2, y, area.width - 4, 1),
     );
     y += 1;
 
     // Keyboard
-    let keyboard_display = app.selected_keyboard.as_deref().unwrap_or("(system default)");
+    let keyboard_display = app.selected_keyboard.as_deref().unwrap_or(&system_default);
     frame.render_widget(
-        Paragraph::new(format!("  Keyboard: {}", keyboard_display)).style(app.theme.style()),
+        Paragraph::new(row("review-keyboard", keyboard_display)).style(app.theme.style()),
         Rect::new(area.x + 2, y, area.width - 4, 1),
     );
     y += 1;
 
     // Timezone
-    let timezone_display = app.selected_timezone.as_deref().unwrap_or("(system default)");
+    let timezone_display = app.selected_timezone.as_deref().unwrap_or(&system_default);
     frame.render_widget(
-        Paragraph::new(format!("  Timezone: {}", timezone_display)).style(app.theme.style()),
+        Paragraph::new(row("review-timezone", timezone_display)).style(app.theme.style()),
         Rect::new(area.x + 2, y, area.width - 4, 1),
     );
     y += 1;
 
+    // Clock sync and keyring, when the config offers them
+    if app.config.preferences.ntp_enabled {
+        let ntp_display = app.catalog.tr(if app.ntp_sync { "review-on" } else { "review-off" });
+        frame.render_widget(
+            Paragraph::new(row("review-ntp", &ntp_display)).style(app.theme.style()),
+            Rect::new(area.x + 2, y, area.width - 4, 1),
+        );
+        y += 1;
+    }
+    if app.config.preferences.keyring_enabled {
+        let keyring_display = match (app.create_keyring, app.password.is_empty()) {
+            (false, _) => "review-keyring-none",
+            (true, false) => "review-keyring-password",
+            (true, true) => "review-keyring-first-login",
+        };
+        frame.render_widget(
+            Paragraph::new(row("review-keyring", &app.catalog.tr(keyring_display))).style(app.theme.style()),
+            Rect::new(area.x + 2, y, area.width - 4, 1),
+        );
+        y += 1;
+    }
+
     // Network
-    let network_display = if app.network_connected { "Connected" } else { "Not connected" };
+    let network_display = app.catalog.tr(if app.network_connected { "review-connected" } else { "review-disconnected" });
     frame.render_widget(
-        Paragraph::new(format!("  Network: {}", network_display))
+        Paragraph::new(row("review-network", &network_display))
             .style(if app.network_connected { app.theme.secondary_style() } else { app.theme.muted_style() }),
         Rect::new(area.x + 2, y, area.width - 4, 1),
     );
     y += 2;
 
+    // `o` gives the selected task's output the whole step
+    if app.log_expanded && app.shows_task_log() {
+        let pane_height = (area.y + area.height).saturating_sub(y + 3);
+        tasks::draw_log_pane(frame, Rect::new(area.x + 2, y, area.width - 4, pane_height), app);
+        return;
+    }
+
     // Show tasks if executing
-    if !app.tasks.is_empty() {
+    if app.shows_task_log() {
         frame.render_widget(
-            Paragraph::new("Applying configuration:").style(app.theme.style()),
+            Paragraph::new(app.catalog.tr("review-applying")).style(app.theme.style()),
             Rect::new(area.x + 2, y, area.width - 4, 1),
         );
         y += 1;
 
-        for task in &app.tasks {
-            if y >= area.y + area.height - 4 {
-                break;
-            }
-
-            let (status_char, style) = match task.status {
-                TaskState::Pending => (' ', app.theme.muted_style()),
-                TaskState::Running => (app.spinner_char(), app.theme.primary_style()),
-                TaskState::Success => ('x', app.theme.secondary_style()),
-                TaskState::Failed => ('!', app.theme.error_style()),
-            };
-
-            // Show progress bar if available
-            if let Some(progress) = task.progress {
-                let bar_width = 20;
-                let filled = (progress as usize * bar_width / 100).min(bar_width);
-                let empty = bar_width - filled;
-                let bar = format!("[{}{}]", "=".repeat(filled), " ".repeat(empty));
-                let line = format!("  [{status_char}] {} {bar} {progress}%", task.name);
-                frame.render_widget(
-                    Paragraph::new(line).style(style),
-                    Rect::new(area.x + 2, y, area.width - 4, 1),
-                );
-            } else {
-                let line = format!("  [{status_char}] {}", task.name);
-                frame.render_widget(
-                    Paragraph::new(line).style(style),
-                    Rect::new(area.x + 2, y, area.width - 4, 1),
-                );
-            }
-            y += 1;
-        }
+        let list_end = (area.y + area.height).saturating_sub(4).max(y);
+        tasks::draw_task_list(frame, Rect::new(area.x + 2, y, area.width - 4, list_end - y), app);
     } else {
         frame.render_widget(
-            Paragraph::new("Press Enter to apply configuration and create user.")
+            Paragraph::new(app.catalog.tr("review-press-enter"))
                 .style(app.theme.style()),
             Rect::new(area.x + 2, y, area.width - 4, 1),
         );
@@ -124,8 +138,8 @@ pub fn draw_review_step(frame: &mut Frame, area: Rect, app: &OnboardApp) {
     // Action button
     if !app.is_executing {
         let button_y = area.y + area.height - 4;
-        let button_text = " [Enter] Apply & Create User ";
-        let button_width = button_text.len() as u16;
+        let button_text = enter_button(&app.catalog.tr("button-apply"));
+        let button_width = button_text.chars().count() as u16;
         let button_x = area.x + 2;
 
         let button_style = if is_valid && is_content_focused {
@@ -0,0 +1,112 @@
+//! Task list and output pane shared by the Review and Update steps
+
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph},
+};
+
+use super::super::{OnboardApp, PanelFocus, TaskState};
+
+/// One line per task with its status, progress and latest output.
+/// Returns the row below the last task drawn.
+pub(super) fn draw_task_list(frame: &mut Frame, area: Rect, app: &OnboardApp) -> u16 {
+    let mut y = area.y;
+    let width = area.width as usize;
+
+    for (idx, task) in app.tasks.iter().enumerate() {
+        if y >= area.y + area.height {
+            break;
+        }
+
+        let (status_char, style) = match task.status {
+            TaskState::Pending => (' ', app.theme.muted_style()),
+            TaskState::Running => (app.spinner_char(), app.theme.primary_style()),
+            TaskState::Success => ('x', app.theme.secondary_style()),
+            TaskState::Failed => ('!', app.theme.error_style()),
+            TaskState::Skipped => ('-', app.theme.muted_style()),
+        };
+        let cursor = if idx == app.log_task { '>' } else { ' ' };
+
+        // Show progress bar if available
+        let line = if let Some(progress) = task.progress {
+            let bar_width = 20;
+            let filled = (progress as usize * bar_width / 100).min(bar_width);
+            let empty = bar_width - filled;
+            let bar = format!("[{}{}]", "=".repeat(filled), " ".repeat(empty));
+            let count = task.count.map(|(n, m)| format!(" ({n}/{m})")).unwrap_or_default();
+            format!("{cursor} [{status_char}] {} {bar} {progress}%{count}", task.name)
+        } else {
+            format!("{cursor} [{status_char}] {}", task.name)
+        };
+
+        let mut spans = vec![Span::styled(line.clone(), style)];
+        let room = width.saturating_sub(line.chars().count() + 2);
+        if let Some(last) = task.last_line()
+            && room > 0
+        {
+            spans.push(Span::styled(
+                format!("  {}", truncate(last, room)),
+                app.theme.muted_style(),
+            ));
+        }
+        frame.render_widget(
+            Paragraph::new(Line::from(spans)),
+            Rect::new(area.x, y, area.width, 1),
+        );
+        y += 1;
+    }
+    y
+}
+
+/// Scrollable output of the task selected with J/K
+pub(super) fn draw_log_pane(frame: &mut Frame, area: Rect, app: &mut OnboardApp) {
+    if area.height < 3 {
+        return;
+    }
+
+    let Some(task) = app.tasks.get(app.log_task) else {
+        return;
+    };
+    let is_focused = app.panel_focus == PanelFocus::Content;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(if is_focused {
+            app.theme.primary_style()
+        } else {
+            app.theme.border_style()
+        })
+        .title(format!(" {} ", app.catalog.tr_args("task-output", &[("task", task.name.as_str().into())])));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    app.log_height = inner.height as usize;
+    let Some(task) = app.tasks.get(app.log_task) else {
+        return;
+    };
+    if task.log.is_empty() {
+        frame.render_widget(
+            Paragraph::new(app.catalog.tr("task-no-output")).style(app.theme.muted_style()),
+            inner,
+        );
+        return;
+    }
+
+    let top = app.log_top();
+    let width = inner.width as usize;
+    let lines: Vec<Line> = task.log[top..]
+        .iter()
+        .take(inner.height as usize)
+        .map(|l| Line::styled(truncate(l, width), app.theme.style()))
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// Cut `text` to `max` characters, marking the cut with an ellipsis
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut cut: String = text.chars().take(max.saturating_sub(1)).collect();
+    cut.push('\u{2026}');
+    cut
+}
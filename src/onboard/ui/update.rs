@@ -1,8 +1,12 @@
 use ratatui::{prelude::*, widgets::Paragraph};
 
-use super::super::{ContentFocus, OnboardApp, PanelFocus, TaskState};
+use super::super::{ContentFocus, OnboardApp, PanelFocus};
+use super::{enter_button, tasks};
 use crate::vim::VimMode;
 
+/// Smallest output pane worth drawing, borders included
+const LOG_PANE_MIN_HEIGHT: u16 = 5;
+
 pub fn draw_update_step(frame: &mut Frame, area: Rect, app: &mut OnboardApp) {
     if area.height < 8 {
         return;
@@ -15,56 +19,37 @@ pub fn draw_update_step(frame: &mut Frame, area: Rect, app: &mut OnboardApp) {
 
     // Title
     frame.render_widget(
-        Paragraph::new("Install Packages")
+        Paragraph::new(app.catalog.tr("update-title"))
             .style(app.theme.primary_style().add_modifier(Modifier::BOLD)),
         Rect::new(area.x + 2, y, area.width - 4, 1),
     );
     y += 2;
 
-    // Show running tasks if executing
-    if !app.tasks.is_empty() {
+    // `o` gives the selected task's output the whole step
+    if app.log_expanded && app.shows_task_log() {
+        let pane_height = (area.y + area.height).saturating_sub(y);
+        tasks::draw_log_pane(frame, Rect::new(area.x + 2, y, area.width - 4, pane_height), app);
+        return;
+    }
+
+    // Show running tasks with their latest output, then the selected task's log
+    if app.shows_task_log() {
         frame.render_widget(
-            Paragraph::new("Installing:").style(app.theme.style()),
+            Paragraph::new(app.catalog.tr("update-installing")).style(app.theme.style()),
             Rect::new(area.x + 2, y, area.width - 4, 1),
         );
         y += 1;
 
-        for task in &app.tasks {
-            if y >= area.y + area.height - 2 {
-                break;
-            }
+        // Keep at least a few lines for the output pane below the list
+        let list_end = (area.y + area.height).saturating_sub(LOG_PANE_MIN_HEIGHT + 1).max(y);
+        y = tasks::draw_task_list(frame, Rect::new(area.x + 2, y, area.width - 4, list_end - y), app);
 
-            let (status_char, style) = match task.status {
-                TaskState::Pending => (' ', app.theme.muted_style()),
-                TaskState::Running => (app.spinner_char(), app.theme.primary_style()),
-                TaskState::Success => ('x', app.theme.secondary_style()),
-                TaskState::Failed => ('!', app.theme.error_style()),
-            };
-
-            // Show progress bar if available
-            if let Some(progress) = task.progress {
-                let bar_width = 20;
-                let filled = (progress as usize * bar_width / 100).min(bar_width);
-                let empty = bar_width - filled;
-                let bar = format!("[{}{}]", "=".repeat(filled), " ".repeat(empty));
-                let line = format!("  [{status_char}] {} {bar} {progress}%", task.name);
-                frame.render_widget(
-                    Paragraph::new(line).style(style),
-                    Rect::new(area.x + 2, y, area.width - 4, 1),
-                );
-            } else {
-                let line = format!("  [{status_char}] {}", task.name);
-                frame.render_widget(
-                    Paragraph::new(line).style(style),
-                    Rect::new(area.x + 2, y, area.width - 4, 1),
-                );
-            }
-            y += 1;
-        }
+        let pane_height = (area.y + area.height).saturating_sub(y + 1);
+        tasks::draw_log_pane(frame, Rect::new(area.x + 2, y + 1, area.width - 4, pane_height), app);
     } else {
         // Show package selection list
         frame.render_widget(
-            Paragraph::new("Select packages to install:")
+            Paragraph::new(app.catalog.tr("update-select"))
                 .style(app.theme.style()),
             Rect::new(area.x + 2, y, area.width - 4, 1),
         );
@@ -73,15 +58,9 @@ pub fn draw_update_step(frame: &mut Frame, area: Rect, app: &mut OnboardApp) {
         let max_y = area.y + area.height - 4;
         let num_categories = app.config.updates.len();
 
-        if num_categories > 0 {
-            // Divide available height equally among categories
-            let available_height = max_y.saturating_sub(y) as usize;
-            let per_category_height = if num_categories > 0 {
-                available_height / num_categories
-            } else {
-                0
-            };
-
+        // Divide available height equally among categories
+        let available_height = max_y.saturating_sub(y) as usize;
+        if let Some(per_category_height) = available_height.checked_div(num_categories) {
             for (cat_idx, category) in app.config.updates.iter().enumerate() {
                 let section_start = y;
                 let section_end = if cat_idx == num_categories - 1 {
@@ -162,7 +141,7 @@ pub fn draw_update_step(frame: &mut Frame, area: Rect, app: &mut OnboardApp) {
                 // Show scroll-up indicator
                 if scroll > 0 && y < pkg_area_end {
                     frame.render_widget(
-                        Paragraph::new("       \u{2191} more above")
+                        Paragraph::new(format!("       {}", app.catalog.tr("update-more-above")))
                             .style(app.theme.muted_style()),
                         Rect::new(area.x + 2, y, area.width - 4, 1),
                     );
@@ -234,7 +213,10 @@ pub fn draw_update_step(frame: &mut Frame, area: Rect, app: &mut OnboardApp) {
                 // Show scroll-down indicator
                 if scroll + rendered < total_packages && y < pkg_area_end {
                     frame.render_widget(
-                        Paragraph::new(format!("       \u{2193} {} more below", total_packages - scroll - rendered))
+                        Paragraph::new(format!(
+                            "       {}",
+                            app.catalog.tr_args("update-more-below", &[("count", (total_packages - scroll - rendered).into())])
+                        ))
                             .style(app.theme.muted_style()),
                         Rect::new(area.x + 2, y, area.width - 4, 1),
                     );
@@ -246,80 +228,79 @@ pub fn draw_update_step(frame: &mut Frame, area: Rect, app: &mut OnboardApp) {
         }
 
         // Show sudo password input if needed
-        if app.sudo_password_needed && !app.sudo_password_entered && !app.is_dryrun() {
-            if y < max_y {
-                y += 1;
-                frame.render_widget(
-                    Paragraph::new("Password required for sudo commands:")
-                        .style(app.theme.primary_style()),
-                    Rect::new(area.x + 2, y, area.width - 4, 1),
-                );
-                y += 1;
-
-                let label = "Password: ";
-                frame.render_widget(
-                    Paragraph::new(label).style(app.theme.style()),
-                    Rect::new(area.x + 2, y, label.len() as u16, 1),
-                );
+        if app.sudo_password_needed && !app.sudo_password_entered && !app.is_dryrun() && y < max_y {
+            y += 1;
+            frame.render_widget(
+                Paragraph::new(app.catalog.tr("update-sudo"))
+                    .style(app.theme.primary_style()),
+                Rect::new(area.x + 2, y, area.width - 4, 1),
+            );
+            y += 1;
 
-                let field_x = area.x + 2 + label.len() as u16;
-                let field_width = area.width.saturating_sub(label.len() as u16 + 4);
-                let password_display = "*".repeat(app.sudo_password.content().len());
-
-                if is_insert {
-                    let cursor_pos = app.sudo_password.cursor().min(password_display.len());
-                    let before: String = password_display.chars().take(cursor_pos).collect();
-                    let after: String = password_display.chars().skip(cursor_pos).collect();
-
-                    let line = Line::from(vec![
-                        Span::styled(before, app.theme.style()),
-                        Span::styled("|", app.theme.primary_style().add_modifier(Modifier::BOLD)),
-                        Span::styled(after, app.theme.style()),
-                    ]);
-                    frame.render_widget(Paragraph::new(line), Rect::new(field_x, y, field_width, 1));
-                } else if is_password_focused {
-                    let chars: Vec<char> = password_display.chars().collect();
-                    let cursor_pos = app.sudo_password.cursor().min(chars.len());
-                    let mut spans = Vec::new();
-
-                    for (i, ch) in chars.iter().enumerate() {
-                        if i == cursor_pos {
-                            spans.push(Span::styled(ch.to_string(), app.theme.style().add_modifier(Modifier::REVERSED)));
-                        } else {
-                            spans.push(Span::styled(ch.to_string(), app.theme.style()));
-                        }
-                    }
-                    if cursor_pos >= chars.len() {
-                        spans.push(Span::styled(" ", app.theme.style().add_modifier(Modifier::REVERSED)));
+            let label = format!("{} ", app.catalog.tr("update-password"));
+            let label_width = label.chars().count() as u16;
+            frame.render_widget(
+                Paragraph::new(label).style(app.theme.style()),
+                Rect::new(area.x + 2, y, label_width, 1),
+            );
+
+            let field_x = area.x + 2 + label_width;
+            let field_width = area.width.saturating_sub(label_width + 4);
+            let password_display = "*".repeat(app.sudo_password.content().len());
+
+            if is_insert {
+                let cursor_pos = app.sudo_password.cursor().min(password_display.len());
+                let before: String = password_display.chars().take(cursor_pos).collect();
+                let after: String = password_display.chars().skip(cursor_pos).collect();
+
+                let line = Line::from(vec![
+                    Span::styled(before, app.theme.style()),
+                    Span::styled("|", app.theme.primary_style().add_modifier(Modifier::BOLD)),
+                    Span::styled(after, app.theme.style()),
+                ]);
+                frame.render_widget(Paragraph::new(line), Rect::new(field_x, y, field_width, 1));
+            } else if is_password_focused {
+                let chars: Vec<char> = password_display.chars().collect();
+                let cursor_pos = app.sudo_password.cursor().min(chars.len());
+                let mut spans = Vec::new();
+
+                for (i, ch) in chars.iter().enumerate() {
+                    if i == cursor_pos {
+                        spans.push(Span::styled(ch.to_string(), app.theme.style().add_modifier(Modifier::REVERSED)));
+                    } else {
+                        spans.push(Span::styled(ch.to_string(), app.theme.style()));
                     }
-
-                    frame.render_widget(Paragraph::new(Line::from(spans)), Rect::new(field_x, y, field_width, 1));
-                } else {
-                    let display = if password_display.is_empty() { "(enter password)" } else { &password_display };
-                    frame.render_widget(
-                        Paragraph::new(display).style(app.theme.muted_style()),
-                        Rect::new(field_x, y, field_width, 1),
-                    );
                 }
+                if cursor_pos >= chars.len() {
+                    spans.push(Span::styled(" ", app.theme.style().add_modifier(Modifier::REVERSED)));
+                }
+
+                frame.render_widget(Paragraph::new(Line::from(spans)), Rect::new(field_x, y, field_width, 1));
+            } else {
+                let display = if password_display.is_empty() { app.catalog.tr("update-enter-password") } else { password_display };
+                frame.render_widget(
+                    Paragraph::new(display).style(app.theme.muted_style()),
+                    Rect::new(field_x, y, field_width, 1),
+                );
             }
         }
     }
 
     // Action button
-    let can_run = !app.is_executing && app.tasks.is_empty();
+    let can_run = !app.is_executing && !app.shows_task_log();
     let needs_password = app.commands_need_sudo() && !app.sudo_password_entered && !app.is_dryrun();
     let any_selected = app.any_package_selected();
 
     if can_run {
         let button_y = area.y + area.height - 2;
         let button_text = if needs_password && app.sudo_password.content().is_empty() {
-            " [i] Enter Password "
+            format!(" [i] {} ", app.catalog.tr("button-enter-password"))
         } else if any_selected {
-            " [Enter] Install Selected "
+            enter_button(&app.catalog.tr("button-install"))
         } else {
-            " [Enter] Skip & Continue "
+            enter_button(&app.catalog.tr("button-skip-continue"))
         };
-        let button_width = button_text.len() as u16;
+        let button_width = button_text.chars().count() as u16;
         let button_x = area.x + 2;
 
         let button_style = if is_content_focused {
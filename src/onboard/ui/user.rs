@@ -1,7 +1,8 @@
 use ratatui::{prelude::*, widgets::Paragraph};
 
 use super::super::{ContentFocus, OnboardApp, PanelFocus};
-use crate::vim::VimMode;
+use super::enter_button;
+use crate::vim::{InputBuffer, VimMode};
 
 pub fn draw_user_form(frame: &mut Frame, area: Rect, app: &OnboardApp) {
     if area.height < 10 || area.width < 30 {
@@ -13,7 +14,7 @@ pub fn draw_user_form(frame: &mut Frame, area: Rect, app: &OnboardApp) {
 
     // Title
     frame.render_widget(
-        Paragraph::new("Create User Account")
+        Paragraph::new(app.catalog.tr("user-title"))
             .style(app.theme.primary_style().add_modifier(Modifier::BOLD)),
         Rect::new(area.x + 2, y, area.width - 4, 1),
     );
@@ -21,84 +22,14 @@ pub fn draw_user_form(frame: &mut Frame, area: Rect, app: &OnboardApp) {
 
     // Form fields - vim-style with cursor
     let fields = [
-        ("Username", &app.username, false, 0),
-        ("Password", &app.password, true, 1),
-        ("Confirm", &app.password_confirm, true, 2),
+        ("field-username", &app.username, false, 0),
+        ("field-password", &app.password, true, 1),
+        ("field-confirm", &app.password_confirm, true, 2),
     ];
 
-    for (label, buffer, masked, field_idx) in &fields {
-        let is_field_focused = is_content_focused && matches!(app.content_focus, ContentFocus::InputField(i) if i == *field_idx);
-        let is_insert = app.vim_mode == VimMode::Insert && is_field_focused;
-
-        // Label
-        let label_style = if is_field_focused {
-            app.theme.primary_style()
-        } else {
-            app.theme.style()
-        };
-        frame.render_widget(
-            Paragraph::new(*label).style(label_style),
-            Rect::new(area.x + 2, y, 12, 1),
-        );
-
-        // Input field with vim-style display
-        let content = buffer.content();
-        let display_content = if *masked && !content.is_empty() {
-            "*".repeat(content.len())
-        } else {
-            content.to_string()
-        };
-
-        let field_x = area.x + 14;
-        let field_width = area.width.saturating_sub(18);
-
-        // Draw field background/border
-        let field_style = if is_field_focused {
-            app.theme.primary_style()
-        } else {
-            app.theme.muted_style()
-        };
-
-        // Draw the content with cursor
-        if is_insert {
-            // Insert mode - show cursor as |
-            let cursor_pos = buffer.cursor();
-            let before: String = display_content.chars().take(cursor_pos).collect();
-            let after: String = display_content.chars().skip(cursor_pos).collect();
-
-            let line = Line::from(vec![
-                Span::styled(before, app.theme.style()),
-                Span::styled("|", app.theme.primary_style().add_modifier(Modifier::BOLD)),
-                Span::styled(after, app.theme.style()),
-            ]);
-            frame.render_widget(Paragraph::new(line), Rect::new(field_x, y, field_width, 1));
-        } else if is_field_focused {
-            // Normal mode - show cursor as block
-            let cursor_pos = buffer.cursor();
-            let chars: Vec<char> = display_content.chars().collect();
-            let mut spans = Vec::new();
-
-            for (i, ch) in chars.iter().enumerate() {
-                if i == cursor_pos {
-                    spans.push(Span::styled(ch.to_string(), app.theme.style().add_modifier(Modifier::REVERSED)));
-                } else {
-                    spans.push(Span::styled(ch.to_string(), app.theme.style()));
-                }
-            }
-            if cursor_pos >= chars.len() {
-                spans.push(Span::styled(" ", app.theme.style().add_modifier(Modifier::REVERSED)));
-            }
-
-            frame.render_widget(Paragraph::new(Line::from(spans)), Rect::new(field_x, y, field_width, 1));
-        } else {
-            // Not focused - just show content
-            let display = if display_content.is_empty() { "(empty)" } else { &display_content };
-            frame.render_widget(
-                Paragraph::new(display).style(field_style),
-                Rect::new(field_x, y, field_width, 1),
-            );
-        }
-
+    for (label, buffer, masked, field_idx) in fields {
+        let label = app.catalog.tr(label);
+        draw_input_field(frame, Rect::new(area.x, y, area.width, 1), app, &label, buffer, masked, field_idx);
         y += 2;
     }
 
@@ -108,8 +39,8 @@ pub fn draw_user_form(frame: &mut Frame, area: Rect, app: &OnboardApp) {
         && !app.password.content().is_empty()
         && !app.password_confirm.content().is_empty();
 
-    let button_text = " [Enter] Create User ";
-    let button_width = button_text.len() as u16;
+    let button_text = enter_button(&app.catalog.tr("button-create-user"));
+    let button_width = button_text.chars().count() as u16;
     let button_x = area.x + 2;
 
     let button_style = if is_form_ready && is_content_focused {
@@ -123,3 +54,87 @@ pub fn draw_user_form(frame: &mut Frame, area: Rect, app: &OnboardApp) {
         Rect::new(button_x, button_y, button_width, 1),
     );
 }
+
+/// One labelled text field of a form, drawn on `row` with a vim-style cursor when focused
+pub(super) fn draw_input_field(
+    frame: &mut Frame,
+    row: Rect,
+    app: &OnboardApp,
+    label: &str,
+    buffer: &InputBuffer,
+    masked: bool,
+    field_idx: usize,
+) {
+    let is_content_focused = app.panel_focus == PanelFocus::Content;
+    let is_field_focused = is_content_focused && matches!(app.content_focus, ContentFocus::InputField(i) if i == field_idx);
+    let is_insert = app.vim_mode == VimMode::Insert && is_field_focused;
+
+    // Label
+    let label_style = if is_field_focused {
+        app.theme.primary_style()
+    } else {
+        app.theme.style()
+    };
+    frame.render_widget(
+        Paragraph::new(label).style(label_style),
+        Rect::new(row.x + 2, row.y, 12, 1),
+    );
+
+    // Input field with vim-style display
+    let content = buffer.content();
+    let display_content = if masked && !content.is_empty() {
+        "*".repeat(content.len())
+    } else {
+        content.to_string()
+    };
+
+    let field_x = row.x + 14;
+    let field_width = row.width.saturating_sub(18);
+
+    // Draw field background/border
+    let field_style = if is_field_focused {
+        app.theme.primary_style()
+    } else {
+        app.theme.muted_style()
+    };
+
+    // Draw the content with cursor
+    if is_insert {
+        // Insert mode - show cursor as |
+        let cursor_pos = buffer.cursor();
+        let before: String = display_content.chars().take(cursor_pos).collect();
+        let after: String = display_content.chars().skip(cursor_pos).collect();
+
+        let line = Line::from(vec![
+            Span::styled(before, app.theme.style()),
+            Span::styled("|", app.theme.primary_style().add_modifier(Modifier::BOLD)),
+            Span::styled(after, app.theme.style()),
+        ]);
+        frame.render_widget(Paragraph::new(line), Rect::new(field_x, row.y, field_width, 1));
+    } else if is_field_focused {
+        // Normal mode - show cursor as block
+        let cursor_pos = buffer.cursor();
+        let chars: Vec<char> = display_content.chars().collect();
+        let mut spans = Vec::new();
+
+        for (i, ch) in chars.iter().enumerate() {
+            if i == cursor_pos {
+                spans.push(Span::styled(ch.to_string(), app.theme.style().add_modifier(Modifier::REVERSED)));
+            } else {
+                spans.push(Span::styled(ch.to_string(), app.theme.style()));
+            }
+        }
+        if cursor_pos >= chars.len() {
+            spans.push(Span::styled(" ", app.theme.style().add_modifier(Modifier::REVERSED)));
+        }
+
+        frame.render_widget(Paragraph::new(Line::from(spans)), Rect::new(field_x, row.y, field_width, 1));
+    } else {
+        // Not focused - just show content
+        let display = if display_content.is_empty() { app.catalog.tr("field-empty") } else { display_content };
+        frame.render_widget(
+            Paragraph::new(display).style(field_style),
+            Rect::new(field_x, row.y, field_width, 1),
+        );
+    }
+}
@@ -17,34 +17,31 @@ pub fn draw_welcome_content(frame: &mut Frame, area: Rect, app: &OnboardApp) {
     frame.render_widget(Clear, centered);
     frame.render_widget(block, centered);
 
+    let catalog = &app.catalog;
+    let item = |id: &str| format!("  * {}", catalog.tr(id));
     let welcome_text = [
-        "",
-        "This wizard will help you set up your system:",
-        "",
-        "  * Create your user account",
-        "  * Configure language and keyboard",
-        "  * Set your timezone",
-        "  * Connect to the network (if needed)",
-        "  * Install applications",
-        "",
+        String::new(),
+        catalog.tr("welcome-intro"),
+        String::new(),
+        item("welcome-user"),
+        item("welcome-language"),
+        item("welcome-timezone"),
+        item("welcome-network"),
+        item("welcome-apps"),
+        String::new(),
     ];
 
-    let mut y = inner.y;
-    for line in &welcome_text {
-        if y >= inner.y + inner.height {
-            break;
-        }
+    for (y, line) in (inner.y..inner.y + inner.height).zip(welcome_text.iter()) {
         frame.render_widget(
-            Paragraph::new(*line).style(app.theme.style()),
+            Paragraph::new(line.as_str()).style(app.theme.style()),
             Rect::new(inner.x + 1, y, inner.width.saturating_sub(2), 1),
         );
-        y += 1;
     }
 
     // Start Setup button - centered at bottom
     let button_y = inner.y + inner.height - 2;
-    let button_text = "[ Start Setup ]";
-    let button_width = button_text.len() as u16;
+    let button_text = format!("[ {} ]", catalog.tr("welcome-start"));
+    let button_width = button_text.chars().count() as u16;
     let button_x = inner.x + (inner.width.saturating_sub(button_width)) / 2;
 
     frame.render_widget(
@@ -54,10 +51,11 @@ pub fn draw_welcome_content(frame: &mut Frame, area: Rect, app: &OnboardApp) {
     );
 
     // Hint
-    let hint = "Press Enter to begin";
-    let hint_x = inner.x + (inner.width.saturating_sub(hint.len() as u16)) / 2;
+    let hint = catalog.tr("welcome-hint");
+    let hint_width = hint.chars().count() as u16;
+    let hint_x = inner.x + (inner.width.saturating_sub(hint_width)) / 2;
     frame.render_widget(
         Paragraph::new(hint).style(app.theme.muted_style()),
-        Rect::new(hint_x, button_y + 1, hint.len() as u16, 1),
+        Rect::new(hint_x, button_y + 1, hint_width, 1),
     );
 }
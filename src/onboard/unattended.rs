@@ -0,0 +1,184 @@
+//! Headless onboarding driven by an answer file (`--answers`)
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use thiserror::Error;
+use tokio::sync::mpsc;
+use tracing::info;
+
+use super::error::{OnboardError, Result};
+use super::{ExecutionMessage, OnboardApp, StepId, TaskState, TaskStatus};
+
+/// Pre-seeded answers for the wizard
+#[derive(Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Answers {
+    pub username: Option<String>,
+    /// crypt(3) hash, e.g. from `openssl passwd -6`
+    pub password_hash: Option<String>,
+    /// Plain password; only needed when selected packages run sudo commands
+    /// under `sudo_policy = "password"`
+    pub password: Option<String>,
+    pub locale: Option<String>,
+    pub keymap: Option<String>,
+    pub timezone: Option<String>,
+    pub hostname: Option<String>,
+    /// Free-form name shown alongside `hostname`, e.g. "Lab Workstation 3"
+    pub pretty_hostname: Option<String>,
+    /// Network time sync; omit to keep it on
+    pub ntp: Option<bool>,
+    /// Create a login keyring for the user; needs `password`, as a hash cannot unlock it
+    pub keyring: Option<bool>,
+    /// Titles of the packages to install; omit to keep the config defaults
+    pub packages: Option<Vec<String>>,
+    /// Answers for `[[steps]]` entries by id: an option value, a list of them,
+    /// a table of form fields, or `true` for info and program steps
+    pub steps: BTreeMap<String, toml::Value>,
+}
+
+impl Answers {
+    pub fn load_from<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| OnboardError::Answers(format!("{}: {}", path.display(), e)))?;
+        let answers = toml::from_str(&content)
+            .map_err(|e| OnboardError::Answers(format!("{}: {}", path.display(), e)))?;
+        info!("Loaded answers from {:?}", path);
+        Ok(answers)
+    }
+}
+
+/// Why an unattended run stopped
+#[derive(Error, Debug)]
+pub enum UnattendedError {
+    #[error("{0}")]
+    Answers(String),
+
+    #[error("Configuration failed: {0}")]
+    Review(String),
+
+    #[error("Package installation failed: {0}")]
+    Update(String),
+}
+
+impl UnattendedError {
+    /// Process exit status reported for this failure
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            UnattendedError::Answers(_) => 2,
+            UnattendedError::Review(_) => 3,
+            UnattendedError::Update(_) => 4,
+        }
+    }
+}
+
+/// Apply `answers` and run the Review and Update steps without a UI,
+/// printing progress to stdout
+pub async fn run_unattended(
+    app: &mut OnboardApp,
+    answers: &Answers,
+) -> std::result::Result<(), UnattendedError> {
+    if app.can_resume() {
+        println!("==> Resuming previous setup");
+        app.resume();
+    }
+
+    app.apply_answers(answers)
+        .map_err(|e| UnattendedError::Answers(e.to_string()))?;
+
+    if !app.is_dryrun() && app.commands_need_sudo() && answers.password.is_none() {
+        return Err(UnattendedError::Answers(
+            "selected packages run sudo commands with sudo_policy = \"password\", which need `password`".to_string(),
+        ));
+    }
+
+    println!("==> Applying configuration");
+    let rx = app.start_review_execution();
+    if rx.is_none() && !app.is_executing {
+        return Err(UnattendedError::Answers(last_message(app)));
+    }
+    follow(app, rx).await;
+    if !app.review_completed {
+        return Err(UnattendedError::Review(last_message(app)));
+    }
+
+    if app.step_index_by_id(StepId::Update).is_some() {
+        println!("==> Installing packages");
+        if let Some(ref password) = answers.password {
+            app.sudo_password.set(password);
+            app.sudo_password_entered = true;
+        }
+        let rx = app.start_update_execution();
+        follow(app, rx).await;
+
+        if !app.update_completed {
+            return Err(UnattendedError::Update(last_message(app)));
+        }
+    }
+
+    println!("==> Finishing setup");
+    app.finish_setup().await;
+    println!("Setup complete");
+    if !app.skipped_tasks.is_empty() {
+        println!("Skipped:");
+        for task in &app.skipped_tasks {
+            println!("  - {task}");
+        }
+    }
+    Ok(())
+}
+
+/// Wait for the running step, from the task channel or the dryrun simulation
+async fn follow(app: &mut OnboardApp, rx: Option<mpsc::UnboundedReceiver<ExecutionMessage>>) {
+    let mut reported = Vec::new();
+    match rx {
+        Some(mut rx) => {
+            while let Some(msg) = rx.recv().await {
+                if let ExecutionMessage::TaskOutput(_, ref line) = msg {
+                    println!("       {line}");
+                }
+                app.handle_execution_message(msg);
+                report(&app.tasks, &mut reported);
+            }
+        }
+        None => {
+            while app.is_executing {
+                app.tick();
+                report(&app.tasks, &mut reported);
+            }
+        }
+    }
+}
+
+/// Print every task whose state changed since the last call
+fn report(tasks: &[TaskStatus], reported: &mut Vec<TaskState>) {
+    reported.resize(tasks.len(), TaskState::Pending);
+    for (task, last) in tasks.iter().zip(reported.iter_mut()) {
+        if task.status == *last {
+            continue;
+        }
+        *last = task.status;
+        match task.status {
+            TaskState::Running => println!("  ...  {}", task.name),
+            TaskState::Success => println!("  ok   {}", task.name),
+            TaskState::Failed => println!(
+                "  FAIL {}: {}",
+                task.name,
+                task.output.as_deref().unwrap_or("")
+            ),
+            TaskState::Skipped => match task.output {
+                Some(ref reason) => println!("  skip {}: {reason}", task.name),
+                None => println!("  skip {}", task.name),
+            },
+            TaskState::Pending => {}
+        }
+    }
+}
+
+fn last_message(app: &OnboardApp) -> String {
+    app.message
+        .as_ref()
+        .map(|m| m.text.clone())
+        .unwrap_or_else(|| "unknown error".to_string())
+}
@@ -1,3 +1,5 @@
+use crate::i18n::Catalog;
+
 /// Dynamic status bar state that can be updated by content panels
 #[derive(Debug, Clone, Default)]
 pub struct StatusBarState {
@@ -8,121 +10,109 @@ pub struct StatusBarState {
 }
 
 impl StatusBarState {
-    /// Get hints for normal mode in sidebar
-    pub fn sidebar_normal() -> Self {
+    /// Hints from the messages `left` and `right`; an empty id leaves that side empty
+    fn hints(catalog: &Catalog, left: &str, right: &str) -> Self {
+        let text = |id: &str| if id.is_empty() { String::new() } else { catalog.tr(id) };
         Self {
-            left_hint: "j/k: navigate".to_string(),
-            right_hint: "l/Enter: edit  :help".to_string(),
+            left_hint: text(left),
+            right_hint: text(right),
         }
     }
 
+    /// Get hints for normal mode in sidebar
+    pub fn sidebar_normal(catalog: &Catalog) -> Self {
+        Self::hints(catalog, "status-sidebar", "status-sidebar-keys")
+    }
+
     /// Get hints for normal mode in content with picker
-    pub fn content_picker_normal() -> Self {
-        Self {
-            left_hint: "j/k: navigate  Enter: select".to_string(),
-            right_hint: "i: filter  Ctrl+h: sidebar".to_string(),
-        }
+    pub fn content_picker_normal(catalog: &Catalog) -> Self {
+        Self::hints(catalog, "status-picker", "status-picker-keys")
     }
 
     /// Get hints for insert mode in content with picker
-    pub fn content_picker_insert() -> Self {
-        Self {
-            left_hint: "Type to filter".to_string(),
-            right_hint: "Esc: normal  Enter: select".to_string(),
-        }
+    pub fn content_picker_insert(catalog: &Catalog) -> Self {
+        Self::hints(catalog, "status-picker-insert", "status-picker-insert-keys")
+    }
+
+    /// Get hints for the toggles on the Preferences step
+    pub fn content_toggles(catalog: &Catalog) -> Self {
+        Self::hints(catalog, "status-toggles", "status-toggles-keys")
+    }
+
+    /// Get hints for a custom info step
+    pub fn info_step(catalog: &Catalog) -> Self {
+        Self::hints(catalog, "", "status-info-keys")
+    }
+
+    /// Get hints for a custom step that runs a program
+    pub fn program_step(catalog: &Catalog) -> Self {
+        Self::hints(catalog, "", "status-program-keys")
     }
 
     /// Get hints for normal mode in content with form
-    pub fn content_form_normal() -> Self {
-        Self {
-            left_hint: "j/k: fields  i: edit".to_string(),
-            right_hint: "Enter: submit  Ctrl+h: sidebar".to_string(),
-        }
+    pub fn content_form_normal(catalog: &Catalog) -> Self {
+        Self::hints(catalog, "status-form", "status-form-keys")
     }
 
     /// Get hints for insert mode in content with form
-    pub fn content_form_insert() -> Self {
-        Self {
-            left_hint: "Type to enter text".to_string(),
-            right_hint: "Esc: normal  Tab: next field".to_string(),
-        }
+    pub fn content_form_insert(catalog: &Catalog) -> Self {
+        Self::hints(catalog, "status-form-insert", "status-form-insert-keys")
     }
 
     /// Get hints for command mode
-    pub fn command_mode() -> Self {
-        Self {
-            left_hint: String::new(),
-            right_hint: "Enter: run  Esc: cancel".to_string(),
-        }
+    pub fn command_mode(catalog: &Catalog) -> Self {
+        Self::hints(catalog, "", "status-command-keys")
     }
 
     /// Get hints for welcome screen
-    pub fn welcome() -> Self {
-        Self {
-            left_hint: String::new(),
-            right_hint: "Enter: start setup".to_string(),
-        }
+    pub fn welcome(catalog: &Catalog) -> Self {
+        Self::hints(catalog, "", "status-welcome-keys")
     }
 
     /// Get hints for review step
-    pub fn review_step() -> Self {
-        Self {
-            left_hint: "Review your settings".to_string(),
-            right_hint: "Enter: apply  Ctrl+h: sidebar".to_string(),
-        }
+    pub fn review_step(catalog: &Catalog) -> Self {
+        Self::hints(catalog, "status-review", "status-review-keys")
     }
 
     /// Get hints for update step
-    pub fn update_step(needs_password: bool) -> Self {
+    pub fn update_step(catalog: &Catalog, needs_password: bool) -> Self {
         if needs_password {
-            Self {
-                left_hint: "Password required".to_string(),
-                right_hint: "i: enter password  :skip".to_string(),
-            }
+            Self::hints(catalog, "status-update-password", "status-update-password-keys")
         } else {
-            Self {
-                left_hint: "Ready to run commands".to_string(),
-                right_hint: "Enter: run  :skip".to_string(),
-            }
+            Self::hints(catalog, "status-update", "status-update-keys")
         }
     }
 
-    /// Get hints for reboot/finish step
-    pub fn reboot_step() -> Self {
-        Self {
-            left_hint: "Setup complete!".to_string(),
-            right_hint: "Enter: reboot system".to_string(),
+    /// Get hints for a step's task list and output pane
+    pub fn task_output(catalog: &Catalog, failed: bool) -> Self {
+        if failed {
+            Self::hints(catalog, "status-task-failed", "status-task-failed-keys")
+        } else {
+            Self::hints(catalog, "status-task-output", "status-task-output-keys")
         }
     }
 
+    /// Get hints for reboot/finish step
+    pub fn reboot_step(catalog: &Catalog) -> Self {
+        Self::hints(catalog, "status-reboot", "status-reboot-keys")
+    }
+
     /// Get hints for network step
-    pub fn network_step(connected: bool) -> Self {
+    pub fn network_step(catalog: &Catalog, connected: bool) -> Self {
         if connected {
-            Self {
-                left_hint: "Network connected".to_string(),
-                right_hint: "Enter: next  Ctrl+h: sidebar".to_string(),
-            }
+            Self::hints(catalog, "status-network", "status-network-keys")
         } else {
-            Self {
-                left_hint: "Network not connected".to_string(),
-                right_hint: "Enter: configure  :skip".to_string(),
-            }
+            Self::hints(catalog, "status-network-none", "status-network-none-keys")
         }
     }
 
     /// Get hints when a step is locked
-    pub fn locked_step() -> Self {
-        Self {
-            left_hint: "Step locked".to_string(),
-            right_hint: "Complete previous steps first".to_string(),
-        }
+    pub fn locked_step(catalog: &Catalog) -> Self {
+        Self::hints(catalog, "status-locked", "status-locked-keys")
     }
 
     /// Get hints while executing
-    pub fn executing() -> Self {
-        Self {
-            left_hint: "Please wait...".to_string(),
-            right_hint: String::new(),
-        }
+    pub fn executing(catalog: &Catalog) -> Self {
+        Self::hints(catalog, "status-executing", "")
     }
 }
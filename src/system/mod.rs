@@ -2,6 +2,6 @@ mod power;
 mod session;
 mod user;
 
-pub use power::{poweroff, reboot};
+pub use power::{poweroff, reboot, run_power_command, POWEROFF_COMMAND, REBOOT_COMMAND};
 pub use session::{discover_sessions, Session, SessionType};
 pub use user::{discover_users, User};
@@ -1,13 +1,16 @@
 use std::process::Command;
 use tracing::{error, info};
 
+pub const REBOOT_COMMAND: &[&str] = &["systemctl", "reboot"];
+pub const POWEROFF_COMMAND: &[&str] = &["systemctl", "poweroff"];
+
 pub fn reboot(demo_mode: bool) -> std::io::Result<()> {
     if demo_mode {
         info!("Demo mode: skipping reboot");
         return Ok(());
     }
     info!("Executing reboot");
-    execute_power_command(&["systemctl", "reboot"])
+    execute_power_command(REBOOT_COMMAND)
 }
 
 pub fn poweroff(demo_mode: bool) -> std::io::Result<()> {
@@ -16,7 +19,21 @@ pub fn poweroff(demo_mode: bool) -> std::io::Result<()> {
         return Ok(());
     }
     info!("Executing poweroff");
-    execute_power_command(&["systemctl", "poweroff"])
+    execute_power_command(POWEROFF_COMMAND)
+}
+
+/// Run a configured power command (e.g. from the greeter config)
+pub fn run_power_command<S: AsRef<str>>(args: &[S], demo_mode: bool) -> std::io::Result<()> {
+    let args: Vec<&str> = args.iter().map(|s| s.as_ref()).collect();
+    if demo_mode {
+        info!("Demo mode: skipping {:?}", args);
+        return Ok(());
+    }
+    if args.is_empty() {
+        return Err(std::io::Error::other("Power command is empty"));
+    }
+    info!("Executing {:?}", args);
+    execute_power_command(&args)
 }
 
 fn execute_power_command(args: &[&str]) -> std::io::Result<()> {
@@ -45,7 +45,7 @@ pub fn discover_sessions() -> Vec<Session> {
         }
     }
 
-    sessions.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+    sessions.sort_by_key(|s| s.name.to_lowercase());
     sessions.dedup_by(|a, b| a.slug == b.slug);
 
     debug!("Discovered {} sessions", sessions.len());
@@ -65,10 +65,10 @@ fn load_sessions_from_dir(dir: &PathBuf, session_type: SessionType) -> Vec<Sessi
 
     for entry in entries.flatten() {
         let path = entry.path();
-        if path.extension().is_some_and(|ext| ext == "desktop") {
-            if let Some(session) = parse_desktop_file(&path, session_type) {
-                sessions.push(session);
-            }
+        if path.extension().is_some_and(|ext| ext == "desktop")
+            && let Some(session) = parse_desktop_file(&path, session_type)
+        {
+            sessions.push(session);
         }
     }
 
@@ -8,17 +8,21 @@ pub struct User {
     pub display_name: Option<String>,
 }
 
-pub fn discover_users() -> Vec<User> {
-    let (min_uid, max_uid) = read_uid_bounds();
+/// List login users from /etc/passwd.
+/// `min_uid`/`max_uid` override the bounds from /etc/login.defs when set.
+pub fn discover_users(hidden: &[String], min_uid: Option<u32>, max_uid: Option<u32>) -> Vec<User> {
+    let (defs_min_uid, defs_max_uid) = read_uid_bounds();
+    let min_uid = min_uid.unwrap_or(defs_min_uid);
+    let max_uid = max_uid.unwrap_or(defs_max_uid);
     let mut users = Vec::new();
 
     // Read /etc/passwd directly for user enumeration
     if let Ok(content) = fs::read_to_string("/etc/passwd") {
         for line in content.lines() {
-            if let Some(user) = parse_passwd_line(line, min_uid, max_uid) {
-                if !should_hide_user(&user.username) {
-                    users.push(user);
-                }
+            if let Some(user) = parse_passwd_line(line, min_uid, max_uid)
+                && !hidden.contains(&user.username)
+            {
+                users.push(user);
             }
         }
     } else {
@@ -101,8 +105,3 @@ fn read_uid_bounds() -> (u32, u32) {
     debug!("UID bounds: {} - {}", min_uid, max_uid);
     (min_uid, max_uid)
 }
-
-fn should_hide_user(username: &str) -> bool {
-    const HIDDEN_USERS: &[&str] = &["nobody", "nfsnobody", "greeter"];
-    HIDDEN_USERS.contains(&username)
-}
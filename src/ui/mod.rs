@@ -1,3 +1,5 @@
 mod theme;
+mod wildmenu;
 
-pub use theme::Theme;
+pub use theme::{Theme, ThemeConfig, PALETTES};
+pub use wildmenu::wildmenu_line;
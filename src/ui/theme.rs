@@ -1,4 +1,8 @@
 use ratatui::style::{Color, Modifier, Style};
+use serde::Deserialize;
+use std::path::Path;
+use std::str::FromStr;
+use tracing::warn;
 
 #[derive(Debug, Clone)]
 pub struct Theme {
@@ -27,7 +31,144 @@ impl Default for Theme {
     }
 }
 
+/// Names accepted by [`Theme::named`]
+pub const PALETTES: &[&str] = &["default", "gruvbox", "catppuccin", "nord", "high-contrast"];
+
+/// Theme selection and color overrides from a config or theme file.
+/// Colors accept ANSI color names, 256-color indices or `#rrggbb`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    /// Built-in palette the overrides are applied on top of
+    pub palette: Option<String>,
+    /// TOML theme file with the same keys, applied before this section's overrides
+    pub file: Option<String>,
+    pub primary: Option<String>,
+    pub secondary: Option<String>,
+    pub background: Option<String>,
+    pub foreground: Option<String>,
+    pub error: Option<String>,
+    pub success: Option<String>,
+    pub border: Option<String>,
+    pub muted: Option<String>,
+}
+
+impl ThemeConfig {
+    /// Load a theme file
+    pub fn load_from<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let path = path.as_ref();
+        let content =
+            std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        toml::from_str(&content).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Build the theme: palette, then theme file, then the overrides in this section
+    pub fn build(&self) -> Theme {
+        let file = self.file.as_ref().and_then(|path| match Self::load_from(path) {
+            Ok(file) => Some(file),
+            Err(e) => {
+                warn!("Ignoring theme file {}", e);
+                None
+            }
+        });
+
+        // The section's palette wins over the one named in the theme file
+        let palette = self
+            .palette
+            .as_deref()
+            .or_else(|| file.as_ref().and_then(|f| f.palette.as_deref()));
+        let mut theme = match palette {
+            Some(name) => Theme::named(name).unwrap_or_else(|| {
+                warn!("Unknown theme palette {:?}, expected one of {:?}", name, PALETTES);
+                Theme::default()
+            }),
+            None => Theme::default(),
+        };
+
+        if let Some(file) = &file {
+            if file.file.is_some() {
+                warn!("Theme files cannot include other theme files");
+            }
+            file.apply(&mut theme);
+        }
+        self.apply(&mut theme);
+        theme
+    }
+
+    fn apply(&self, theme: &mut Theme) {
+        let overrides = [
+            (&self.primary, &mut theme.primary),
+            (&self.secondary, &mut theme.secondary),
+            (&self.background, &mut theme.background),
+            (&self.foreground, &mut theme.foreground),
+            (&self.error, &mut theme.error),
+            (&self.success, &mut theme.success),
+            (&self.border, &mut theme.border),
+            (&self.muted, &mut theme.muted),
+        ];
+        for (value, slot) in overrides {
+            if let Some(value) = value {
+                match Color::from_str(value) {
+                    Ok(color) => *slot = color,
+                    Err(_) => warn!("Ignoring invalid theme color: {}", value),
+                }
+            }
+        }
+    }
+}
+
 impl Theme {
+    /// Look up a built-in palette by name
+    pub fn named(name: &str) -> Option<Self> {
+        let theme = match name.to_lowercase().as_str() {
+            "default" => Self::default(),
+            "gruvbox" => Self {
+                primary: Color::Rgb(0xfa, 0xbd, 0x2f),
+                secondary: Color::Rgb(0x8e, 0xc0, 0x7c),
+                background: Color::Rgb(0x28, 0x28, 0x28),
+                foreground: Color::Rgb(0xeb, 0xdb, 0xb2),
+                error: Color::Rgb(0xfb, 0x49, 0x34),
+                success: Color::Rgb(0xb8, 0xbb, 0x26),
+                border: Color::Rgb(0x66, 0x5c, 0x54),
+                muted: Color::Rgb(0x92, 0x83, 0x74),
+            },
+            // Mocha flavour
+            "catppuccin" => Self {
+                primary: Color::Rgb(0xcb, 0xa6, 0xf7),
+                secondary: Color::Rgb(0x89, 0xdc, 0xeb),
+                background: Color::Rgb(0x1e, 0x1e, 0x2e),
+                foreground: Color::Rgb(0xcd, 0xd6, 0xf4),
+                error: Color::Rgb(0xf3, 0x8b, 0xa8),
+                success: Color::Rgb(0xa6, 0xe3, 0xa1),
+                border: Color::Rgb(0x58, 0x5b, 0x70),
+                muted: Color::Rgb(0x6c, 0x70, 0x86),
+            },
+            "nord" => Self {
+                primary: Color::Rgb(0xeb, 0xcb, 0x8b),
+                secondary: Color::Rgb(0x88, 0xc0, 0xd0),
+                background: Color::Rgb(0x2e, 0x34, 0x40),
+                foreground: Color::Rgb(0xec, 0xef, 0xf4),
+                error: Color::Rgb(0xbf, 0x61, 0x6a),
+                success: Color::Rgb(0xa3, 0xbe, 0x8c),
+                border: Color::Rgb(0x4c, 0x56, 0x6a),
+                muted: Color::Rgb(0x61, 0x6e, 0x88),
+            },
+            // Plain ANSI colors so it also works on the Linux console
+            "high-contrast" => Self {
+                primary: Color::LightYellow,
+                secondary: Color::LightCyan,
+                background: Color::Black,
+                foreground: Color::White,
+                error: Color::LightRed,
+                success: Color::LightGreen,
+                border: Color::White,
+                muted: Color::Gray,
+            },
+            _ => return None,
+        };
+        Some(theme)
+    }
+
     pub fn style(&self) -> Style {
         Style::default().fg(self.foreground).bg(self.background)
     }
@@ -40,6 +181,10 @@ impl Theme {
         Style::default().fg(self.secondary)
     }
 
+    pub fn success_style(&self) -> Style {
+        Style::default().fg(self.success)
+    }
+
     pub fn error_style(&self) -> Style {
         Style::default().fg(self.error)
     }
@@ -0,0 +1,46 @@
+use super::Theme;
+use crate::vim::Completion;
+use ratatui::prelude::*;
+
+const SEPARATOR: &str = "  ";
+
+/// Completion candidates on one line, scrolled so the highlighted one stays visible
+pub fn wildmenu_line(completion: &Completion, theme: &Theme, max_width: usize) -> Line<'static> {
+    let candidates = &completion.candidates;
+    let widths: Vec<usize> = candidates.iter().map(|c| c.chars().count()).collect();
+    // Room for the "< " and " >" scroll markers
+    let budget = max_width.saturating_sub(4);
+    let span_width = |first: usize, last: usize| {
+        widths[first..=last].iter().sum::<usize>() + SEPARATOR.len() * (last - first)
+    };
+
+    let selected = completion.selected.unwrap_or(0);
+    let mut first = 0;
+    while first < selected && span_width(first, selected) > budget {
+        first += 1;
+    }
+    let mut last = selected;
+    while last + 1 < candidates.len() && span_width(first, last + 1) <= budget {
+        last += 1;
+    }
+
+    let mut spans = Vec::new();
+    if first > 0 {
+        spans.push(Span::styled("< ", theme.muted_style()));
+    }
+    for (i, candidate) in candidates.iter().enumerate().take(last + 1).skip(first) {
+        if i > first {
+            spans.push(Span::raw(SEPARATOR));
+        }
+        let style = if completion.selected == Some(i) {
+            theme.primary_style().add_modifier(Modifier::REVERSED)
+        } else {
+            theme.secondary_style()
+        };
+        spans.push(Span::styled(candidate.clone(), style));
+    }
+    if last + 1 < candidates.len() {
+        spans.push(Span::styled(" >", theme.muted_style()));
+    }
+    Line::from(spans)
+}
@@ -0,0 +1,197 @@
+//! The `:` command line shared by both apps, with history recall and wildmenu completion
+
+use super::InputBuffer;
+
+/// Commands remembered per session
+const MAX_HISTORY: usize = 50;
+
+#[derive(Default)]
+pub struct CommandLine {
+    buffer: InputBuffer,
+    history: Vec<String>,
+    /// History entry being shown, `None` while editing a fresh line
+    history_pos: Option<usize>,
+    /// Line typed before recalling history; only entries starting with it are recalled
+    draft: String,
+    completion: Option<Completion>,
+}
+
+/// Candidates offered for the word being completed
+#[derive(Debug, Clone)]
+pub struct Completion {
+    /// Byte offset where the completed word starts
+    start: usize,
+    /// The word as typed, shown again after cycling past the last candidate
+    typed: String,
+    pub candidates: Vec<String>,
+    /// Highlighted candidate; `None` shows the typed word
+    pub selected: Option<usize>,
+}
+
+impl CommandLine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn content(&self) -> &str {
+        self.buffer.content()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Active wildmenu, if Tab was pressed since the last edit
+    pub fn completion(&self) -> Option<&Completion> {
+        self.completion.as_ref()
+    }
+
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    pub fn insert(&mut self, c: char) {
+        self.stop_browsing();
+        self.buffer.insert(c);
+    }
+
+    pub fn delete_back(&mut self) -> bool {
+        self.stop_browsing();
+        self.buffer.delete_back()
+    }
+
+    /// Abandon the line; history is kept
+    pub fn clear(&mut self) {
+        self.stop_browsing();
+        self.buffer.clear();
+    }
+
+    /// Take the line for execution and remember it in the history
+    pub fn submit(&mut self) -> String {
+        let line = self.buffer.content().trim().to_string();
+        if !line.is_empty() && self.history.last() != Some(&line) {
+            self.history.push(line.clone());
+            if self.history.len() > MAX_HISTORY {
+                self.history.remove(0);
+            }
+        }
+        self.clear();
+        line
+    }
+
+    /// Recall the previous history entry starting with the typed text
+    pub fn history_prev(&mut self) {
+        self.completion = None;
+        let pos = match self.history_pos {
+            Some(pos) => pos,
+            None => {
+                self.draft = self.buffer.content().to_string();
+                self.history.len()
+            }
+        };
+        let draft = self.draft.as_str();
+        if let Some(idx) = self.history[..pos].iter().rposition(|e| e.starts_with(draft)) {
+            self.history_pos = Some(idx);
+            let entry = self.history[idx].clone();
+            self.buffer.set(&entry);
+        }
+    }
+
+    /// Recall the next history entry, returning to the typed text after the newest
+    pub fn history_next(&mut self) {
+        self.completion = None;
+        let Some(pos) = self.history_pos else {
+            return;
+        };
+        let draft = self.draft.as_str();
+        match self.history[pos + 1..].iter().position(|e| e.starts_with(draft)) {
+            Some(offset) => {
+                self.history_pos = Some(pos + 1 + offset);
+                let entry = self.history[pos + 1 + offset].clone();
+                self.buffer.set(&entry);
+            }
+            None => {
+                self.history_pos = None;
+                let draft = std::mem::take(&mut self.draft);
+                self.buffer.set(&draft);
+            }
+        }
+    }
+
+    /// Complete the word before the cursor, cycling through candidates on repeated calls
+    ///
+    /// The first word completes from `commands`; later words from `arguments`, which is
+    /// given the command name as typed. Matching and duplicate removal ignore case.
+    pub fn complete<F>(&mut self, commands: &[&str], arguments: F, forward: bool)
+    where
+        F: FnOnce(&str) -> Vec<String>,
+    {
+        if self.completion.is_none() {
+            self.history_pos = None;
+            let Some(completion) = start_completion(self.buffer.content(), commands, arguments)
+            else {
+                return;
+            };
+            self.completion = Some(completion);
+        }
+        let Some(completion) = self.completion.as_mut() else {
+            return;
+        };
+
+        let count = completion.candidates.len();
+        completion.selected = match (completion.selected, forward) {
+            (None, true) => Some(0),
+            (None, false) => Some(count - 1),
+            (Some(i), true) if i + 1 < count => Some(i + 1),
+            (Some(i), false) if i > 0 => Some(i - 1),
+            _ => None,
+        };
+
+        let word = match completion.selected {
+            Some(i) => completion.candidates[i].as_str(),
+            None => completion.typed.as_str(),
+        };
+        let line = format!("{}{}", &self.buffer.content()[..completion.start], word);
+        self.buffer.set(&line);
+    }
+
+    fn stop_browsing(&mut self) {
+        self.history_pos = None;
+        self.completion = None;
+    }
+}
+
+fn start_completion<F>(line: &str, commands: &[&str], arguments: F) -> Option<Completion>
+where
+    F: FnOnce(&str) -> Vec<String>,
+{
+    let cmd_start = line.len() - line.trim_start().len();
+    let (start, candidates) = match line[cmd_start..].find(' ') {
+        None => (cmd_start, commands.iter().map(|c| c.to_string()).collect()),
+        Some(space) => {
+            let rest = &line[cmd_start + space..];
+            let arg_start = cmd_start + space + (rest.len() - rest.trim_start().len());
+            (arg_start, arguments(&line[cmd_start..cmd_start + space]))
+        }
+    };
+
+    let typed = line[start..].to_string();
+    let prefix = typed.to_lowercase();
+    let mut matches: Vec<String> = Vec::new();
+    for candidate in candidates {
+        let lower = candidate.to_lowercase();
+        if lower.starts_with(&prefix) && !matches.iter().any(|m| m.to_lowercase() == lower) {
+            matches.push(candidate);
+        }
+    }
+
+    if matches.is_empty() {
+        return None;
+    }
+    Some(Completion {
+        start,
+        typed,
+        candidates: matches,
+        selected: None,
+    })
+}
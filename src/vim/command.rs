@@ -18,23 +18,58 @@ pub enum CommandError {
     Unknown(String),
 }
 
+/// One entry of a `:` command table: the name offered by Tab completion, the
+/// abbreviations that also parse, and how to build the command from its argument
+pub struct CommandSpec<C> {
+    pub name: &'static str,
+    pub aliases: &'static [&'static str],
+    pub build: fn(Option<String>) -> C,
+}
+
+impl<C> CommandSpec<C> {
+    pub const fn new(name: &'static str, aliases: &'static [&'static str], build: fn(Option<String>) -> C) -> Self {
+        Self { name, aliases, build }
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        self.name == name || self.aliases.contains(&name)
+    }
+}
+
+/// Look up `name` (a full name or an alias) in a command table
+pub fn find_command<'a, C>(table: &'a [CommandSpec<C>], name: &str) -> Option<&'a CommandSpec<C>> {
+    table.iter().find(|spec| spec.matches(name))
+}
+
+/// Full names in a command table, offered by Tab completion
+pub fn command_names<C>(table: &[CommandSpec<C>]) -> Vec<&'static str> {
+    table.iter().map(|spec| spec.name).collect()
+}
+
+/// Every greeter command accepted by [`parse_command`]
+pub const COMMANDS: &[CommandSpec<Command>] = &[
+    CommandSpec::new("cancel", &["c"], |_| Command::Cancel),
+    CommandSpec::new("exit", &[], |_| Command::Quit),
+    CommandSpec::new("help", &["h", "?"], |_| Command::Help),
+    CommandSpec::new("login", &["l"], |_| Command::Login),
+    CommandSpec::new("poweroff", &["po"], |_| Command::Poweroff),
+    CommandSpec::new("quit", &["q"], |_| Command::Quit),
+    CommandSpec::new("reboot", &["rb"], |_| Command::Reboot),
+    CommandSpec::new("session", &["s"], Command::Session),
+    CommandSpec::new("shutdown", &[], |_| Command::Poweroff),
+    CommandSpec::new("user", &["u"], Command::User),
+];
+
 pub fn parse_command(input: &str) -> Result<Command, CommandError> {
     let input = input.trim();
     let mut parts = input.splitn(2, ' ');
     let cmd = parts.next().unwrap_or("");
     let arg = parts.next().map(|s| s.trim().to_string());
 
-    match cmd {
-        "reboot" | "rb" => Ok(Command::Reboot),
-        "poweroff" | "shutdown" | "po" => Ok(Command::Poweroff),
-        "session" | "s" => Ok(Command::Session(arg)),
-        "user" | "u" => Ok(Command::User(arg)),
-        "login" | "l" => Ok(Command::Login),
-        "cancel" | "c" => Ok(Command::Cancel),
-        "help" | "h" | "?" => Ok(Command::Help),
-        "q" | "quit" | "exit" => Ok(Command::Quit),
-        "" => Err(CommandError::Unknown("empty command".to_string())),
-        other => Err(CommandError::Unknown(other.to_string())),
+    if cmd.is_empty() {
+        return Err(CommandError::Unknown("empty command".to_string()));
     }
+    find_command(COMMANDS, cmd)
+        .map(|spec| (spec.build)(arg))
+        .ok_or_else(|| CommandError::Unknown(cmd.to_string()))
 }
-
@@ -1,10 +1,18 @@
 use zeroize::Zeroize;
 
+/// Undo steps kept per buffer
+const MAX_HISTORY: usize = 100;
+
 #[derive(Default, Clone)]
 pub struct InputBuffer {
     content: String,
     cursor: usize,
     masked: bool,
+    /// States before each undo step; never recorded for masked buffers
+    undo: Vec<(String, usize)>,
+    redo: Vec<(String, usize)>,
+    /// Set once the current undo step has saved its starting state
+    step_recorded: bool,
 }
 
 impl InputBuffer {
@@ -13,11 +21,9 @@ impl InputBuffer {
     }
 
     pub fn masked() -> Self {
-        Self {
-            content: String::new(),
-            cursor: 0,
-            masked: true,
-        }
+        let mut buffer = Self::default();
+        buffer.masked = true;
+        buffer
     }
 
     pub fn content(&self) -> &str {
@@ -45,6 +51,7 @@ impl InputBuffer {
     }
 
     pub fn insert(&mut self, c: char) {
+        self.record();
         let byte_pos = self.cursor_byte_position();
         self.content.insert(byte_pos, c);
         self.cursor += 1;
@@ -52,6 +59,7 @@ impl InputBuffer {
 
     pub fn delete_back(&mut self) -> bool {
         if self.cursor > 0 {
+            self.record();
             self.cursor -= 1;
             let byte_pos = self.cursor_byte_position();
             let next_byte_pos = self.content[byte_pos..]
@@ -69,6 +77,7 @@ impl InputBuffer {
     pub fn delete_forward(&mut self) -> bool {
         let len = self.len();
         if self.cursor < len {
+            self.record();
             let byte_pos = self.cursor_byte_position();
             let next_byte_pos = self.content[byte_pos..]
                 .char_indices()
@@ -103,21 +112,102 @@ impl InputBuffer {
     }
 
     pub fn clear(&mut self) {
+        if !self.content.is_empty() {
+            self.record();
+        }
         self.content.zeroize();
         self.content.clear();
         self.cursor = 0;
     }
 
     pub fn set(&mut self, value: &str) {
+        if self.content != value {
+            self.record();
+        }
         self.content.zeroize();
         self.content = value.to_string();
         self.cursor = self.len();
     }
 
+    /// Move the cursor to a character index, clamped to the end of the content
+    pub fn set_cursor(&mut self, pos: usize) {
+        self.cursor = pos.min(self.len());
+    }
+
+    /// Remove the characters in `start..end` and leave the cursor at `start`
+    pub fn delete_range(&mut self, start: usize, end: usize) {
+        let end = end.min(self.len());
+        if start < end {
+            self.record();
+            let start_byte = self.byte_position(start);
+            let end_byte = self.byte_position(end);
+            self.content.drain(start_byte..end_byte);
+        }
+        self.set_cursor(start);
+    }
+
+    /// Replace the character at `pos`, if there is one
+    pub fn replace_char(&mut self, pos: usize, c: char) {
+        if pos < self.len() {
+            self.record();
+            let start = self.byte_position(pos);
+            let end = self.byte_position(pos + 1);
+            let mut buf = [0u8; 4];
+            self.content.replace_range(start..end, c.encode_utf8(&mut buf));
+        }
+    }
+
+    /// End the current undo step; the next edit starts a new one
+    pub fn checkpoint(&mut self) {
+        self.step_recorded = false;
+    }
+
+    /// Restore the state before the last undo step. Returns false if there was none.
+    pub fn undo(&mut self) -> bool {
+        let Some(previous) = self.undo.pop() else {
+            return false;
+        };
+        let current = (std::mem::replace(&mut self.content, previous.0), self.cursor);
+        self.cursor = previous.1;
+        self.redo.push(current);
+        self.step_recorded = false;
+        true
+    }
+
+    /// Reapply the last undone step. Returns false if there was none.
+    pub fn redo(&mut self) -> bool {
+        let Some(next) = self.redo.pop() else {
+            return false;
+        };
+        let current = (std::mem::replace(&mut self.content, next.0), self.cursor);
+        self.cursor = next.1;
+        self.undo.push(current);
+        self.step_recorded = false;
+        true
+    }
+
+    /// Save the state before the first edit of an undo step
+    fn record(&mut self) {
+        // Old secrets must not linger in memory, so masked buffers have no history
+        if self.masked || self.step_recorded {
+            return;
+        }
+        self.step_recorded = true;
+        if self.undo.len() == MAX_HISTORY {
+            self.undo.remove(0);
+        }
+        self.undo.push((self.content.clone(), self.cursor));
+        self.redo.clear();
+    }
+
     fn cursor_byte_position(&self) -> usize {
+        self.byte_position(self.cursor)
+    }
+
+    fn byte_position(&self, pos: usize) -> usize {
         self.content
             .char_indices()
-            .nth(self.cursor)
+            .nth(pos)
             .map(|(i, _)| i)
             .unwrap_or(self.content.len())
     }
@@ -125,6 +215,7 @@ impl InputBuffer {
 
 impl Drop for InputBuffer {
     fn drop(&mut self) {
+        // History is never recorded for masked buffers, so the content is the only copy
         if self.masked {
             self.content.zeroize();
         }
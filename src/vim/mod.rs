@@ -1,7 +1,11 @@
+mod cmdline;
 mod command;
 mod input;
 mod mode;
+mod normal;
 
-pub use command::{parse_command, Command};
+pub use cmdline::{CommandLine, Completion};
+pub use command::{command_names, find_command, parse_command, Command, CommandSpec, COMMANDS};
 pub use input::InputBuffer;
 pub use mode::{ModeAction, VimMode};
+pub use normal::{NormalCommand, NormalParser, Parsed};
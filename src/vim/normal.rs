@@ -0,0 +1,621 @@
+use super::InputBuffer;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Result of feeding one key to the [`NormalParser`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Parsed {
+    /// Part of an unfinished or aborted command; nothing else should handle it
+    Consumed,
+    /// A complete command to apply to the focused input
+    Command(NormalCommand),
+    /// Not an editing key; the app handles it (navigation, `:`, Enter, ...)
+    Unhandled,
+}
+
+/// A complete normal-mode command, e.g. `3x`, `d2w`, `ciw` or `fa`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalCommand {
+    count: usize,
+    kind: CommandKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CommandKind {
+    Move(Motion),
+    Operate(Operator, Target),
+    /// `x`
+    DeleteChar,
+    /// `X`
+    DeleteCharBefore,
+    /// `r{char}`
+    Replace(char),
+    /// `~`
+    ToggleCase,
+    /// `s`
+    Substitute,
+    /// `i`, `a`, `I`, `A`
+    Insert(InsertAt),
+    /// `u`
+    Undo,
+    /// `Ctrl+r`
+    Redo,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Delete,
+    Change,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Motion(Motion),
+    Object { inner: bool, big: bool },
+    /// `dd`, `cc`, `S`: the whole line
+    Line,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InsertAt {
+    Cursor,
+    After,
+    LineStart,
+    LineEnd,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Motion {
+    Left,
+    Right,
+    LineStart,
+    FirstNonBlank,
+    LineEnd,
+    WordForward { big: bool },
+    WordBackward { big: bool },
+    WordEnd { big: bool },
+    Find { kind: FindKind, c: char },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FindKind {
+    /// `f`
+    Forward,
+    /// `F`
+    Backward,
+    /// `t`
+    TillForward,
+    /// `T`
+    TillBackward,
+}
+
+impl FindKind {
+    fn reversed(self) -> Self {
+        match self {
+            FindKind::Forward => FindKind::Backward,
+            FindKind::Backward => FindKind::Forward,
+            FindKind::TillForward => FindKind::TillBackward,
+            FindKind::TillBackward => FindKind::TillForward,
+        }
+    }
+}
+
+/// Key expected next to finish a command
+#[derive(Debug, Clone, Copy)]
+enum Awaiting {
+    FindChar(FindKind),
+    ReplaceChar,
+    TextObject { inner: bool },
+}
+
+/// Normal-mode command parser: `[count] [operator [count]] motion | text-object`
+///
+/// Keeps the partial command between keys, so `d` followed by `w` becomes one
+/// `dw` command. Shared by the greeter and onboard normal modes.
+#[derive(Debug, Default)]
+pub struct NormalParser {
+    count: Option<usize>,
+    operator: Option<(Operator, Option<usize>)>,
+    awaiting: Option<Awaiting>,
+    /// Last `f`/`t`/`F`/`T`, repeated by `;` and `,`
+    last_find: Option<(FindKind, char)>,
+}
+
+impl NormalParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether a command has been started but not finished
+    pub fn is_pending(&self) -> bool {
+        self.count.is_some() || self.operator.is_some() || self.awaiting.is_some()
+    }
+
+    /// Drop the partial command
+    pub fn reset(&mut self) {
+        self.count = None;
+        self.operator = None;
+        self.awaiting = None;
+    }
+
+    pub fn feed(&mut self, key: KeyEvent) -> Parsed {
+        if key.modifiers.contains(KeyModifiers::CONTROL)
+            && key.code == KeyCode::Char('r')
+            && self.operator.is_none()
+            && self.awaiting.is_none()
+        {
+            return self.finish(CommandKind::Redo);
+        }
+        if key.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) {
+            return self.abort_or_unhandled();
+        }
+
+        if let Some(awaiting) = self.awaiting.take() {
+            let KeyCode::Char(c) = key.code else {
+                self.reset();
+                return Parsed::Consumed;
+            };
+            return self.finish_awaiting(awaiting, c);
+        }
+
+        let c = match key.code {
+            KeyCode::Char(c) => c,
+            KeyCode::Left => 'h',
+            KeyCode::Right => 'l',
+            KeyCode::Home => '0',
+            KeyCode::End => '$',
+            _ => return self.abort_or_unhandled(),
+        };
+
+        match c {
+            '1'..='9' => {
+                self.push_digit(c);
+                Parsed::Consumed
+            }
+            '0' if self.count.is_some() => {
+                self.push_digit(c);
+                Parsed::Consumed
+            }
+
+            // Motions
+            'h' => self.finish_motion(Motion::Left),
+            'l' => self.finish_motion(Motion::Right),
+            '0' => self.finish_motion(Motion::LineStart),
+            '^' => self.finish_motion(Motion::FirstNonBlank),
+            '$' => self.finish_motion(Motion::LineEnd),
+            'w' | 'W' => self.finish_motion(Motion::WordForward { big: c == 'W' }),
+            'b' | 'B' => self.finish_motion(Motion::WordBackward { big: c == 'B' }),
+            'e' | 'E' => self.finish_motion(Motion::WordEnd { big: c == 'E' }),
+            'f' | 'F' | 't' | 'T' => {
+                let kind = match c {
+                    'f' => FindKind::Forward,
+                    'F' => FindKind::Backward,
+                    't' => FindKind::TillForward,
+                    _ => FindKind::TillBackward,
+                };
+                self.awaiting = Some(Awaiting::FindChar(kind));
+                Parsed::Consumed
+            }
+            ';' | ',' => match self.last_find {
+                Some((kind, target)) => {
+                    let kind = if c == ',' { kind.reversed() } else { kind };
+                    self.finish_motion(Motion::Find { kind, c: target })
+                }
+                None => self.abort(),
+            },
+
+            // Text objects only follow an operator
+            'i' | 'a' if self.operator.is_some() => {
+                self.awaiting = Some(Awaiting::TextObject { inner: c == 'i' });
+                Parsed::Consumed
+            }
+
+            // Operators; doubling one (`dd`, `cc`) applies it to the line
+            'd' | 'c' => {
+                let op = if c == 'd' {
+                    Operator::Delete
+                } else {
+                    Operator::Change
+                };
+                match self.operator {
+                    Some((pending, _)) if pending == op => self.finish(CommandKind::Operate(op, Target::Line)),
+                    Some(_) => self.abort(),
+                    None => {
+                        self.operator = Some((op, self.count.take()));
+                        Parsed::Consumed
+                    }
+                }
+            }
+
+            _ if self.operator.is_some() => self.abort(),
+
+            'x' => self.finish(CommandKind::DeleteChar),
+            'X' => self.finish(CommandKind::DeleteCharBefore),
+            'D' => self.finish(CommandKind::Operate(Operator::Delete, Target::Motion(Motion::LineEnd))),
+            'C' => self.finish(CommandKind::Operate(Operator::Change, Target::Motion(Motion::LineEnd))),
+            'S' => self.finish(CommandKind::Operate(Operator::Change, Target::Line)),
+            's' => self.finish(CommandKind::Substitute),
+            '~' => self.finish(CommandKind::ToggleCase),
+            'r' => {
+                self.awaiting = Some(Awaiting::ReplaceChar);
+                Parsed::Consumed
+            }
+            'i' => self.finish(CommandKind::Insert(InsertAt::Cursor)),
+            'a' => self.finish(CommandKind::Insert(InsertAt::After)),
+            'I' => self.finish(CommandKind::Insert(InsertAt::LineStart)),
+            'A' => self.finish(CommandKind::Insert(InsertAt::LineEnd)),
+            'u' => self.finish(CommandKind::Undo),
+
+            _ => self.abort_or_unhandled(),
+        }
+    }
+
+    fn finish_awaiting(&mut self, awaiting: Awaiting, c: char) -> Parsed {
+        match awaiting {
+            Awaiting::FindChar(kind) => {
+                self.last_find = Some((kind, c));
+                self.finish_motion(Motion::Find { kind, c })
+            }
+            Awaiting::ReplaceChar => self.finish(CommandKind::Replace(c)),
+            Awaiting::TextObject { inner } => match (c, self.operator) {
+                ('w' | 'W', Some((op, _))) => self.finish(CommandKind::Operate(
+                    op,
+                    Target::Object {
+                        inner,
+                        big: c == 'W',
+                    },
+                )),
+                _ => self.abort(),
+            },
+        }
+    }
+
+    fn push_digit(&mut self, c: char) {
+        let digit = c.to_digit(10).unwrap_or(0) as usize;
+        let count = self.count.unwrap_or(0);
+        self.count = Some(count.saturating_mul(10).saturating_add(digit).min(9999));
+    }
+
+    fn finish_motion(&mut self, motion: Motion) -> Parsed {
+        match self.operator {
+            Some((op, _)) => self.finish(CommandKind::Operate(op, Target::Motion(motion))),
+            None => self.finish(CommandKind::Move(motion)),
+        }
+    }
+
+    fn finish(&mut self, kind: CommandKind) -> Parsed {
+        // Counts before and after the operator multiply, as in `2d3w`
+        let outer = self.operator.and_then(|(_, count)| count).unwrap_or(1);
+        let count = outer * self.count.unwrap_or(1);
+        self.reset();
+        Parsed::Command(NormalCommand { count, kind })
+    }
+
+    fn abort(&mut self) -> Parsed {
+        self.reset();
+        Parsed::Consumed
+    }
+
+    fn abort_or_unhandled(&mut self) -> Parsed {
+        if self.is_pending() {
+            self.abort()
+        } else {
+            Parsed::Unhandled
+        }
+    }
+}
+
+impl NormalCommand {
+    /// Whether the app should switch to insert mode after applying the command
+    pub fn enters_insert(&self) -> bool {
+        matches!(
+            self.kind,
+            CommandKind::Insert(_) | CommandKind::Substitute | CommandKind::Operate(Operator::Change, _)
+        )
+    }
+
+    /// Apply the command as one undo step. Commands that enter insert mode leave
+    /// the step open so the text typed afterwards is undone together with them.
+    pub fn apply(&self, buffer: &mut InputBuffer) {
+        buffer.checkpoint();
+        self.edit(buffer);
+        if !self.enters_insert() {
+            buffer.checkpoint();
+        }
+    }
+
+    fn edit(&self, buffer: &mut InputBuffer) {
+        let chars: Vec<char> = buffer.content().chars().collect();
+        let len = chars.len();
+        let cursor = buffer.cursor();
+        let count = self.count;
+
+        match self.kind {
+            CommandKind::Move(motion) => {
+                if let Some((target, _)) = motion.target(&chars, cursor, count) {
+                    buffer.set_cursor(target);
+                }
+            }
+            CommandKind::Operate(op, target) => {
+                let range = match target {
+                    Target::Line => Some((0, len)),
+                    Target::Object { inner, big } => text_object(&chars, cursor, inner, big, count),
+                    Target::Motion(motion) => {
+                        // `cw` changes to the end of the word, like `ce`
+                        let motion = match (op, motion) {
+                            (Operator::Change, Motion::WordForward { big })
+                                if chars.get(cursor).is_some_and(|c| !c.is_whitespace()) =>
+                            {
+                                Motion::WordEnd { big }
+                            }
+                            _ => motion,
+                        };
+                        motion.target(&chars, cursor, count).map(|(target, inclusive)| {
+                            if target < cursor {
+                                (target, cursor)
+                            } else {
+                                (cursor, if inclusive { target + 1 } else { target })
+                            }
+                        })
+                    }
+                };
+                if let Some((start, end)) = range {
+                    buffer.delete_range(start, end);
+                }
+            }
+            CommandKind::DeleteChar | CommandKind::Substitute => {
+                buffer.delete_range(cursor, cursor + count);
+            }
+            CommandKind::DeleteCharBefore => {
+                buffer.delete_range(cursor.saturating_sub(count), cursor);
+            }
+            CommandKind::Replace(c) => {
+                // Like vim, fail when there aren't enough characters
+                if cursor + count <= len {
+                    for pos in cursor..cursor + count {
+                        buffer.replace_char(pos, c);
+                    }
+                    buffer.set_cursor(cursor + count - 1);
+                }
+            }
+            CommandKind::ToggleCase => {
+                let end = (cursor + count).min(len);
+                for (pos, c) in chars.iter().enumerate().take(end).skip(cursor) {
+                    let toggled = if c.is_uppercase() {
+                        c.to_lowercase().next()
+                    } else {
+                        c.to_uppercase().next()
+                    };
+                    if let Some(toggled) = toggled {
+                        buffer.replace_char(pos, toggled);
+                    }
+                }
+                buffer.set_cursor(end);
+            }
+            CommandKind::Insert(at) => match at {
+                InsertAt::Cursor => {}
+                InsertAt::After => buffer.move_right(),
+                InsertAt::LineStart => buffer.set_cursor(first_non_blank(&chars)),
+                InsertAt::LineEnd => buffer.move_end(),
+            },
+            CommandKind::Undo => {
+                for _ in 0..count {
+                    if !buffer.undo() {
+                        break;
+                    }
+                }
+            }
+            CommandKind::Redo => {
+                for _ in 0..count {
+                    if !buffer.redo() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Motion {
+    /// Where the motion lands and whether an operator includes that character
+    fn target(self, chars: &[char], cursor: usize, count: usize) -> Option<(usize, bool)> {
+        let len = chars.len();
+        let target = match self {
+            Motion::Left => return Some((cursor.saturating_sub(count), false)),
+            Motion::Right => return Some(((cursor + count).min(len), false)),
+            Motion::LineStart => return Some((0, false)),
+            Motion::FirstNonBlank => return Some((first_non_blank(chars), false)),
+            Motion::LineEnd => return Some((len, false)),
+            Motion::WordForward { big } => {
+                let mut pos = cursor;
+                for _ in 0..count {
+                    pos = next_word_start(chars, pos, big);
+                }
+                return Some((pos, false));
+            }
+            Motion::WordBackward { big } => {
+                let mut pos = cursor;
+                for _ in 0..count {
+                    pos = prev_word_start(chars, pos, big);
+                }
+                return Some((pos, false));
+            }
+            Motion::WordEnd { big } => {
+                let mut pos = cursor;
+                for _ in 0..count {
+                    pos = word_end(chars, pos, big);
+                }
+                pos
+            }
+            Motion::Find { kind, c } => find(chars, cursor, kind, c, count)?,
+        };
+        let inclusive = matches!(
+            self,
+            Motion::WordEnd { .. }
+                | Motion::Find {
+                    kind: FindKind::Forward | FindKind::TillForward,
+                    ..
+                }
+        );
+        Some((target, inclusive))
+    }
+}
+
+/// 0 for whitespace, 1 for word characters, 2 for punctuation (WORDs don't split on it)
+fn class(c: char, big: bool) -> u8 {
+    if c.is_whitespace() {
+        0
+    } else if big || c.is_alphanumeric() || c == '_' {
+        1
+    } else {
+        2
+    }
+}
+
+fn first_non_blank(chars: &[char]) -> usize {
+    chars
+        .iter()
+        .position(|c| !c.is_whitespace())
+        .unwrap_or(chars.len())
+}
+
+fn next_word_start(chars: &[char], pos: usize, big: bool) -> usize {
+    let len = chars.len();
+    let mut i = pos;
+    if i >= len {
+        return len;
+    }
+    let start_class = class(chars[i], big);
+    if start_class != 0 {
+        while i < len && class(chars[i], big) == start_class {
+            i += 1;
+        }
+    }
+    while i < len && class(chars[i], big) == 0 {
+        i += 1;
+    }
+    i
+}
+
+fn prev_word_start(chars: &[char], pos: usize, big: bool) -> usize {
+    let mut i = pos.min(chars.len());
+    while i > 0 && class(chars[i - 1], big) == 0 {
+        i -= 1;
+    }
+    if i == 0 {
+        return 0;
+    }
+    let word_class = class(chars[i - 1], big);
+    while i > 0 && class(chars[i - 1], big) == word_class {
+        i -= 1;
+    }
+    i
+}
+
+fn word_end(chars: &[char], pos: usize, big: bool) -> usize {
+    let len = chars.len();
+    if len == 0 {
+        return 0;
+    }
+    let mut i = pos + 1;
+    while i < len && class(chars[i], big) == 0 {
+        i += 1;
+    }
+    if i >= len {
+        return len - 1;
+    }
+    let word_class = class(chars[i], big);
+    while i + 1 < len && class(chars[i + 1], big) == word_class {
+        i += 1;
+    }
+    i
+}
+
+fn find(chars: &[char], cursor: usize, kind: FindKind, c: char, count: usize) -> Option<usize> {
+    match kind {
+        FindKind::Forward | FindKind::TillForward => {
+            let found = chars
+                .iter()
+                .enumerate()
+                .skip(cursor + 1)
+                .filter(|(_, ch)| **ch == c)
+                .nth(count - 1)?
+                .0;
+            Some(if kind == FindKind::TillForward { found - 1 } else { found })
+        }
+        FindKind::Backward | FindKind::TillBackward => {
+            let found = chars[..cursor.min(chars.len())]
+                .iter()
+                .enumerate()
+                .rev()
+                .filter(|(_, ch)| **ch == c)
+                .nth(count - 1)?
+                .0;
+            Some(if kind == FindKind::TillBackward { found + 1 } else { found })
+        }
+    }
+}
+
+/// Range of `iw`/`aw` (or `iW`/`aW`) around the cursor
+fn text_object(chars: &[char], cursor: usize, inner: bool, big: bool, count: usize) -> Option<(usize, usize)> {
+    let len = chars.len();
+    if len == 0 {
+        return None;
+    }
+    let pos = cursor.min(len - 1);
+
+    // Start and end of the run of same-class characters containing `i`
+    let run_start = |i: usize| {
+        let run_class = class(chars[i], big);
+        let mut start = i;
+        while start > 0 && class(chars[start - 1], big) == run_class {
+            start -= 1;
+        }
+        start
+    };
+    let run_end = |i: usize| {
+        let run_class = class(chars[i], big);
+        let mut end = i;
+        while end < len && class(chars[end], big) == run_class {
+            end += 1;
+        }
+        end
+    };
+    let is_space = |i: usize| class(chars[i], big) == 0;
+
+    let mut start = run_start(pos);
+    let mut end = run_end(pos);
+
+    if inner {
+        for _ in 1..count {
+            if end >= len {
+                break;
+            }
+            end = run_end(end);
+        }
+        return Some((start, end));
+    }
+
+    if is_space(pos) {
+        // Whitespace plus the following word
+        if end < len {
+            end = run_end(end);
+        }
+    } else if end < len && is_space(end) {
+        end = run_end(end);
+    } else {
+        // No trailing whitespace: take the leading whitespace instead
+        while start > 0 && is_space(start - 1) {
+            start -= 1;
+        }
+    }
+    for _ in 1..count {
+        if end >= len {
+            break;
+        }
+        end = run_end(end);
+        if end < len && is_space(end) {
+            end = run_end(end);
+        }
+    }
+    Some((start, end))
+}
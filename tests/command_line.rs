@@ -0,0 +1,104 @@
+use hypercube_utils::vim::{command_names, parse_command, CommandLine, COMMANDS};
+
+fn type_line(line: &mut CommandLine, text: &str) {
+    for c in text.chars() {
+        line.insert(c);
+    }
+}
+
+fn sessions(cmd: &str) -> Vec<String> {
+    match cmd {
+        "session" | "s" => vec!["sway".into(), "niri".into(), "Sway (debug)".into()],
+        _ => Vec::new(),
+    }
+}
+
+#[test]
+fn command_names_parse() {
+    for name in command_names(COMMANDS) {
+        assert!(parse_command(name).is_ok(), "{name} is not a command");
+    }
+}
+
+#[test]
+fn aliases_parse_like_their_command() {
+    for spec in COMMANDS {
+        for alias in spec.aliases {
+            assert_eq!(
+                parse_command(&format!("{alias} x")).unwrap(),
+                parse_command(&format!("{} x", spec.name)).unwrap(),
+                "{alias}"
+            );
+        }
+    }
+    assert!(parse_command("sess").is_err());
+}
+
+#[test]
+fn history_recall() {
+    let mut line = CommandLine::new();
+    for cmd in ["user alice", "session sway", "session sway", "help"] {
+        type_line(&mut line, cmd);
+        line.submit();
+    }
+    assert_eq!(line.history(), ["user alice", "session sway", "help"]);
+
+    line.history_prev();
+    assert_eq!(line.content(), "help");
+    line.history_prev();
+    assert_eq!(line.content(), "session sway");
+    line.history_next();
+    line.history_next();
+    assert_eq!(line.content(), "");
+
+    // Typed text filters the recalled entries
+    type_line(&mut line, "u");
+    line.history_prev();
+    assert_eq!(line.content(), "user alice");
+    line.history_prev();
+    assert_eq!(line.content(), "user alice");
+    line.history_next();
+    assert_eq!(line.content(), "u");
+}
+
+#[test]
+fn completes_command_names() {
+    let mut line = CommandLine::new();
+    type_line(&mut line, "s");
+    line.complete(&command_names(COMMANDS), sessions, true);
+    assert_eq!(line.content(), "session");
+    let completion = line.completion().expect("wildmenu is shown");
+    assert_eq!(completion.candidates, ["session", "shutdown"]);
+    assert_eq!(completion.selected, Some(0));
+
+    line.complete(&command_names(COMMANDS), sessions, true);
+    assert_eq!(line.content(), "shutdown");
+    // Cycling past the last candidate restores the typed text
+    line.complete(&command_names(COMMANDS), sessions, true);
+    assert_eq!(line.content(), "s");
+    line.complete(&command_names(COMMANDS), sessions, false);
+    assert_eq!(line.content(), "shutdown");
+
+    line.insert(' ');
+    assert!(line.completion().is_none());
+}
+
+#[test]
+fn completes_arguments() {
+    let mut line = CommandLine::new();
+    type_line(&mut line, "s  S");
+    line.complete(&command_names(COMMANDS), sessions, true);
+    assert_eq!(line.content(), "s  sway");
+    assert_eq!(
+        line.completion().unwrap().candidates,
+        ["sway", "Sway (debug)"]
+    );
+    line.complete(&command_names(COMMANDS), sessions, true);
+    assert_eq!(line.content(), "s  Sway (debug)");
+
+    line.clear();
+    type_line(&mut line, "help x");
+    line.complete(&command_names(COMMANDS), sessions, true);
+    assert_eq!(line.content(), "help x");
+    assert!(line.completion().is_none());
+}
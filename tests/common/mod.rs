@@ -0,0 +1,162 @@
+//! Test backend shared by the onboard integration tests
+#![allow(dead_code)]
+
+use hypercube_utils::onboard::{OnboardError, OnboardService, Password, Secret, ServiceOp};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Backend that records what it was asked to do and fails on request
+///
+/// Commands are keyed by their program name and `set_locale` by `locale`.
+#[derive(Default)]
+pub struct RecordingService {
+    /// How each command was run, e.g. `root: dnf upgrade -y`
+    runs: Mutex<Vec<String>>,
+    /// `start <key>` and `end <key>` for every attempt, in order
+    events: Mutex<Vec<String>>,
+    running: Mutex<Vec<String>>,
+    most_running: Mutex<usize>,
+    /// Remaining failures per key
+    failures: Mutex<HashMap<String, u32>>,
+    users: Mutex<Vec<String>>,
+    /// `useradd <user>` and `chpasswd <user>`, in order
+    accounts: Mutex<Vec<String>>,
+    /// How long each command takes
+    delay: Duration,
+}
+
+impl RecordingService {
+    /// A backend whose commands each take `delay`, so overlapping runs show up
+    pub fn with_delay(delay: Duration) -> Self {
+        Self { delay, ..Self::default() }
+    }
+
+    /// Fail the next `times` attempts of `key`
+    pub fn fail(&self, key: &str, times: u32) {
+        self.failures.lock().unwrap().insert(key.to_string(), times);
+    }
+
+    /// Pretend `username` already has an account
+    pub fn add_user(&self, username: &str) {
+        self.users.lock().unwrap().push(username.to_string());
+    }
+
+    pub fn runs(&self) -> Vec<String> {
+        self.runs.lock().unwrap().clone()
+    }
+
+    pub fn accounts(&self) -> Vec<String> {
+        self.accounts.lock().unwrap().clone()
+    }
+
+    pub fn events(&self) -> Vec<String> {
+        self.events.lock().unwrap().clone()
+    }
+
+    pub fn position(&self, event: &str) -> usize {
+        self.events().iter().position(|e| e == event).unwrap()
+    }
+
+    /// How many times `key` was attempted
+    pub fn attempts(&self, key: &str) -> usize {
+        let start = format!("start {key}");
+        self.events().iter().filter(|e| **e == start).count()
+    }
+
+    pub fn most_running(&self) -> usize {
+        *self.most_running.lock().unwrap()
+    }
+
+    fn attempt(&self, key: &str) -> Result<(), OnboardError> {
+        {
+            let mut running = self.running.lock().unwrap();
+            running.push(key.to_string());
+            let mut most = self.most_running.lock().unwrap();
+            *most = (*most).max(running.len());
+            self.events.lock().unwrap().push(format!("start {key}"));
+        }
+        std::thread::sleep(self.delay);
+        {
+            self.running.lock().unwrap().retain(|r| r != key);
+            self.events.lock().unwrap().push(format!("end {key}"));
+        }
+        let mut failures = self.failures.lock().unwrap();
+        match failures.get_mut(key) {
+            Some(left) if *left > 0 => {
+                *left -= 1;
+                Err(OnboardError::Exited { code: Some(1), output: format!("{key} broke") })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn run(&self, run: String, cmd: &[String], on_line: &dyn Fn(&str)) -> Result<String, OnboardError> {
+        self.runs.lock().unwrap().push(run);
+        on_line(&format!("running {}", cmd[0]));
+        self.attempt(&cmd[0]).map(|()| String::new())
+    }
+}
+
+impl OnboardService for RecordingService {
+    fn check_network(&self) -> bool {
+        true
+    }
+    fn list_locales(&self) -> Vec<String> {
+        Vec::new()
+    }
+    fn list_keymaps(&self) -> Vec<String> {
+        Vec::new()
+    }
+    fn list_timezones(&self) -> Vec<String> {
+        Vec::new()
+    }
+    fn user_exists(&self, username: &str) -> bool {
+        self.users.lock().unwrap().iter().any(|u| u == username)
+    }
+    fn machine_serial(&self) -> Option<String> {
+        None
+    }
+    fn create_user(&self, username: &str, _: &Password, _: &[String], _: &str) -> Result<(), OnboardError> {
+        self.accounts.lock().unwrap().push(format!("useradd {username}"));
+        self.add_user(username);
+        Ok(())
+    }
+    fn set_password(&self, username: &str, _: &Password) -> Result<(), OnboardError> {
+        self.accounts.lock().unwrap().push(format!("chpasswd {username}"));
+        Ok(())
+    }
+    fn set_locale(&self, _locale: &str) -> Result<(), OnboardError> {
+        self.attempt("locale")
+    }
+    fn set_keymap(&self, _keymap: &str) -> Result<(), OnboardError> {
+        Ok(())
+    }
+    fn set_timezone(&self, _timezone: &str) -> Result<(), OnboardError> {
+        Ok(())
+    }
+    fn set_hostname(&self, _hostname: &str, _pretty: Option<&str>) -> Result<(), OnboardError> {
+        Ok(())
+    }
+    fn set_ntp(&self, _enabled: bool) -> Result<(), OnboardError> {
+        Ok(())
+    }
+    fn create_keyring(&self, _: &str, _: &Secret) -> Result<(), OnboardError> {
+        Ok(())
+    }
+    fn run_command_as_user(&self, user: &str, cmd: &[String], on_line: &dyn Fn(&str)) -> Result<String, OnboardError> {
+        self.run(format!("{user}: {}", cmd.join(" ")), cmd, on_line)
+    }
+    fn run_command_as_root(&self, cmd: &[String], on_line: &dyn Fn(&str)) -> Result<String, OnboardError> {
+        self.run(format!("root: {}", cmd.join(" ")), cmd, on_line)
+    }
+    fn run_command_as_user_with_sudo(&self, user: &str, cmd: &[String], password: &Secret, on_line: &dyn Fn(&str)) -> Result<String, OnboardError> {
+        self.run(format!("{user} via sudo ({}): {}", password.expose(), cmd.join(" ")), cmd, on_line)
+    }
+    fn remove_initial_session(&self) -> Result<(), OnboardError> {
+        Ok(())
+    }
+    fn command_string(&self, _op: &ServiceOp) -> String {
+        String::new()
+    }
+}
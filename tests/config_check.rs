@@ -0,0 +1,127 @@
+//! `check-config`: parse errors with positions, unknown keys and settings that cannot work
+
+use hypercube_utils::onboard::{check_file, check_str, ConfigProblem, OnboardConfig};
+
+fn problems(content: &str) -> Vec<String> {
+    check_str(content).err().unwrap_or_default().iter().map(ConfigProblem::to_string).collect()
+}
+
+#[test]
+fn shipped_examples_pass() {
+    for name in ["demo", "steps"] {
+        let path = format!("{}/examples/{name}.toml", env!("CARGO_MANIFEST_DIR"));
+        assert!(check_file(&path).is_ok(), "{path}: {:?}", check_file(&path).err());
+    }
+}
+
+#[test]
+fn syntax_errors_and_unknown_keys_have_positions() {
+    assert_eq!(
+        problems("[general]\ntitle = \"Setup\"\ndryrun = tru\n"),
+        ["3:10: invalid string\nexpected `\"`, `'`"]
+    );
+    assert_eq!(
+        problems("[locale]\nenabled = true\n  langauge = \"de\"\n"),
+        ["3:3: unknown key `locale.langauge`"]
+    );
+    assert_eq!(
+        problems("[[updates]]\nname = \"System\"\n[[updates.packages]]\ntitle = \"Vim\"\ncommands = []\nrequried = true\n"),
+        ["6:1: unknown key `updates[0].packages[0].requried`", r#"package "Vim" in "System": commands is empty"#]
+    );
+
+    // Custom steps, down to their options
+    let step = "[[steps]]\nid = \"de\"\ntitle = \"Desktop\"\nkind = \"choice\"\ncolour = \"red\"\n\
+                options = [{ value = \"gnome\", lable = \"GNOME\" }]\n";
+    assert_eq!(
+        problems(step),
+        ["5:1: unknown key `steps[0].colour`", "6:31: unknown key `steps[0].options[0].lable`"]
+    );
+}
+
+#[test]
+fn plain_loading_ignores_unknown_keys() {
+    let path = std::env::temp_dir().join(format!("hypercube-config-unknown-{}.toml", std::process::id()));
+    std::fs::write(&path, "[general]
+titel = \"Acme\"
+parallel_installs = 3
+").unwrap();
+    let plain = OnboardConfig::load_from(&path);
+    let strict = OnboardConfig::load_strict(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(plain.unwrap().general.parallel_installs, 3);
+    let err = strict.unwrap_err().to_string();
+    assert!(err.ends_with(".toml:2:1: unknown key `general.titel`"), "{err}");
+}
+
+#[test]
+fn settings_that_cannot_work_are_listed() {
+    let config = r#"
+[locale]
+default_locale = "de_DE.UTF-8"
+available = ["en_US.UTF-8", "fr_FR.UTF-8"]
+
+[completion]
+action = "shutdown"
+
+[[updates]]
+name = "System"
+
+[[updates.packages]]
+title = "Updates"
+commands = []
+
+[[updates.packages]]
+title = "Firmware"
+[[updates.packages.commands]]
+name = "Refresh"
+command = []
+[[updates.packages.commands]]
+name = "Update"
+command = ["fwupdmgr", "update"]
+sudo = true
+[[updates.packages.commands]]
+name = "Database"
+command = ["sudo", "-u", "postgres", "psql"]
+sudo = true
+
+[[updates]]
+name = "Apps"
+
+[[updates.packages]]
+title = "Updates"
+depends_on = ["Flathub"]
+[[updates.packages.commands]]
+name = "Flatpak"
+command = ["flatpak", "update", "-y"]
+"#;
+    assert_eq!(
+        problems(config),
+        [
+            r#"locale.default_locale "de_DE.UTF-8" is not in locale.available"#,
+            r#"completion.action "shutdown" is not one of reboot, poweroff, exit"#,
+            r#"package "Updates" in "System": commands is empty"#,
+            r#"package "Firmware" in "System": command "Refresh" is empty"#,
+            r#"package "Firmware" in "System": command "Update" has sudo = true but does not start with sudo"#,
+            r#"package "Firmware" in "System": command "Database" passes options to sudo, which are not supported"#,
+            r#"package "Updates" in "Apps": another package has the same title"#,
+            r#"package "Updates" in "Apps": depends_on "Flathub" is not a package"#,
+        ]
+    );
+}
+
+#[test]
+fn strict_loading_refuses_problems_that_plain_loading_accepts() {
+    let path = std::env::temp_dir().join(format!("hypercube-config-check-{}.toml", std::process::id()));
+    std::fs::write(&path, "[completion]\naction = \"halt\"\n").unwrap();
+    let plain = OnboardConfig::load_from(&path);
+    let strict = OnboardConfig::load_strict(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(plain.unwrap().completion.action, "halt");
+    let err = strict.unwrap_err().to_string();
+    assert!(err.ends_with(".toml: completion.action \"halt\" is not one of reboot, poweroff, exit"), "{err}");
+
+    // A missing file is not silently the default config
+    assert!(OnboardConfig::load_strict(&path).is_err());
+}
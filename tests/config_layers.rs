@@ -0,0 +1,164 @@
+//! Vendor config, admin config and drop-ins merged into one
+
+use hypercube_utils::greeter::GreeterConfig;
+use hypercube_utils::layers::layer_paths;
+use hypercube_utils::onboard::{check_layers, ConfigProblem, OnboardConfig};
+use std::path::{Path, PathBuf};
+
+/// A scratch vendor and admin directory pair
+struct Dirs {
+    root: PathBuf,
+}
+
+impl Dirs {
+    fn new(name: &str) -> Self {
+        let root = std::env::temp_dir().join(format!("hypercube-layers-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        for dir in ["vendor", "admin/onboard.d"] {
+            std::fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        Self { root }
+    }
+
+    fn write(&self, path: &str, content: &str) -> &Self {
+        std::fs::write(self.root.join(path), content).unwrap();
+        self
+    }
+
+    fn paths(&self, name: &str) -> Vec<PathBuf> {
+        layer_paths(&self.root.join("vendor"), &self.root.join("admin"), name)
+    }
+}
+
+impl Drop for Dirs {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.root);
+    }
+}
+
+const VENDOR: &str = r#"
+[general]
+title = "Hypercube Setup"
+subtitle = "Welcome"
+
+[[updates]]
+name = "System"
+enabled_by_default = true
+
+[[updates.packages]]
+title = "Updates"
+[[updates.packages.commands]]
+name = "Upgrade"
+command = ["sudo", "dnf", "upgrade", "-y"]
+sudo = true
+
+[[updates.packages]]
+title = "Firmware"
+[[updates.packages.commands]]
+name = "Firmware"
+command = ["fwupdmgr", "update"]
+"#;
+
+fn names(paths: &[PathBuf], root: &Path) -> Vec<String> {
+    paths.iter().map(|p| p.strip_prefix(root).unwrap().display().to_string()).collect()
+}
+
+#[test]
+fn files_are_found_in_priority_order() {
+    let dirs = Dirs::new("order");
+    dirs.write("vendor/onboard.toml", "")
+        .write("admin/onboard.d/50-oem.toml", "")
+        .write("admin/onboard.d/10-spin.toml", "")
+        .write("admin/onboard.d/README", "")
+        .write("admin/onboard.toml", "");
+
+    assert_eq!(
+        names(&dirs.paths("onboard"), &dirs.root),
+        ["vendor/onboard.toml", "admin/onboard.toml", "admin/onboard.d/10-spin.toml", "admin/onboard.d/50-oem.toml"]
+    );
+    // Each binary has its own files
+    assert!(dirs.paths("greeter").is_empty());
+}
+
+#[test]
+fn later_layers_override_keys_and_match_updates_by_name() {
+    let dirs = Dirs::new("merge");
+    dirs.write("vendor/onboard.toml", VENDOR)
+        .write("admin/onboard.toml", "[general]\ntitle = \"Acme Setup\"\n")
+        // An OEM adds a category, turns one off and swaps a package's commands
+        .write(
+            "admin/onboard.d/10-oem.toml",
+            r#"
+[[updates]]
+name = "Acme Tools"
+[[updates.packages]]
+title = "Acme Support"
+[[updates.packages.commands]]
+name = "Install"
+command = ["flatpak", "install", "-y", "com.acme.Support"]
+
+[[updates]]
+name = "System"
+enabled_by_default = false
+[[updates.packages]]
+title = "Firmware"
+commands = [{ name = "Firmware", command = ["sudo", "fwupdmgr", "update"], sudo = true }]
+"#,
+        )
+        .write("admin/onboard.d/20-title.toml", "[general]\ntitle = \"Acme Workstation\"\n");
+
+    let config: OnboardConfig = OnboardConfig::read_layers(&dirs.paths("onboard")).unwrap().deserialize().unwrap();
+    assert_eq!(config.general.title, "Acme Workstation");
+    assert_eq!(config.general.subtitle, "Welcome");
+
+    let categories: Vec<(&str, bool)> = config.updates.iter().map(|c| (c.name.as_str(), c.enabled_by_default)).collect();
+    assert_eq!(categories, [("System", false), ("Acme Tools", false)]);
+    let system = &config.updates[0];
+    let packages: Vec<&str> = system.packages.iter().map(|p| p.title.as_str()).collect();
+    assert_eq!(packages, ["Updates", "Firmware"]);
+    assert_eq!(system.packages[1].commands[0].command, ["sudo", "fwupdmgr", "update"]);
+    assert!(system.packages[1].commands[0].sudo);
+}
+
+#[test]
+fn errors_name_the_layer() {
+    let dirs = Dirs::new("errors");
+    dirs.write("vendor/onboard.toml", VENDOR)
+        .write("admin/onboard.d/10-typo.toml", "[general]\ntitle = \"Acme\n");
+    let err = OnboardConfig::read_layers(&dirs.paths("onboard")).unwrap_err().to_string();
+    assert!(err.contains("onboard.d/10-typo.toml:2:14: invalid basic string"), "{err}");
+
+    // Unknown keys are ignored when loading and only reported by a check
+    dirs.write("admin/onboard.d/10-typo.toml", "[general]\ntitel = \"Acme\"\n");
+    let layers = OnboardConfig::read_layers(&dirs.paths("onboard")).unwrap();
+    assert!(layers.deserialize::<OnboardConfig>().is_ok());
+    let problems = check_layers(&layers).unwrap_err();
+    assert_eq!(problems, [ConfigProblem { position: None, message: "unknown key `general.titel`".into() }]);
+}
+
+#[test]
+fn greeter_config_layers_the_same_way() {
+    let dirs = Dirs::new("greeter");
+    std::fs::create_dir_all(dirs.root.join("admin/greeter.d")).unwrap();
+    dirs.write("vendor/greeter.toml", "[general]\ngreeting = \"Hypercube\"\n[users]\nhidden = [\"nobody\"]\n")
+        .write("admin/greeter.d/oem.toml", "[users]\nhidden = [\"oem\"]\n");
+
+    let layers = GreeterConfig::read_layers(&dirs.paths("greeter")).unwrap();
+    let config: GreeterConfig = layers.deserialize().unwrap();
+    assert_eq!(config.general.greeting, "Hypercube");
+    // Plain arrays are replaced, not appended
+    assert_eq!(config.users.hidden, ["oem"]);
+    assert!(layers.to_toml().starts_with("# Merged from:\n#   "));
+}
+
+#[test]
+fn a_zero_greetd_timeout_becomes_one_second() {
+    let dirs = Dirs::new("greeter-timeout");
+    dirs.write("admin/greeter.toml", "[greetd]\ntimeout_secs = 0\n[general]\ngreeting = \"Hypercube\"\n");
+
+    // The rest of the config still loads
+    let layers = GreeterConfig::read_layers(&dirs.paths("greeter")).unwrap();
+    let config = layers.deserialize::<GreeterConfig>().unwrap();
+    assert_eq!(config.greetd.timeout_secs, 1);
+    assert_eq!(config.general.greeting, "Hypercube");
+}
@@ -0,0 +1,169 @@
+//! `[[steps]]` from the config: placement, answers and command placeholders
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use hypercube_utils::onboard::{
+    Answers, OnboardAction, OnboardApp, OnboardConfig, OnboardState, StepId, StepResult,
+};
+
+fn steps_app() -> OnboardApp {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/steps.toml");
+    OnboardApp::new(OnboardConfig::load_from(path).unwrap())
+}
+
+fn press(app: &mut OnboardApp, script: &str) {
+    for c in script.chars() {
+        let key = match c {
+            '\n' => KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE),
+            '\x1b' => KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE),
+            c => KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE),
+        };
+        // Forms are submitted by the main loop
+        if let Some(OnboardAction::ExecuteStep) = app.handle_key(key) {
+            app.start_step_execution();
+        }
+    }
+}
+
+fn open(app: &mut OnboardApp, name: &str) {
+    press(app, &format!("\x1b:goto {name}\n"));
+}
+
+fn result(app: &OnboardApp, name: &str) -> StepResult {
+    let idx = app.menu_items.iter().position(|item| app.step_name(item.id) == name).unwrap();
+    app.step_results[idx]
+}
+
+fn commands(app: &mut OnboardApp) -> Vec<String> {
+    app.start_update_execution();
+    app.tasks.iter().filter_map(|t| t.command.clone()).collect()
+}
+
+#[test]
+fn steps_slot_in_after_their_anchor_or_before_review() {
+    let app = steps_app();
+    let names: Vec<&str> = app.menu_items.iter().map(|item| app.step_name(item.id)).collect();
+    assert_eq!(
+        names,
+        [
+            "User", "License", "Desktop", "Host", "Locale", "Keyboard", "Prefs", "Extras", "Enroll",
+            "Review", "Update", "Reboot",
+        ]
+    );
+    assert!(app.menu_items[1].required);
+}
+
+#[test]
+fn unvisited_steps_hand_their_defaults_to_commands() {
+    let mut app = steps_app();
+    assert_eq!(
+        commands(&mut app),
+        [
+            "spin-session set gnome (as root)",
+            "flatpak install -y flathub org.inkscape.Inkscape",
+            "mdm-enroll --server=mdm.example.com  (as root)",
+        ]
+    );
+}
+
+#[test]
+fn choices_and_fields_fill_in_commands() {
+    let mut app = steps_app();
+    press(&mut app, "\n");
+
+    open(&mut app, "desktop");
+    press(&mut app, "\x1bj\n");
+    assert_eq!(result(&app, "Desktop"), StepResult::Completed);
+
+    // Everything but Inkscape
+    open(&mut app, "extras");
+    press(&mut app, " j j \n");
+    assert_eq!(result(&app, "Extras"), StepResult::Completed);
+
+    open(&mut app, "enroll");
+    press(&mut app, "\nnobody\n\n");
+    assert_eq!(app.message.as_ref().unwrap().text, "Enter an email address");
+    assert_eq!(result(&app, "Enroll"), StepResult::Pending);
+    press(&mut app, "ccops@example.org\n\n");
+    assert_eq!(result(&app, "Enroll"), StepResult::Completed);
+
+    assert_eq!(
+        commands(&mut app),
+        [
+            "spin-session set plasma (as root)",
+            "flatpak install -y flathub org.gimp.GIMP org.blender.Blender",
+            "mdm-enroll --server=mdm.example.com ops@example.org (as root)",
+        ]
+    );
+}
+
+#[test]
+fn review_waits_for_required_steps() {
+    let mut app = steps_app();
+    press(&mut app, "\n\nlab\nchangeme1\nchangeme1\n");
+    assert_eq!(result(&app, "User"), StepResult::Completed);
+
+    assert!(app.start_review_execution().is_none());
+    assert_eq!(app.message.as_ref().unwrap().text, "Complete the License step first");
+
+    // The info page is now open; Enter accepts it
+    assert_eq!(app.current_step_id(), Some(StepId::Custom(0)));
+    press(&mut app, "\n");
+    assert_eq!(result(&app, "License"), StepResult::Completed);
+    app.start_review_execution();
+    assert!(app.is_executing);
+}
+
+#[test]
+fn answer_files_fill_in_steps() {
+    let answers: Answers = toml::from_str(
+        r#"
+        [steps]
+        eula = true
+        desktop = "niri"
+        extras = []
+        mdm = { email = "ops@example.org", server = "mdm.internal" }
+        "#,
+    )
+    .unwrap();
+    let mut app = steps_app();
+    app.apply_answers(&answers).unwrap();
+
+    assert_eq!(result(&app, "License"), StepResult::Completed);
+    assert_eq!(
+        commands(&mut app),
+        [
+            "spin-session set niri (as root)",
+            "flatpak install -y flathub",
+            "mdm-enroll --server=mdm.internal ops@example.org (as root)",
+        ]
+    );
+
+    for (answers, error) in [
+        ("[steps]\ndesktop = \"cde\"", "steps.desktop: unknown option: cde"),
+        ("[steps]\nmdm = { email = \"nope\" }", "steps.mdm: Enter an email address"),
+        ("[steps]\neula = \"yes\"", "steps.eula: expected true"),
+        ("[steps]\nmystery = true", "unknown step: mystery"),
+    ] {
+        let answers: Answers = toml::from_str(answers).unwrap();
+        let err = steps_app().apply_answers(&answers).unwrap_err();
+        assert!(err.to_string().ends_with(error), "{err}");
+    }
+}
+
+#[test]
+fn custom_step_progress_is_saved() {
+    let mut app = steps_app();
+    press(&mut app, "\n");
+    open(&mut app, "desktop");
+    press(&mut app, "\x1bjj\n");
+    let state = app.snapshot_state();
+
+    let path = std::env::temp_dir().join(format!("hypercube-custom-steps-{}.toml", std::process::id()));
+    state.save(&path).unwrap();
+    let loaded = OnboardState::load(&path);
+    OnboardState::clear(&path);
+
+    assert_eq!(loaded, state);
+    assert_eq!(loaded.result_for(StepId::Custom(1)), Some(StepResult::Completed));
+    assert_eq!(loaded.step_values["desktop"], ["niri"]);
+}
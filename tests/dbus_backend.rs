@@ -0,0 +1,101 @@
+//! The D-Bus backend against stand-in systemd services on a private bus
+
+use hypercube_utils::onboard::dbus::mock::{MockBus, MockSystemd};
+use hypercube_utils::onboard::{DbusService, OnboardError, OnboardService};
+
+/// Set to skip these tests where `dbus-daemon` is not installed, rather than fail them
+const SKIP_VAR: &str = "HYPERCUBE_SKIP_DBUS_TESTS";
+
+/// The bus, or `None` when it cannot start and [`SKIP_VAR`] is set
+fn start(script: MockSystemd) -> Option<(MockBus, DbusService)> {
+    match MockBus::start(script) {
+        Ok(bus) => {
+            let service = DbusService::new(bus.connect().expect("connect to mock bus"));
+            Some((bus, service))
+        }
+        Err(e) if std::env::var_os(SKIP_VAR).is_some() => {
+            eprintln!("mock bus not started ({e}), skipping as {SKIP_VAR} is set");
+            None
+        }
+        Err(OnboardError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+            panic!("dbus-daemon not found; install it or set {SKIP_VAR}=1 to skip these tests")
+        }
+        Err(e) => panic!("start mock bus: {e}"),
+    }
+}
+
+#[test]
+fn settings_reach_the_services() {
+    let Some((bus, service)) = start(MockSystemd::default()) else { return };
+
+    service.set_locale("de_DE.UTF-8").unwrap();
+    service.set_keymap("de").unwrap();
+    service.set_timezone("Europe/Berlin").unwrap();
+    service.set_ntp(false).unwrap();
+    service.set_hostname("hypercube", Some("Hypercube Laptop")).unwrap();
+    service.set_hostname("cube", None).unwrap();
+
+    assert_eq!(
+        bus.calls(),
+        [
+            "SetLocale LANG=de_DE.UTF-8",
+            r#"SetVConsoleKeyboard "de" "" convert=true"#,
+            "SetTimezone Europe/Berlin",
+            "SetNTP false",
+            r#"SetStaticHostname "hypercube""#,
+            r#"SetHostname "hypercube""#,
+            r#"SetPrettyHostname "Hypercube Laptop""#,
+            r#"SetStaticHostname "cube""#,
+            r#"SetHostname "cube""#,
+            r#"SetPrettyHostname """#,
+        ]
+    );
+    assert_eq!(service.list_timezones(), ["America/New_York", "Europe/Berlin", "UTC"]);
+}
+
+#[test]
+fn refusals_keep_their_error_names() {
+    let script = MockSystemd { locales: vec!["en_US.UTF-8".to_string()], ntp: false, ..MockSystemd::default() };
+    let Some((bus, service)) = start(script) else { return };
+
+    let err = service.set_locale("xx_XX.UTF-8").unwrap_err();
+    assert!(
+        matches!(&err, OnboardError::DbusMethod { name, .. } if name == "org.freedesktop.DBus.Error.InvalidArgs"),
+        "{err:?}"
+    );
+    assert_eq!(err.to_string(), "org.freedesktop.DBus.Error.InvalidArgs: Locale xx_XX.UTF-8 not installed, refusing.");
+
+    assert_eq!(
+        service.set_timezone("Mars/Olympus_Mons").unwrap_err().to_string(),
+        "org.freedesktop.DBus.Error.InvalidArgs: Invalid or not installed time zone 'Mars/Olympus_Mons'"
+    );
+    assert_eq!(
+        service.set_ntp(true).unwrap_err().to_string(),
+        "org.freedesktop.timedate1.NoNTPSupport: NTP not supported"
+    );
+    assert!(bus.calls().is_empty());
+}
+
+#[test]
+fn an_unprivileged_caller_is_denied() {
+    let Some((_bus, service)) = start(MockSystemd { deny: true, ..MockSystemd::default() }) else { return };
+
+    for err in [
+        service.set_keymap("us").unwrap_err(),
+        service.set_timezone("UTC").unwrap_err(),
+        service.set_hostname("cube", None).unwrap_err(),
+    ] {
+        assert_eq!(err.to_string(), "org.freedesktop.DBus.Error.AccessDenied: Access denied");
+    }
+}
+
+#[test]
+fn a_missing_service_is_named() {
+    let Some((_bus, service)) = start(MockSystemd { hostname1: false, ..MockSystemd::default() }) else { return };
+
+    let err = service.set_hostname("cube", None).unwrap_err();
+    assert!(
+        matches!(&err, OnboardError::DbusMethod { name, .. } if name == "org.freedesktop.DBus.Error.ServiceUnknown"),
+        "{err:?}"
+    );
+}
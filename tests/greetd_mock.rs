@@ -0,0 +1,317 @@
+use hypercube_utils::error::HypercubeError;
+use hypercube_utils::greeter::{App, GreeterConfig};
+use hypercube_utils::ipc::mock::{MockGreetd, MockScript, MockStep, MockStepKind};
+use hypercube_utils::ipc::{AuthRequest, AuthResponse, GreetdClient};
+use hypercube_utils::system::{Session, SessionType};
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+fn step(kind: MockStepKind, message: &str, expect: Option<&str>) -> MockStep {
+    MockStep {
+        kind,
+        message: message.to_string(),
+        expect: expect.map(str::to_string),
+        delay_ms: 0,
+    }
+}
+
+fn password_script() -> MockScript {
+    MockScript {
+        users: vec!["alice".to_string()],
+        steps: vec![step(MockStepKind::Secret, "Password: ", Some("hunter2"))],
+        start_session_error: None,
+    }
+}
+
+async fn connect(mock: &MockGreetd) -> GreetdClient {
+    GreetdClient::connect_to(mock.socket_path(), TIMEOUT)
+        .await
+        .expect("connect to mock greetd")
+}
+
+#[tokio::test]
+async fn password_login_starts_session() {
+    let mock = MockGreetd::start(password_script()).unwrap();
+    let mut client = connect(&mock).await;
+
+    let response = client.create_session("alice").await.unwrap();
+    assert!(matches!(response, AuthResponse::PromptSecret(ref p) if p == "Password: "));
+
+    let response = client
+        .post_auth_response(Some("hunter2".to_string()))
+        .await
+        .unwrap();
+    assert!(matches!(response, AuthResponse::Success));
+
+    client
+        .start_session(vec!["sway".to_string()], Vec::new())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn wrong_password_is_an_auth_error() {
+    let mock = MockGreetd::start(password_script()).unwrap();
+    let mut client = connect(&mock).await;
+
+    client.create_session("alice").await.unwrap();
+    let response = client
+        .post_auth_response(Some("wrong".to_string()))
+        .await
+        .unwrap();
+    assert!(matches!(response, AuthResponse::Error(ref msg) if msg == "Authentication failed"));
+
+    // The conversation is over, so a new one can begin on the same connection
+    let response = client.create_session("alice").await.unwrap();
+    assert!(matches!(response, AuthResponse::PromptSecret(_)));
+}
+
+#[tokio::test]
+async fn unknown_user_is_rejected() {
+    let mock = MockGreetd::start(password_script()).unwrap();
+    let mut client = connect(&mock).await;
+
+    let response = client.create_session("mallory").await.unwrap();
+    assert!(matches!(response, AuthResponse::Error(_)));
+}
+
+#[tokio::test]
+async fn multi_prompt_conversation() {
+    let mock = MockGreetd::start(MockScript {
+        users: Vec::new(),
+        steps: vec![
+            step(MockStepKind::Info, "Touch your security key", None),
+            step(MockStepKind::Secret, "Password: ", Some("hunter2")),
+            step(MockStepKind::Error, "Fingerprint reader not found", None),
+            step(MockStepKind::Visible, "Verification code: ", Some("123456")),
+        ],
+        start_session_error: None,
+    })
+    .unwrap();
+    let mut client = connect(&mock).await;
+
+    let response = client.create_session("bob").await.unwrap();
+    assert!(matches!(response, AuthResponse::Info(ref m) if m == "Touch your security key"));
+
+    let response = client.post_auth_response(None).await.unwrap();
+    assert!(matches!(response, AuthResponse::PromptSecret(_)));
+
+    let response = client
+        .post_auth_response(Some("hunter2".to_string()))
+        .await
+        .unwrap();
+    assert!(matches!(response, AuthResponse::ErrorMessage(ref m) if m == "Fingerprint reader not found"));
+
+    let response = client.post_auth_response(None).await.unwrap();
+    assert!(matches!(response, AuthResponse::PromptVisible(ref m) if m == "Verification code: "));
+
+    let response = client
+        .post_auth_response(Some("123456".to_string()))
+        .await
+        .unwrap();
+    assert!(matches!(response, AuthResponse::Success));
+}
+
+#[tokio::test]
+async fn start_session_failure_is_reported() {
+    let mut script = password_script();
+    script.start_session_error = Some("exec failed: no such file".to_string());
+    let mock = MockGreetd::start(script).unwrap();
+    let mut client = connect(&mock).await;
+
+    client.create_session("alice").await.unwrap();
+    client
+        .post_auth_response(Some("hunter2".to_string()))
+        .await
+        .unwrap();
+
+    let err = client
+        .start_session(vec!["missing".to_string()], Vec::new())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, HypercubeError::SessionFailed(ref msg) if msg == "exec failed: no such file"));
+}
+
+#[tokio::test]
+async fn cancel_resets_the_conversation() {
+    let mock = MockGreetd::start(password_script()).unwrap();
+    let mut client = connect(&mock).await;
+
+    client.create_session("alice").await.unwrap();
+    client.cancel_session().await.unwrap();
+
+    let err = client
+        .start_session(vec!["sway".to_string()], Vec::new())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, HypercubeError::SessionFailed(_)));
+}
+
+#[tokio::test]
+async fn slow_reply_times_out_and_loses_the_session() {
+    let mut script = password_script();
+    script.steps[0].delay_ms = 500;
+    let mock = MockGreetd::start(script).unwrap();
+    let mut client = GreetdClient::connect_to(mock.socket_path(), Duration::from_millis(50))
+        .await
+        .unwrap();
+
+    let err = client.create_session("alice").await.unwrap_err();
+    assert!(matches!(err, HypercubeError::Timeout(_)));
+
+    // The session went with the connection, so answering its prompt cannot work
+    let err = client
+        .post_auth_response(Some("hunter2".to_string()))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, HypercubeError::ConnectionLost), "{err}");
+    let err = client.cancel_session().await.unwrap_err();
+    assert!(matches!(err, HypercubeError::ConnectionLost), "{err}");
+
+    // Starting over opens a new connection; greetd rejects an unknown user without waiting
+    let response = client.create_session("mallory").await.unwrap();
+    assert!(matches!(response, AuthResponse::Error(_)));
+}
+
+// The greeter's own login flow against the mock
+
+/// A greeter for `username` with one session and nothing saved to disk
+fn app(username: &str, password: &str) -> App {
+    let mut config = GreeterConfig::default();
+    config.general.remember_last_user = false;
+    let sessions = vec![Session {
+        name: "Sway".to_string(),
+        slug: "sway".to_string(),
+        exec: "sway".to_string(),
+        desktop_names: vec!["sway".to_string()],
+        session_type: SessionType::Wayland,
+    }];
+    let mut app = App::with_system(config, sessions, Vec::new(), true);
+    app.username.set(username);
+    app.password.set(password);
+    app
+}
+
+/// Send the app's requests until it waits for the user or the conversation is over
+async fn drive(app: &mut App, client: &mut GreetdClient, mut next: Option<AuthRequest>) {
+    while let Some(request) = next {
+        let response = client.request(request).await;
+        next = app.handle_auth_response(response);
+    }
+}
+
+#[tokio::test]
+async fn app_logs_in_with_the_form_password() {
+    let mock = MockGreetd::start(password_script()).unwrap();
+    let mut client = connect(&mock).await;
+    let mut app = app("alice", "hunter2");
+
+    let request = app.login();
+    drive(&mut app, &mut client, request).await;
+    assert!(app.should_exit && app.exit_success);
+    assert!(app.message.is_none());
+}
+
+#[tokio::test]
+async fn app_shows_pam_messages_and_answers_later_prompts() {
+    let mock = MockGreetd::start(MockScript {
+        users: Vec::new(),
+        steps: vec![
+            step(MockStepKind::Info, "Touch your security key", None),
+            step(MockStepKind::Secret, "Password: ", Some("hunter2")),
+            step(MockStepKind::Error, "Fingerprint reader not found", None),
+            step(MockStepKind::Visible, "Verification code: ", Some("123456")),
+        ],
+        start_session_error: None,
+    })
+    .unwrap();
+    let mut client = connect(&mock).await;
+    let mut app = app("bob", "hunter2");
+
+    // The info and error messages are acknowledged, the password comes from the form
+    let request = app.login();
+    drive(&mut app, &mut client, request).await;
+    let messages: Vec<_> = app.auth_messages.iter().map(|m| (m.text.as_str(), m.is_error)).collect();
+    assert_eq!(messages, [("Touch your security key", false), ("Fingerprint reader not found", true)]);
+    let prompt = app.auth_prompt.as_mut().expect("code prompt");
+    assert_eq!(prompt.text, "Verification code: ");
+    assert!(!prompt.secret);
+    assert!(!app.working && !app.should_exit);
+
+    app.auth_prompt.as_mut().unwrap().input.set("123456");
+    let request = app.respond();
+    drive(&mut app, &mut client, request).await;
+    assert!(app.should_exit && app.exit_success);
+}
+
+#[tokio::test]
+async fn app_reports_a_wrong_password_and_can_try_again() {
+    let mock = MockGreetd::start(password_script()).unwrap();
+    let mut client = connect(&mock).await;
+    let mut app = app("alice", "wrong");
+
+    let request = app.login();
+    drive(&mut app, &mut client, request).await;
+    let message = app.message.as_ref().expect("error message");
+    assert!(message.is_error);
+    assert_eq!(message.text, "Authentication failed");
+    assert!(app.password.is_empty() && !app.working && !app.should_exit);
+
+    // The failed conversation was cancelled, so greetd takes a new one
+    app.password.set("hunter2");
+    let request = app.login();
+    drive(&mut app, &mut client, request).await;
+    assert!(app.should_exit && app.exit_success);
+}
+
+#[tokio::test]
+async fn app_cancels_at_a_prompt() {
+    let mock = MockGreetd::start(MockScript {
+        users: Vec::new(),
+        steps: vec![
+            step(MockStepKind::Secret, "Password: ", Some("hunter2")),
+            step(MockStepKind::Secret, "OTP: ", Some("000000")),
+        ],
+        start_session_error: None,
+    })
+    .unwrap();
+    let mut client = connect(&mock).await;
+    let mut app = app("alice", "hunter2");
+
+    let request = app.login();
+    drive(&mut app, &mut client, request).await;
+    assert_eq!(app.auth_prompt.as_ref().map(|p| p.text.as_str()), Some("OTP: "));
+
+    let request = app.cancel();
+    drive(&mut app, &mut client, request).await;
+    assert!(app.auth_prompt.is_none() && !app.working && !app.should_exit);
+
+    // A fresh attempt starts over at the first prompt
+    app.password.set("hunter2");
+    let request = app.login();
+    drive(&mut app, &mut client, request).await;
+    assert_eq!(app.auth_prompt.as_ref().map(|p| p.text.as_str()), Some("OTP: "));
+}
+
+#[tokio::test]
+async fn app_reports_a_session_that_fails_to_start() {
+    let mut script = password_script();
+    script.start_session_error = Some("exec failed: no such file".to_string());
+    let mock = MockGreetd::start(script).unwrap();
+    let mut client = connect(&mock).await;
+    let mut app = app("alice", "hunter2");
+
+    let request = app.login();
+    drive(&mut app, &mut client, request).await;
+    let message = app.message.as_ref().expect("error message");
+    assert!(message.is_error && message.text.contains("exec failed: no such file"), "{}", message.text);
+    assert!(!app.should_exit);
+}
+
+#[test]
+fn example_script_parses() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/mock-greetd.toml");
+    let script = MockScript::load_from(path).unwrap();
+    assert!(!script.steps.is_empty());
+}
@@ -0,0 +1,136 @@
+//! Message catalogs: every language has every message, and the wizard switches language live
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use hypercube_utils::i18n::{self, Catalog, Domain};
+use hypercube_utils::onboard::{OnboardApp, OnboardConfig, StepId};
+use std::collections::BTreeSet;
+
+/// Message ids defined in `locales/<language>/<file>`
+fn message_ids(language: &str, file: &str) -> BTreeSet<String> {
+    let path = format!("{}/locales/{language}/{file}", env!("CARGO_MANIFEST_DIR"));
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("{path}: {e}"))
+        .lines()
+        .filter_map(|line| line.split_once(" ="))
+        .map(|(id, _)| id)
+        .filter(|id| id.starts_with(|c: char| c.is_ascii_lowercase()) && !id.contains(' '))
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn every_language_has_the_english_messages() {
+    for file in ["greeter.ftl", "onboard.ftl"] {
+        let english = message_ids("en", file);
+        assert!(!english.is_empty());
+        for language in i18n::languages().filter(|l| *l != "en") {
+            let ids = message_ids(language, file);
+            let missing: Vec<_> = english.difference(&ids).collect();
+            let extra: Vec<_> = ids.difference(&english).collect();
+            assert!(missing.is_empty() && extra.is_empty(), "{language}/{file}: missing {missing:?}, extra {extra:?}");
+        }
+    }
+}
+
+#[test]
+fn locales_pick_their_language() {
+    for (locale, language) in [
+        ("de_DE.UTF-8", "de"),
+        ("de_AT.UTF-8@euro", "de"),
+        ("fr_CA.UTF-8", "fr"),
+        ("en_US.UTF-8", "en"),
+        ("es_ES.UTF-8", "en"),
+        ("C", "en"),
+        ("POSIX", "en"),
+        ("", "en"),
+    ] {
+        assert_eq!(Catalog::for_locale(Domain::Onboard, locale).language(), language, "{locale}");
+    }
+}
+
+#[test]
+fn messages_fill_in_arguments_and_plurals() {
+    let english = Catalog::english(Domain::Onboard);
+    assert_eq!(english.tr_args("msg-tasks-failed", &[("count", 1.into())]), "1 task failed during configuration");
+    assert_eq!(english.tr_args("msg-tasks-failed", &[("count", 3.into())]), "3 tasks failed during configuration");
+
+    let german = Catalog::for_locale(Domain::Greeter, "de_DE.UTF-8");
+    assert_eq!(german.tr_args("error-user-not-found", &[("name", "alex".into())]), "Benutzer nicht gefunden: alex");
+    // Unknown ids come back as they are
+    assert_eq!(german.tr("no-such-message"), "no-such-message");
+}
+
+fn press(app: &mut OnboardApp, script: &str) {
+    for c in script.chars() {
+        let key = match c {
+            '\n' => KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE),
+            '\x1b' => KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE),
+            c => KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE),
+        };
+        app.handle_key(key);
+    }
+}
+
+#[test]
+fn choosing_a_locale_switches_the_wizard() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/demo.toml");
+    let mut config = OnboardConfig::load_from(path).unwrap();
+    config.general.dryrun = true;
+    let mut app = OnboardApp::new(config);
+    assert_eq!(app.catalog.language(), "en");
+
+    press(&mut app, "\n\x1b:goto locale\nfr_FR\n");
+    assert_eq!(app.catalog.language(), "fr");
+    assert_eq!(app.step_title(StepId::Keyboard), "Clavier");
+    assert_eq!(app.message.as_ref().unwrap().text, "Langue choisie : fr_FR.UTF-8");
+    assert_eq!(app.status_bar.right_hint, "i : filtrer  Ctrl+h : panneau");
+    // Commands and reports keep the English step names
+    assert_eq!(app.step_name(StepId::Keyboard), "Keyboard");
+
+    press(&mut app, "\x1b:goto locale\nen_GB\n");
+    assert_eq!(app.catalog.language(), "en");
+    assert_eq!(app.step_title(StepId::Keyboard), "Keyboard");
+}
+
+#[test]
+fn review_tasks_and_hostname_errors_are_translated() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/demo.toml");
+    let mut config = OnboardConfig::load_from(path).unwrap();
+    config.general.dryrun = true;
+    let mut app = OnboardApp::new(config);
+    app.set_catalog(Catalog::for_locale(Domain::Onboard, "de_DE.UTF-8"));
+
+    app.hostname.set("-cube");
+    assert!(!app.validate_hostname_form());
+    assert_eq!(
+        app.message.as_ref().unwrap().text,
+        "Teile des Rechnernamens dürfen nicht mit einem Bindestrich beginnen oder enden"
+    );
+
+    app.username.set("lab");
+    app.password.set("correct horse battery");
+    app.password_confirm.set("correct horse battery");
+    app.selected_locale = Some("de_DE.UTF-8".to_string());
+    app.start_review_execution();
+    let names: Vec<&str> = app.tasks.iter().map(|t| t.name.as_str()).take(2).collect();
+    assert_eq!(names, ["Benutzer 'lab' wird angelegt", "Sprache wird auf de_DE.UTF-8 gesetzt"]);
+}
+
+#[test]
+fn goto_takes_the_translated_step_titles() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/demo.toml");
+    let mut config = OnboardConfig::load_from(path).unwrap();
+    config.general.dryrun = true;
+    let mut app = OnboardApp::new(config);
+    app.set_catalog(Catalog::for_locale(Domain::Onboard, "de_DE.UTF-8"));
+
+    press(&mut app, "\n\x1b:goto Tastatur\n");
+    assert_eq!(app.current_step_id(), Some(StepId::Keyboard));
+    // The English names keep working
+    press(&mut app, "\x1b:goto locale\n");
+    assert_eq!(app.current_step_id(), Some(StepId::Locale));
+
+    press(&mut app, "\x1b:goto tas");
+    app.handle_key(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE));
+    assert_eq!(app.command_line.content(), "goto tastatur");
+}
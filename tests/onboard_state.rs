@@ -0,0 +1,91 @@
+//! Persisted onboarding progress and resuming after an interruption
+
+mod common;
+
+use common::RecordingService;
+use hypercube_utils::onboard::{OnboardApp, OnboardConfig, OnboardState, StepId, StepResult};
+use std::sync::Arc;
+
+fn demo_app() -> OnboardApp {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/demo.toml");
+    let mut config = OnboardConfig::load_from(path).unwrap();
+    config.general.dryrun = true;
+    OnboardApp::new(config)
+}
+
+#[test]
+fn state_round_trips_through_the_state_file() {
+    let mut app = demo_app();
+    app.created_username = Some("lab".to_string());
+    app.selected_locale = Some("en_US.UTF-8".to_string());
+    app.completed_commands = vec!["flatpak update -y".to_string()];
+    let state = app.snapshot_state();
+    assert!(state.has_progress());
+    assert_eq!(state.result_for(StepId::Update), Some(StepResult::Locked));
+
+    let path = std::env::temp_dir().join(format!("hypercube-onboard-state-{}.toml", std::process::id()));
+    state.save(&path).unwrap();
+    let loaded = OnboardState::load(&path);
+    OnboardState::clear(&path);
+
+    assert_eq!(loaded, state);
+    assert!(!path.exists());
+    assert!(!OnboardState::load(&path).has_progress());
+}
+
+#[test]
+fn succeeded_commands_are_not_run_again() {
+    let mut app = demo_app();
+    app.start_update_execution();
+    let all = app.tasks.len();
+    assert!(all > 1);
+
+    let mut app = demo_app();
+    app.completed_commands = vec!["sudo dnf makecache".to_string()];
+    app.start_update_execution();
+    assert_eq!(app.tasks.len(), all - 1);
+    assert!(app.tasks.iter().all(|t| t.name != "Refreshing package cache"));
+}
+
+#[tokio::test]
+async fn an_account_added_before_a_crash_only_gets_its_password_set() {
+    let path = std::env::temp_dir().join(format!("hypercube-onboard-pending-{}.toml", std::process::id()));
+    OnboardState::clear(&path);
+    let mut config: OnboardConfig = toml::from_str("[network]\nenabled = false").unwrap();
+    config.general.state_file = path.display().to_string();
+    config.completion.report = String::new();
+    let service = Arc::new(RecordingService::default());
+
+    let mut app = OnboardApp::with_service(config.clone(), service.clone());
+    app.setup_started = true;
+    app.username.set("lab");
+    app.password.set("correct horse");
+    app.password_confirm.set("correct horse");
+    let mut rx = app.start_review_execution().unwrap();
+    let saved = OnboardState::load(&path);
+    assert_eq!(saved.pending_username.as_deref(), Some("lab"));
+    assert_eq!(saved.created_username, None);
+
+    // useradd goes through, but the app dies before it hears about it
+    while rx.recv().await.is_some() {}
+    drop(app);
+    assert_eq!(service.accounts(), ["useradd lab"]);
+    assert_eq!(OnboardState::load(&path), saved);
+
+    let mut app = OnboardApp::with_service(config, service.clone());
+    assert!(app.can_resume());
+    app.resume();
+    assert_eq!(app.username.content(), "lab");
+    app.password.set("correct horse");
+    app.password_confirm.set("correct horse");
+    let mut rx = app.start_review_execution().unwrap();
+    while let Some(msg) = rx.recv().await {
+        app.handle_execution_message(msg);
+    }
+    OnboardState::clear(&path);
+
+    assert!(app.review_completed);
+    assert_eq!(service.accounts(), ["useradd lab", "chpasswd lab"]);
+    assert_eq!(app.created_username.as_deref(), Some("lab"));
+    assert_eq!(app.pending_username, None);
+}
@@ -0,0 +1,171 @@
+//! Scheduling Update packages by `depends_on`, `group` and `parallel_installs`
+
+mod common;
+
+use common::RecordingService;
+use hypercube_utils::onboard::{ExecutionMessage, OnboardApp, OnboardConfig, TaskState};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc::UnboundedReceiver;
+
+/// Backend whose commands take long enough to see which ones overlap
+fn slow_service() -> Arc<RecordingService> {
+    Arc::new(RecordingService::with_delay(Duration::from_millis(50)))
+}
+
+/// One enabled category holding `packages`, each given as TOML lines
+fn config(parallel: usize, packages: &[(&str, &str)]) -> OnboardConfig {
+    let mut toml = format!(
+        "[general]\nparallel_installs = {parallel}\n\n[network]\nenabled = false\n\n\
+         [[updates]]\nname = \"Apps\"\nenabled_by_default = true\n"
+    );
+    for (title, extra) in packages {
+        let command = title.to_lowercase().replace(' ', "-");
+        toml.push_str(&format!(
+            "\n[[updates.packages]]\ntitle = \"{title}\"\n{extra}\n\
+             [[updates.packages.commands]]\nname = \"{title}\"\ncommand = [\"{command}\"]\n"
+        ));
+    }
+    toml::from_str(&toml).unwrap()
+}
+
+fn live_app(service: &Arc<RecordingService>, mut config: OnboardConfig) -> OnboardApp {
+    config.general.state_file = std::env::temp_dir()
+        .join(format!("hypercube-parallel-install-{}.toml", std::process::id()))
+        .display()
+        .to_string();
+    let mut app = OnboardApp::with_service(config, service.clone());
+    app.created_username = Some("lab".to_string());
+    app
+}
+
+async fn drain(app: &mut OnboardApp, rx: Option<UnboundedReceiver<ExecutionMessage>>) {
+    if let Some(mut rx) = rx {
+        while let Some(msg) = rx.recv().await {
+            app.handle_execution_message(msg);
+        }
+    }
+}
+
+fn states(app: &OnboardApp) -> Vec<TaskState> {
+    app.tasks.iter().map(|t| t.status).collect()
+}
+
+#[tokio::test]
+async fn installs_run_side_by_side_up_to_the_limit() {
+    let service = slow_service();
+    let packages = [("One", ""), ("Two", ""), ("Three", ""), ("Four", "")];
+    let mut app = live_app(&service, config(2, &packages));
+
+    let rx = app.start_update_execution();
+    drain(&mut app, rx).await;
+
+    assert!(app.update_completed);
+    assert_eq!(service.most_running(), 2);
+    // Packages start in config order as slots free up
+    assert!(service.position("start two") < service.position("end one"));
+    assert!(service.position("start three") > service.position("end one"));
+}
+
+#[tokio::test]
+async fn packages_wait_for_dependencies_and_their_group() {
+    let service = slow_service();
+    let packages = [
+        ("Homebrew", ""),
+        ("Neovim", "depends_on = [\"Homebrew\"]"),
+        ("Firefox", "group = \"flatpak\""),
+        ("Thunderbird", "group = \"flatpak\""),
+    ];
+    let mut app = live_app(&service, config(4, &packages));
+
+    let rx = app.start_update_execution();
+    drain(&mut app, rx).await;
+
+    assert!(app.update_completed);
+    assert!(service.position("start neovim") > service.position("end homebrew"));
+    assert!(service.position("start thunderbird") > service.position("end firefox"));
+    // Homebrew and Firefox had nothing in common
+    assert!(service.position("start firefox") < service.position("end homebrew"));
+}
+
+#[tokio::test]
+async fn skipping_a_failed_dependency_skips_its_dependents() {
+    let service = slow_service();
+    service.fail("homebrew", u32::MAX);
+    let packages = [
+        ("Homebrew", ""),
+        ("Neovim", "depends_on = [\"Homebrew\"]"),
+        ("Lazygit", "depends_on = [\"Neovim\"]"),
+        ("Firefox", ""),
+    ];
+    let mut app = live_app(&service, config(2, &packages));
+
+    let rx = app.start_update_execution();
+    drain(&mut app, rx).await;
+
+    // Independent packages still install while dependents wait for a retry or skip
+    use TaskState::*;
+    assert_eq!(states(&app), [Failed, Pending, Pending, Success]);
+    assert!(!app.update_completed);
+    assert_eq!(app.selected_failed_task(), Some(0));
+
+    let rx = app.skip_task(0);
+    drain(&mut app, rx).await;
+
+    assert_eq!(states(&app), [Skipped, Skipped, Skipped, Success]);
+    assert!(app.update_completed);
+    assert_eq!(
+        app.skipped_tasks,
+        [
+            "Homebrew",
+            "Neovim (Homebrew was not installed)",
+            "Lazygit (Neovim was not installed)",
+        ]
+    );
+    assert!(!service.events().iter().any(|e| e.contains("neovim")));
+}
+
+#[tokio::test]
+async fn a_dependency_that_was_not_selected_skips_its_dependents() {
+    let service = slow_service();
+    let packages = [("Homebrew", ""), ("Neovim", "depends_on = [\"Homebrew\"]"), ("Firefox", "")];
+    let mut app = live_app(&service, config(2, &packages));
+    app.update_package_selected[0][0] = false;
+
+    let rx = app.start_update_execution();
+    drain(&mut app, rx).await;
+
+    use TaskState::*;
+    assert_eq!(states(&app), [Skipped, Success]);
+    assert_eq!(app.skipped_tasks, ["Neovim (Homebrew was not selected)"]);
+
+    // Installed before an interruption counts, selected or not
+    let service = slow_service();
+    let mut app = live_app(&service, config(2, &packages));
+    app.update_package_selected[0][0] = false;
+    app.completed_commands = vec!["homebrew".to_string()];
+
+    let rx = app.start_update_execution();
+    drain(&mut app, rx).await;
+    assert_eq!(states(&app), [Success, Success]);
+    assert!(app.skipped_tasks.is_empty());
+}
+
+#[tokio::test]
+async fn circular_dependencies_are_skipped() {
+    let service = slow_service();
+    let packages = [
+        ("Left", "depends_on = [\"Right\"]"),
+        ("Right", "depends_on = [\"Left\"]"),
+        ("Other", ""),
+    ];
+    let mut app = live_app(&service, config(1, &packages));
+
+    let rx = app.start_update_execution();
+    drain(&mut app, rx).await;
+
+    use TaskState::*;
+    assert_eq!(states(&app), [Skipped, Skipped, Success]);
+    assert!(app.update_completed);
+    assert_eq!(app.tasks[0].output.as_deref(), Some("circular depends_on: Right"));
+}
@@ -0,0 +1,87 @@
+//! How `sudo = true` commands get root under each `sudo_policy`
+
+mod common;
+
+use common::RecordingService;
+use hypercube_utils::onboard::{ExecutionMessage, OnboardApp, OnboardConfig};
+use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedReceiver;
+
+fn live_app(service: &Arc<RecordingService>, policy: &str) -> OnboardApp {
+    let mut config: OnboardConfig = toml::from_str(&format!(
+        r#"
+[general]
+sudo_policy = "{policy}"
+
+[network]
+enabled = false
+
+[[updates]]
+name = "System"
+enabled_by_default = true
+
+[[updates.packages]]
+title = "Updates"
+[[updates.packages.commands]]
+name = "Upgrade"
+command = ["sudo", "dnf", "upgrade", "-y"]
+sudo = true
+[[updates.packages.commands]]
+name = "Dotfiles"
+command = ["chezmoi", "apply"]
+"#
+    ))
+    .unwrap();
+    config.general.state_file = std::env::temp_dir()
+        .join(format!("hypercube-privileges-{policy}-{}.toml", std::process::id()))
+        .display()
+        .to_string();
+    let mut app = OnboardApp::with_service(config, service.clone());
+    app.created_username = Some("lab".to_string());
+    app
+}
+
+async fn drain(app: &mut OnboardApp, rx: Option<UnboundedReceiver<ExecutionMessage>>) {
+    if let Some(mut rx) = rx {
+        while let Some(msg) = rx.recv().await {
+            app.handle_execution_message(msg);
+        }
+    }
+}
+
+#[tokio::test]
+async fn sudo_commands_run_as_root_without_a_password() {
+    let service = Arc::new(RecordingService::default());
+    let mut app = live_app(&service, "root");
+
+    assert!(!app.commands_need_sudo());
+    let rx = app.start_update_execution();
+    drain(&mut app, rx).await;
+
+    assert!(app.update_completed);
+    assert_eq!(service.runs(), ["root: sudo dnf upgrade -y", "lab: chezmoi apply"]);
+}
+
+#[tokio::test]
+async fn password_policy_asks_first_and_hands_the_password_to_sudo() {
+    let service = Arc::new(RecordingService::default());
+    let mut app = live_app(&service, "password");
+
+    assert!(app.commands_need_sudo());
+    assert!(app.start_update_execution().is_none());
+    assert!(app.sudo_password_needed);
+    assert!(service.runs().is_empty());
+
+    app.sudo_password.set("hunter2");
+    app.sudo_password_entered = true;
+    let rx = app.start_update_execution();
+    drain(&mut app, rx).await;
+
+    assert!(app.update_completed);
+    assert_eq!(
+        service.runs(),
+        ["lab via sudo (hunter2): sudo dnf upgrade -y", "lab: chezmoi apply"]
+    );
+    // Not kept around once nothing else needs it
+    assert!(app.sudo_password.content().is_empty());
+}
@@ -0,0 +1,70 @@
+//! Progress parsed from the output of package managers
+
+use hypercube_utils::onboard::{Progress, ProgressFormat, ProgressParser};
+use regex::Regex;
+
+fn feed(parser: &mut ProgressParser, lines: &[&str]) -> Option<Progress> {
+    lines.iter().filter_map(|line| parser.parse(line)).last()
+}
+
+fn command(args: &[&str]) -> Vec<String> {
+    args.iter().map(|a| a.to_string()).collect()
+}
+
+#[test]
+fn formats_are_detected_from_the_command() {
+    let detect = |args: &[&str]| ProgressFormat::detect(&command(args));
+    assert_eq!(detect(&["sudo", "dnf", "upgrade", "-y"]), Some(ProgressFormat::Dnf));
+    assert_eq!(detect(&["/usr/bin/flatpak", "install", "-y", "flathub", "org.gimp.GIMP"]), Some(ProgressFormat::Flatpak));
+    assert_eq!(detect(&["env", "HOMEBREW_NO_ANALYTICS=1", "brew", "install", "gh"]), Some(ProgressFormat::Brew));
+    assert_eq!(detect(&["bash", "-c", "dnf upgrade"]), None);
+}
+
+#[test]
+fn dnf_counts_packages_in_the_transaction() {
+    let mut dnf = ProgressParser::new(ProgressFormat::Dnf, None);
+    assert_eq!(dnf.parse("Updating and loading repositories:"), None);
+
+    // dnf5
+    let progress = feed(&mut dnf, &["[ 3/12] Installing firefox-0:131.0-1.fc41.x86_64  50% |  45.0 MiB/s"]);
+    assert_eq!(progress, Some(Progress { percent: 20, count: Some((3, 12)) }));
+
+    // dnf4
+    let progress = feed(&mut dnf, &["  Installing       : firefox-131.0-1.fc41.x86_64            6/12 "]);
+    assert_eq!(progress, Some(Progress { percent: 50, count: Some((6, 12)) }));
+    let progress = feed(&mut dnf, &["(12/12): vim-common-9.1.x86_64.rpm   12 MB/s | 7.9 MB  00:00"]);
+    assert_eq!(progress, Some(Progress { percent: 100, count: Some((12, 12)) }));
+}
+
+#[test]
+fn flatpak_combines_the_ref_count_with_its_download() {
+    let mut flatpak = ProgressParser::new(ProgressFormat::Flatpak, None);
+    assert_eq!(flatpak.parse("Looking for matches…"), None);
+    let progress = feed(&mut flatpak, &["Installing 2/2… ████████▌            45%  3.1 MB/s  00:12"]);
+    assert_eq!(progress, Some(Progress { percent: 72, count: Some((2, 2)) }));
+}
+
+#[test]
+fn brew_counts_poured_bottles() {
+    let mut brew = ProgressParser::new(ProgressFormat::Brew, None);
+    let progress = feed(&mut brew, &[
+        "==> Fetching dependencies for gh: libyaml and ruby",
+        "==> Installing dependencies for gh: libyaml, ruby and readline",
+        "==> Pouring libyaml--0.2.5.arm64_sonoma.bottle.tar.gz",
+    ]);
+    assert_eq!(progress, Some(Progress { percent: 25, count: Some((1, 4)) }));
+
+    let progress = feed(&mut brew, &["==> Pouring ruby--3.3.5.arm64_sonoma.bottle.tar.gz", "==> Summary"]);
+    assert_eq!(progress, Some(Progress { percent: 100, count: Some((4, 4)) }));
+}
+
+#[test]
+fn percent_regex_reads_named_groups() {
+    let mut plain = ProgressParser::new(ProgressFormat::PercentRegex, None);
+    assert_eq!(plain.parse("downloaded 12.5% of image").map(|p| p.percent), Some(12));
+
+    let pattern = Regex::new(r"step (?P<current>\d+) of (?P<total>\d+)").unwrap();
+    let mut steps = ProgressParser::new(ProgressFormat::PercentRegex, Some(pattern));
+    assert_eq!(steps.parse("step 3 of 4: linking"), Some(Progress { percent: 75, count: Some((3, 4)) }));
+    assert_eq!(steps.parse("linking 50%"), None);
+}
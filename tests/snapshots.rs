@@ -0,0 +1,395 @@
+//! Golden snapshots of the greeter and onboard UIs
+//!
+//! Each case drives an app with a key sequence, renders it into a `TestBackend`
+//! at several sizes and compares the text against `tests/snapshots/<name>.txt`.
+//! Run with `UPDATE_SNAPSHOTS=1` to (re)write the golden files after an
+//! intentional layout change.
+
+use chrono::NaiveDate;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use hypercube_utils::greeter::{App, AppAction, GreeterConfig};
+use hypercube_utils::onboard::{
+    ExecutionMessage, OnboardApp, OnboardConfig, StepId, StepResult, TaskState, TaskStatus,
+};
+use hypercube_utils::system::{Session, SessionType, User};
+use ratatui::Terminal;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use std::path::PathBuf;
+
+const SIZES: &[(u16, u16)] = &[(80, 24), (120, 40), (60, 20)];
+
+/// Parse a key script: plain characters, plus `<Esc>`, `<Enter>`, `<Tab>`, `<S-Tab>`,
+/// `<Up>`, `<Down>`, `<F2>`-style function keys and `<C-x>` for Ctrl
+fn keys(script: &str) -> Vec<KeyEvent> {
+    let mut events = Vec::new();
+    let mut chars = script.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '<' {
+            events.push(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE));
+            continue;
+        }
+
+        let name: String = chars.by_ref().take_while(|&c| c != '>').collect();
+        let event = match name.as_str() {
+            "Esc" => KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE),
+            "Enter" => KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE),
+            "Tab" => KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE),
+            "S-Tab" => KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT),
+            "Up" => KeyEvent::new(KeyCode::Up, KeyModifiers::NONE),
+            "Down" => KeyEvent::new(KeyCode::Down, KeyModifiers::NONE),
+            "lt" => KeyEvent::new(KeyCode::Char('<'), KeyModifiers::NONE),
+            ctrl if ctrl.starts_with("C-") => {
+                let c = ctrl[2..].chars().next().expect("key after C-");
+                KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+            }
+            f if f.starts_with('F') => {
+                let n = f[1..].parse().expect("function key number");
+                KeyEvent::new(KeyCode::F(n), KeyModifiers::NONE)
+            }
+            other => panic!("unknown key <{other}>"),
+        };
+        events.push(event);
+    }
+
+    events
+}
+
+fn buffer_to_string(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let mut out = String::new();
+    for y in 0..area.height {
+        let mut line = String::new();
+        let mut skip = 0;
+        for x in 0..area.width {
+            // Wide characters occupy two cells; the second one is empty
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            let symbol = buffer[(x, y)].symbol();
+            skip = unicode_width(symbol).saturating_sub(1);
+            line.push_str(symbol);
+        }
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+fn unicode_width(symbol: &str) -> usize {
+    use ratatui::text::Span;
+    Span::raw(symbol).width()
+}
+
+/// Compare the renders against the golden file, or write it when updating
+fn assert_snapshot(name: &str, renders: &[(u16, u16, String)]) {
+    let mut actual = String::new();
+    for (width, height, text) in renders {
+        actual.push_str(&format!("--- {width}x{height} ---\n"));
+        actual.push_str(text);
+    }
+
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(format!("{name}.txt"));
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let Ok(expected) = std::fs::read_to_string(&path) else {
+        panic!("missing snapshot {}, run with UPDATE_SNAPSHOTS=1\n\n{actual}", path.display());
+    };
+    if expected != actual {
+        panic!(
+            "snapshot {name} does not match {}\n\
+             rerun with UPDATE_SNAPSHOTS=1 if the change is intended\n\n{actual}",
+            path.display()
+        );
+    }
+}
+
+// Greeter
+
+fn greeter_app() -> App {
+    let mut config = GreeterConfig::default();
+    config.general.remember_last_user = false;
+    config.general.greeting = "Welcome to Hypercube".to_string();
+
+    let sessions = vec![
+        session("Sway", "sway", SessionType::Wayland),
+        session("Niri", "niri", SessionType::Wayland),
+        session("GNOME on Xorg", "gnome-xorg", SessionType::X11),
+    ];
+    let users = vec![
+        User {
+            username: "alice".to_string(),
+            display_name: Some("Alice Liddell".to_string()),
+        },
+        User {
+            username: "bob".to_string(),
+            display_name: None,
+        },
+    ];
+
+    let mut app = App::with_system(config, sessions, users, true);
+    app.hostname = "hypercube".to_string();
+    app.frozen_time = Some(
+        NaiveDate::from_ymd_opt(2025, 1, 6)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap(),
+    );
+    app
+}
+
+fn session(name: &str, slug: &str, session_type: SessionType) -> Session {
+    Session {
+        name: name.to_string(),
+        slug: slug.to_string(),
+        exec: slug.to_string(),
+        desktop_names: vec![name.to_string()],
+        session_type,
+    }
+}
+
+fn greeter_snapshot(name: &str, script: &str) {
+    let renders = SIZES
+        .iter()
+        .map(|&(width, height)| {
+            let mut app = greeter_app();
+            for key in keys(script) {
+                // Requests are dropped; there is no greetd to answer them
+                let _ = match app.handle_key(key) {
+                    Some(AppAction::Login) => app.login(),
+                    Some(AppAction::Respond) => app.respond(),
+                    Some(AppAction::Cancel) => app.cancel(),
+                    _ => None,
+                };
+            }
+
+            let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+            terminal
+                .draw(|frame| hypercube_utils::greeter::ui::draw(frame, &app))
+                .unwrap();
+            (width, height, buffer_to_string(terminal.backend().buffer()))
+        })
+        .collect::<Vec<_>>();
+
+    assert_snapshot(name, &renders);
+}
+
+#[test]
+fn greeter_login_form() {
+    greeter_snapshot("greeter_login_form", "");
+}
+
+#[test]
+fn greeter_login_form_filled() {
+    greeter_snapshot("greeter_login_form_filled", "alice<Tab>secret");
+}
+
+#[test]
+fn greeter_missing_username() {
+    greeter_snapshot("greeter_missing_username", "<Esc><Enter>");
+}
+
+#[test]
+fn greeter_authenticating() {
+    greeter_snapshot("greeter_authenticating", "alice<Tab>secret<Enter>");
+}
+
+#[test]
+fn greeter_session_picker() {
+    greeter_snapshot("greeter_session_picker", "<Esc><F3><Down>");
+}
+
+#[test]
+fn greeter_user_picker() {
+    greeter_snapshot("greeter_user_picker", "<Esc><F2>");
+}
+
+#[test]
+fn greeter_help() {
+    greeter_snapshot("greeter_help", "<Esc>:help<Enter>");
+}
+
+#[test]
+fn greeter_command_line() {
+    greeter_snapshot("greeter_command_line", "<Esc>:sess");
+}
+
+#[test]
+fn greeter_wildmenu() {
+    greeter_snapshot("greeter_wildmenu", "<Esc>:session <Tab><Tab>");
+}
+
+// Onboard
+
+fn onboard_app() -> OnboardApp {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/demo.toml");
+    let mut config = OnboardConfig::load_from(path).unwrap();
+    config.general.dryrun = true;
+    OnboardApp::new(config)
+}
+
+fn onboard_snapshot(name: &str, setup: impl Fn(&mut OnboardApp), script: &str) {
+    onboard_snapshot_of(name, || {
+        let mut app = onboard_app();
+        setup(&mut app);
+        app
+    }, script);
+}
+
+/// Onboard with the custom steps of `examples/steps.toml`
+fn custom_steps_snapshot(name: &str, script: &str) {
+    onboard_snapshot_of(name, || {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/steps.toml");
+        OnboardApp::new(OnboardConfig::load_from(path).unwrap())
+    }, script);
+}
+
+fn onboard_snapshot_of(name: &str, make_app: impl Fn() -> OnboardApp, script: &str) {
+    let renders = SIZES
+        .iter()
+        .map(|&(width, height)| {
+            let mut app = make_app();
+            for key in keys(script) {
+                app.handle_key(key);
+            }
+
+            let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+            terminal
+                .draw(|frame| hypercube_utils::onboard::ui::draw(frame, &mut app))
+                .unwrap();
+            (width, height, buffer_to_string(terminal.backend().buffer()))
+        })
+        .collect::<Vec<_>>();
+
+    assert_snapshot(name, &renders);
+}
+
+/// Make the Update step reachable without running the review
+fn unlock_update(app: &mut OnboardApp) {
+    for (idx, item) in app.menu_items.iter().enumerate() {
+        if item.id == StepId::Update {
+            app.step_results[idx] = StepResult::Pending;
+        }
+    }
+    app.review_completed = true;
+}
+
+#[test]
+fn onboard_welcome() {
+    onboard_snapshot("onboard_welcome", |_| {}, "");
+}
+
+#[test]
+fn onboard_sidebar() {
+    onboard_snapshot("onboard_sidebar", |_| {}, "<Enter>");
+}
+
+#[test]
+fn onboard_user_form() {
+    onboard_snapshot("onboard_user_form", |_| {}, "<Enter><C-h>2l");
+}
+
+#[test]
+fn onboard_hostname_form() {
+    onboard_snapshot("onboard_hostname_form", |_| {}, "<Enter><C-h>3l");
+}
+
+#[test]
+fn onboard_preferences() {
+    // Up from the timezone filter to the toggles, then turn off NTP
+    onboard_snapshot("onboard_preferences", |_| {}, "<Enter><C-h>6l<S-Tab>k ");
+}
+
+#[test]
+fn onboard_custom_info() {
+    custom_steps_snapshot("onboard_custom_info", "<Enter><C-h>2l");
+}
+
+#[test]
+fn onboard_custom_choice() {
+    custom_steps_snapshot("onboard_custom_choice", "<Enter><C-h>3l");
+}
+
+#[test]
+fn onboard_custom_multi_choice() {
+    custom_steps_snapshot("onboard_custom_multi_choice", "<Enter><C-h>8lj ");
+}
+
+#[test]
+fn onboard_custom_form() {
+    custom_steps_snapshot("onboard_custom_form", "<Enter>:goto enroll<Enter><Enter>ops@");
+}
+
+#[test]
+fn onboard_locale_picker() {
+    onboard_snapshot("onboard_locale_picker", |_| {}, "<Enter><C-h>4l");
+}
+
+#[test]
+fn onboard_locale_switch() {
+    // Choosing German on the Locale step turns the wizard German
+    onboard_snapshot("onboard_locale_switch", |_| {}, "<Enter><C-h>4lde_DE<Enter>");
+}
+
+#[test]
+fn onboard_update_list() {
+    onboard_snapshot("onboard_update_list", unlock_update, "<Enter><C-h>8lj");
+}
+
+/// Two update tasks, the first finished and the second streaming output
+fn update_output(app: &mut OnboardApp) {
+    unlock_update(app);
+    app.task_step = Some(StepId::Update);
+    for name in ["Refreshing package cache", "Installing Firefox"] {
+        app.tasks.push(TaskStatus {
+            name: name.to_string(),
+            status: TaskState::Pending,
+            output: None,
+            progress: None,
+            count: None,
+            command: None,
+            log: Vec::new(),
+        });
+    }
+    let lines = [
+        (0, "Updating and loading repositories:"),
+        (0, " Fedora 41 - x86_64  100% |  12.3 MiB/s |  35.1 MiB |  00m03s"),
+        (0, "Repositories loaded."),
+        (0, "Metadata cache created."),
+        (1, "Looking for matches in: flathub"),
+        (1, "Installing 1/2 org.mozilla.firefox.Locale  45%"),
+    ];
+    app.handle_execution_message(ExecutionMessage::TaskStarted(0));
+    for (idx, line) in &lines[..4] {
+        app.handle_execution_message(ExecutionMessage::TaskOutput(*idx, line.to_string()));
+    }
+    app.handle_execution_message(ExecutionMessage::TaskSuccess(0, None));
+    app.handle_execution_message(ExecutionMessage::TaskStarted(1));
+    for (idx, line) in &lines[4..] {
+        app.handle_execution_message(ExecutionMessage::TaskOutput(*idx, line.to_string()));
+    }
+}
+
+#[test]
+fn onboard_update_output() {
+    onboard_snapshot("onboard_update_output", update_output, "<Enter><C-h>8l");
+}
+
+#[test]
+fn onboard_update_output_previous_task() {
+    onboard_snapshot("onboard_update_output_previous_task", update_output, "<Enter><C-h>8lK");
+}
+
+#[test]
+fn onboard_help() {
+    onboard_snapshot("onboard_help", |_| {}, "<Enter>?");
+}
@@ -0,0 +1,259 @@
+//! Retrying and skipping failed Review and Update tasks
+
+mod common;
+
+use common::RecordingService;
+use hypercube_utils::onboard::{
+    ExecutionMessage, OnboardApp, OnboardConfig, OnboardState, RunOutcome, StepId, StepResult,
+    TaskState,
+};
+use std::sync::Arc;
+use tokio::sync::mpsc::UnboundedReceiver;
+
+const CONFIG: &str = r#"
+[network]
+enabled = false
+
+[preferences]
+ntp_enabled = false
+keyring_enabled = false
+
+[[updates]]
+name = "Apps"
+enabled_by_default = true
+
+[[updates.packages]]
+title = "Tools"
+[[updates.packages.commands]]
+name = "First"
+command = ["first"]
+[[updates.packages.commands]]
+name = "Flaky"
+command = ["flaky"]
+retries = 2
+retry_delay = 0
+[[updates.packages.commands]]
+name = "Broken"
+command = ["broken"]
+[[updates.packages.commands]]
+name = "Tolerated"
+command = ["tolerated"]
+continue_on_error = true
+[[updates.packages.commands]]
+name = "Last"
+command = ["last"]
+"#;
+
+fn live_app(service: &Arc<RecordingService>) -> OnboardApp {
+    let mut config: OnboardConfig = toml::from_str(CONFIG).unwrap();
+    config.general.state_file = std::env::temp_dir()
+        .join(format!("hypercube-task-retry-{}.toml", std::process::id()))
+        .display()
+        .to_string();
+    config.completion.report = String::new();
+    let mut app = OnboardApp::with_service(config, service.clone());
+    app.created_username = Some("lab".to_string());
+    app
+}
+
+async fn drain(app: &mut OnboardApp, rx: Option<UnboundedReceiver<ExecutionMessage>>) {
+    if let Some(mut rx) = rx {
+        while let Some(msg) = rx.recv().await {
+            app.handle_execution_message(msg);
+        }
+    }
+}
+
+fn states(app: &OnboardApp) -> Vec<TaskState> {
+    app.tasks.iter().map(|t| t.status).collect()
+}
+
+fn update_result(app: &OnboardApp) -> StepResult {
+    let idx = app.menu_items.iter().position(|m| m.id == StepId::Update).unwrap();
+    app.step_results[idx]
+}
+
+#[tokio::test]
+async fn a_failure_stops_the_batch_until_retried() {
+    let service = Arc::new(RecordingService::default());
+    service.fail("flaky", 2);
+    service.fail("broken", 1);
+    let mut app = live_app(&service);
+
+    let rx = app.start_update_execution();
+    drain(&mut app, rx).await;
+
+    use TaskState::*;
+    assert_eq!(states(&app), [Success, Success, Failed, Pending, Pending]);
+    assert_eq!(update_result(&app), StepResult::Failed);
+    assert!(!app.update_completed);
+    assert_eq!(app.selected_failed_task(), Some(2));
+    // Two automatic retries got the flaky command through
+    assert_eq!(service.attempts("flaky"), 3);
+    assert!(app.tasks[1].log.iter().any(|l| l.contains("retrying in 0s (attempt 2 of 3)")));
+
+    let rx = app.retry_task(2);
+    drain(&mut app, rx).await;
+
+    assert_eq!(states(&app), [Success, Success, Success, Success, Success]);
+    assert!(app.update_completed);
+    assert!(app.skipped_tasks.is_empty());
+    assert_eq!(service.attempts("first"), 1);
+}
+
+#[tokio::test]
+async fn skipped_and_tolerated_failures_are_summarised() {
+    let service = Arc::new(RecordingService::default());
+    service.fail("broken", 5);
+    service.fail("tolerated", 5);
+    let mut app = live_app(&service);
+
+    let rx = app.start_update_execution();
+    drain(&mut app, rx).await;
+    assert_eq!(app.selected_failed_task(), Some(2));
+
+    let rx = app.skip_task(2);
+    drain(&mut app, rx).await;
+
+    use TaskState::*;
+    assert_eq!(states(&app), [Success, Success, Skipped, Failed, Success]);
+    assert!(app.update_completed);
+    assert_eq!(update_result(&app), StepResult::Completed);
+    assert_eq!(app.skipped_tasks, ["Broken", "Tolerated (failed)"]);
+}
+
+#[tokio::test]
+async fn a_failed_review_task_can_be_skipped_but_not_the_account() {
+    let service = Arc::new(RecordingService::default());
+    service.fail("locale", 1);
+    let mut app = live_app(&service);
+    app.created_username = None;
+    app.username.set("lab");
+    app.password.set("correct horse");
+    app.password_confirm.set("correct horse");
+    app.selected_locale = Some("de_DE.UTF-8".to_string());
+
+    let rx = app.start_review_execution();
+    drain(&mut app, rx).await;
+    assert_eq!(states(&app), [TaskState::Success, TaskState::Failed]);
+    assert!(!app.review_completed);
+
+    assert!(app.skip_task(0).is_none());
+    assert_eq!(app.tasks[0].status, TaskState::Success);
+
+    let rx = app.skip_task(1);
+    drain(&mut app, rx).await;
+    assert!(app.review_completed);
+    assert_eq!(app.created_username.as_deref(), Some("lab"));
+    assert_eq!(app.skipped_tasks, ["Setting locale to de_DE.UTF-8"]);
+}
+
+#[tokio::test]
+async fn the_install_report_records_each_run() {
+    let service = Arc::new(RecordingService::default());
+    service.fail("flaky", 1);
+    service.fail("broken", 1);
+    let mut app = live_app(&service);
+    app.selected_locale = Some("de_DE.UTF-8".to_string());
+
+    let rx = app.start_update_execution();
+    drain(&mut app, rx).await;
+    let rx = app.retry_task(2);
+    drain(&mut app, rx).await;
+
+    let path = std::env::temp_dir().join(format!("hypercube-report-{}.json", std::process::id()));
+    app.config.completion.report = path.display().to_string();
+    app.finish_setup().await;
+    let json = std::fs::read_to_string(&path).unwrap();
+    let summary = std::fs::read_to_string(path.with_extension("txt")).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    std::fs::remove_file(path.with_extension("txt")).unwrap();
+
+    let report: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(report["dryrun"], false);
+    assert_eq!(report["locale"], "de_DE.UTF-8");
+    assert_eq!(report["steps"][0], serde_json::json!({ "step": "User", "result": "pending" }));
+
+    let runs: Vec<(&str, &str, i64, u64)> = report["commands"].as_array().unwrap().iter()
+        .map(|run| (
+            run["name"].as_str().unwrap(),
+            run["outcome"].as_str().unwrap(),
+            run["exit_code"].as_i64().unwrap(),
+            run["attempts"].as_u64().unwrap(),
+        ))
+        .collect();
+    assert_eq!(runs, [
+        ("First", "success", 0, 1),
+        ("Flaky", "success", 0, 2),
+        ("Broken", "failed", 1, 1),
+        ("Broken", "success", 0, 1),
+        ("Tolerated", "success", 0, 1),
+        ("Last", "success", 0, 1),
+    ]);
+    assert_eq!(report["commands"][2]["output"], serde_json::json!(["running broken", "Command failed: broken broke"]));
+    assert_eq!(report["commands"][0]["package"], "Tools");
+
+    assert!(summary.contains("Locale:    de_DE.UTF-8\n"), "{summary}");
+    assert!(summary.contains("  [exit 1] Tools / Broken ("), "{summary}");
+}
+
+#[tokio::test]
+async fn an_account_the_wizard_did_not_create_is_not_taken_over() {
+    let service = Arc::new(RecordingService::default());
+    service.add_user("lab");
+    let mut app = live_app(&service);
+    app.created_username = None;
+    app.username.set("lab");
+    app.password.set("correct horse");
+    app.password_confirm.set("correct horse");
+
+    let rx = app.start_review_execution();
+    drain(&mut app, rx).await;
+    assert_eq!(states(&app), [TaskState::Failed]);
+    assert!(app.tasks[0].log.iter().any(|l| l.contains("user 'lab' already exists")), "{:?}", app.tasks[0].log);
+    assert!(!app.review_completed);
+    assert_eq!(app.created_username, None);
+
+    // The account from an earlier run of the wizard is kept
+    let mut app = live_app(&service);
+    app.username.set("lab");
+    let rx = app.start_review_execution();
+    drain(&mut app, rx).await;
+    assert!(app.review_completed);
+}
+
+#[tokio::test]
+async fn the_report_after_a_resume_keeps_the_earlier_runs() {
+    let service = Arc::new(RecordingService::default());
+    service.fail("broken", 1);
+    let mut app = live_app(&service);
+    app.config.general.state_file = std::env::temp_dir()
+        .join(format!("hypercube-task-resume-{}.toml", std::process::id()))
+        .display()
+        .to_string();
+    app.setup_started = true;
+
+    let rx = app.start_update_execution();
+    drain(&mut app, rx).await;
+    assert_eq!(app.selected_failed_task(), Some(2));
+
+    // Interrupted at the failure, then started again
+    let mut app = OnboardApp::with_service(app.config.clone(), service.clone());
+    assert!(app.can_resume());
+    app.resume();
+    let rx = app.start_update_execution();
+    drain(&mut app, rx).await;
+    OnboardState::clear(&app.config.general.state_file);
+
+    assert!(app.update_completed);
+    let runs: Vec<(&str, RunOutcome)> = app.command_runs.iter().map(|run| (run.name.as_str(), run.outcome)).collect();
+    assert_eq!(runs, [
+        ("First", RunOutcome::Success),
+        ("Flaky", RunOutcome::Success),
+        ("Broken", RunOutcome::Failed),
+        ("Broken", RunOutcome::Success),
+        ("Tolerated", RunOutcome::Success),
+        ("Last", RunOutcome::Success),
+    ]);
+    assert_eq!(service.attempts("first"), 1);
+}
@@ -0,0 +1,102 @@
+//! Built-in palettes, color parsing and theme files
+
+use hypercube_utils::ui::{Theme, ThemeConfig, PALETTES};
+use ratatui::style::Color;
+
+fn colors(theme: &Theme) -> [Color; 8] {
+    [
+        theme.primary,
+        theme.secondary,
+        theme.background,
+        theme.foreground,
+        theme.error,
+        theme.success,
+        theme.border,
+        theme.muted,
+    ]
+}
+
+fn primary(value: &str) -> Color {
+    ThemeConfig { primary: Some(value.to_string()), ..ThemeConfig::default() }.build().primary
+}
+
+#[test]
+fn every_palette_resolves() {
+    let default = colors(&Theme::default());
+    for name in PALETTES {
+        let theme = Theme::named(name).unwrap_or_else(|| panic!("{name} is not a palette"));
+        assert_eq!(*name == "default", colors(&theme) == default, "{name}");
+
+        let config = ThemeConfig { palette: Some(name.to_string()), ..ThemeConfig::default() };
+        assert_eq!(colors(&config.build()), colors(&theme), "{name}");
+    }
+    assert_eq!(colors(&Theme::named("Nord").unwrap()), colors(&Theme::named("nord").unwrap()));
+}
+
+#[test]
+fn colors_parse_from_hex_indices_and_names() {
+    assert_eq!(primary("#fabd2f"), Color::Rgb(0xfa, 0xbd, 0x2f));
+    assert_eq!(primary("#FABD2F"), Color::Rgb(0xfa, 0xbd, 0x2f));
+    assert_eq!(primary("0"), Color::Indexed(0));
+    assert_eq!(primary("136"), Color::Indexed(136));
+    assert_eq!(primary("255"), Color::Indexed(255));
+    assert_eq!(primary("red"), Color::Red);
+    assert_eq!(primary("light-blue"), Color::LightBlue);
+    assert_eq!(primary("Dark Gray"), Color::DarkGray);
+}
+
+#[test]
+fn invalid_values_fall_back() {
+    // Bad colors keep the palette's color, including #rgb shorthand and indices past 255
+    let nord = Theme::named("nord").unwrap();
+    for value in ["#fa0", "256", "-1", "#12345g", "chartreuse", ""] {
+        let config = ThemeConfig {
+            palette: Some("nord".to_string()),
+            primary: Some(value.to_string()),
+            ..ThemeConfig::default()
+        };
+        assert_eq!(config.build().primary, nord.primary, "{value:?}");
+    }
+
+    // Unknown palettes are not found by name and build the default theme
+    assert!(Theme::named("solarized").is_none());
+    let config = ThemeConfig { palette: Some("solarized".to_string()), ..ThemeConfig::default() };
+    assert_eq!(colors(&config.build()), colors(&Theme::default()));
+
+    // So does a theme file that cannot be read
+    let config = ThemeConfig { file: Some("/nonexistent/theme.toml".to_string()), ..ThemeConfig::default() };
+    assert_eq!(colors(&config.build()), colors(&Theme::default()));
+    assert!(ThemeConfig::load_from("/nonexistent/theme.toml").is_err());
+}
+
+#[test]
+fn theme_files_apply_on_top_of_their_palette() {
+    let path = std::env::temp_dir().join(format!("hypercube-theme-{}.toml", std::process::id()));
+    std::fs::write(&path, "palette = \"gruvbox\"\nprimary = \"#ff0000\"\nborder = \"240\"\n").unwrap();
+    let file = Some(path.display().to_string());
+
+    let gruvbox = Theme::named("gruvbox").unwrap();
+    let theme = ThemeConfig { file: file.clone(), ..ThemeConfig::default() }.build();
+    assert_eq!(theme.primary, Color::Rgb(0xff, 0, 0));
+    assert_eq!(theme.border, Color::Indexed(240));
+    assert_eq!(theme.secondary, gruvbox.secondary);
+    assert_eq!(theme.background, gruvbox.background);
+
+    // The section's own palette and colors win over the file's
+    let theme = ThemeConfig {
+        palette: Some("nord".to_string()),
+        file,
+        border: Some("yellow".to_string()),
+        ..ThemeConfig::default()
+    }
+    .build();
+    std::fs::remove_file(&path).unwrap();
+    let nord = Theme::named("nord").unwrap();
+    assert_eq!(theme.primary, Color::Rgb(0xff, 0, 0));
+    assert_eq!(theme.border, Color::Yellow);
+    assert_eq!(theme.secondary, nord.secondary);
+
+    let example = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/themes/solarized.toml");
+    let theme = ThemeConfig { file: Some(example.to_string()), ..ThemeConfig::default() }.build();
+    assert_eq!(theme.background, Color::Rgb(0x00, 0x2b, 0x36));
+}
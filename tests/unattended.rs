@@ -0,0 +1,153 @@
+//! Headless onboarding from answer files, run against the dryrun service
+
+use hypercube_utils::onboard::{
+    run_unattended, Answers, OnboardApp, OnboardConfig, StepId, StepResult, UnattendedError,
+};
+
+fn demo_app() -> OnboardApp {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/demo.toml");
+    let mut config = OnboardConfig::load_from(path).unwrap();
+    config.general.dryrun = true;
+    config.completion.report = String::new();
+    OnboardApp::new(config)
+}
+
+fn step_result(app: &OnboardApp, id: StepId) -> StepResult {
+    let idx = app.menu_items.iter().position(|item| item.id == id).unwrap();
+    app.step_results[idx]
+}
+
+#[tokio::test]
+async fn example_answers_complete_setup() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/answers.toml");
+    let answers = Answers::load_from(path).unwrap();
+    let mut app = demo_app();
+    let report = std::env::temp_dir().join(format!("hypercube-unattended-report-{}.json", std::process::id()));
+    app.config.completion.report = report.display().to_string();
+
+    run_unattended(&mut app, &answers).await.unwrap();
+
+    assert!(app.setup_complete);
+    assert_eq!(app.created_username.as_deref(), Some("lab"));
+    assert_eq!(app.selected_timezone.as_deref(), Some("America/New_York"));
+    assert_eq!(step_result(&app, StepId::Review), StepResult::Completed);
+    assert_eq!(step_result(&app, StepId::Update), StepResult::Completed);
+
+    // Nothing ran, and the report says so
+    let json = std::fs::read_to_string(&report).unwrap();
+    let summary = std::fs::read_to_string(report.with_extension("txt")).unwrap();
+    std::fs::remove_file(&report).unwrap();
+    std::fs::remove_file(report.with_extension("txt")).unwrap();
+    let report: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(report["dryrun"], true);
+    assert_eq!(report["hostname"], "lab-01");
+    assert_eq!(report["keymap"], "us");
+    let commands = report["commands"].as_array().unwrap();
+    assert!(!commands.is_empty());
+    assert!(commands.iter().all(|run| run["outcome"] == "simulated" && run["exit_code"].is_null()));
+    assert!(summary.lines().nth(1).unwrap().ends_with(" (dryrun)"), "{summary}");
+}
+
+#[tokio::test]
+async fn confirm_mode_opens_review() {
+    let answers: Answers = toml::from_str(
+        r#"
+        username = "lab"
+        password = "changeme"
+        hostname = "lab"
+        locale = "de_DE.UTF-8"
+        keymap = "de"
+        timezone = "UTC"
+        "#,
+    )
+    .unwrap();
+    let mut app = demo_app();
+
+    app.apply_answers(&answers).unwrap();
+
+    assert_eq!(app.current_step_id(), Some(StepId::Review));
+    assert_eq!(step_result(&app, StepId::User), StepResult::Completed);
+    assert_eq!(step_result(&app, StepId::Keyboard), StepResult::Completed);
+}
+
+#[tokio::test]
+async fn hostname_answers_are_checked_and_queued_for_review() {
+    let answers: Answers = toml::from_str(
+        r#"
+        username = "lab"
+        password = "changeme"
+        hostname = "Lab-01"
+        pretty_hostname = "Lab's Workstation"
+        "#,
+    )
+    .unwrap();
+    let mut app = demo_app();
+
+    app.apply_answers(&answers).unwrap();
+    assert_eq!(step_result(&app, StepId::Hostname), StepResult::Completed);
+    assert_eq!(app.selected_hostname.as_deref(), Some("lab-01"));
+    assert_eq!(app.selected_pretty_hostname.as_deref(), Some("Lab's Workstation"));
+
+    app.start_review_execution();
+    let names: Vec<&str> = app.tasks.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names[..2], ["Creating user 'lab'", "Setting hostname to lab-01"]);
+}
+
+#[tokio::test]
+async fn preference_toggles_become_review_tasks() {
+    let review_tasks = |answers: &str| {
+        let answers: Answers = toml::from_str(answers).unwrap();
+        let mut app = demo_app();
+        app.apply_answers(&answers).unwrap();
+        app.start_review_execution();
+        app.tasks.iter().skip(1).map(|t| t.name.clone()).collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        review_tasks(r#"username = "lab"
+                        password = "changeme"
+                        ntp = false"#),
+        ["Turning off network time sync", "Creating login keyring for 'lab'"]
+    );
+    // Nothing can unlock a keyring for a hashed password; PAM makes one at first login
+    assert_eq!(
+        review_tasks(r#"username = "lab"
+                        password_hash = "$6$x$y""#),
+        ["Turning on network time sync"]
+    );
+    assert_eq!(
+        review_tasks(r#"username = "lab"
+                        password = "changeme"
+                        keyring = false"#),
+        ["Turning on network time sync"]
+    );
+}
+
+#[tokio::test]
+async fn invalid_answers_exit_with_status_2() {
+    let cases = [
+        r#"username = "lab"
+           password_hash = "$6$x$y"
+           packages = ["Not A Package"]"#,
+        r#"username = "lab"
+           password_hash = "$6$x$y"
+           timezone = "Mars/Olympus_Mons""#,
+        r#"locale = "en_US.UTF-8""#,
+        r#"username = "bad name!"
+           password = "changeme""#,
+        r#"username = "lab"
+           password = "changeme"
+           hostname = "-lab-""#,
+        r#"username = "lab"
+           password_hash = "$6$x$y"
+           keyring = true"#,
+    ];
+
+    for case in cases {
+        let answers: Answers = toml::from_str(case).unwrap();
+        let mut app = demo_app();
+        let err = run_unattended(&mut app, &answers).await.unwrap_err();
+        assert!(matches!(err, UnattendedError::Answers(_)), "{case}: {err}");
+        assert_eq!(err.exit_code(), 2);
+    }
+}
@@ -0,0 +1,82 @@
+//! Streamed command output and the Update step's log pane
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use hypercube_utils::onboard::{
+    ExecutionMessage, OnboardApp, OnboardConfig, PanelFocus, StepId, TaskState, TaskStatus,
+};
+
+fn update_app(tasks: &[&str]) -> OnboardApp {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/demo.toml");
+    let mut config = OnboardConfig::load_from(path).unwrap();
+    config.general.dryrun = true;
+    let mut app = OnboardApp::new(config);
+    app.selected_step = app.menu_items.iter().position(|m| m.id == StepId::Update).unwrap();
+    app.panel_focus = PanelFocus::Content;
+    app.task_step = Some(StepId::Update);
+    for name in tasks {
+        app.tasks.push(TaskStatus {
+            name: name.to_string(),
+            status: TaskState::Pending,
+            output: None,
+            progress: None,
+            count: None,
+            command: None,
+            log: Vec::new(),
+        });
+    }
+    app
+}
+
+fn press(app: &mut OnboardApp, code: KeyCode, modifiers: KeyModifiers) {
+    app.handle_key(KeyEvent::new(code, modifiers));
+}
+
+#[test]
+fn output_lines_are_appended_to_their_task() {
+    let mut app = update_app(&["Refreshing package cache", "Installing Firefox"]);
+    app.handle_execution_message(ExecutionMessage::TaskStarted(1));
+    app.handle_execution_message(ExecutionMessage::TaskOutput(1, "Resolving".to_string()));
+    app.handle_execution_message(ExecutionMessage::TaskOutput(1, "Installing".to_string()));
+
+    assert!(app.tasks[0].log.is_empty());
+    assert_eq!(app.tasks[1].log, ["Resolving", "Installing"]);
+    assert_eq!(app.tasks[1].last_line(), Some("Installing"));
+    // The pane follows the running task
+    assert_eq!(app.log_task, 1);
+}
+
+#[test]
+fn log_pane_scrolls_and_follows_the_tail() {
+    let mut app = update_app(&["Refreshing package cache", "Installing Firefox"]);
+    app.is_executing = true;
+    app.log_height = 10;
+    for n in 0..30 {
+        app.handle_execution_message(ExecutionMessage::TaskOutput(0, format!("line {n}")));
+    }
+    assert_eq!(app.log_top(), 20);
+
+    press(&mut app, KeyCode::Char('k'), KeyModifiers::NONE);
+    assert_eq!(app.log_scroll, Some(19));
+    press(&mut app, KeyCode::Char('u'), KeyModifiers::CONTROL);
+    assert_eq!(app.log_top(), 14);
+    press(&mut app, KeyCode::Char('g'), KeyModifiers::NONE);
+    assert_eq!(app.log_top(), 0);
+
+    // New output does not move a scrolled pane
+    app.handle_execution_message(ExecutionMessage::TaskOutput(0, "line 30".to_string()));
+    assert_eq!(app.log_top(), 0);
+
+    // Scrolling back to the bottom resumes following
+    for _ in 0..5 {
+        press(&mut app, KeyCode::Char('d'), KeyModifiers::CONTROL);
+    }
+    assert_eq!(app.log_scroll, None);
+    assert_eq!(app.log_top(), 21);
+
+    press(&mut app, KeyCode::Char('J'), KeyModifiers::SHIFT);
+    assert_eq!(app.log_task, 1);
+    press(&mut app, KeyCode::Char('J'), KeyModifiers::SHIFT);
+    assert_eq!(app.log_task, 1);
+    press(&mut app, KeyCode::Char('K'), KeyModifiers::SHIFT);
+    assert_eq!(app.log_task, 0);
+}
@@ -0,0 +1,213 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use hypercube_utils::onboard::{OnboardApp, OnboardConfig};
+use hypercube_utils::vim::{InputBuffer, NormalParser, Parsed, VimMode};
+
+/// Feed `keys` to a buffer holding `text` with the cursor at `cursor`.
+/// Returns the content, cursor and whether the last command enters insert mode.
+fn run(text: &str, cursor: usize, keys: &str) -> (String, usize, bool) {
+    let mut buffer = InputBuffer::new();
+    buffer.set(text);
+    buffer.set_cursor(cursor);
+
+    let mut parser = NormalParser::new();
+    let mut insert = false;
+    for c in keys.chars() {
+        let key = KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+        match parser.feed(key) {
+            Parsed::Command(command) => {
+                command.apply(&mut buffer);
+                insert = command.enters_insert();
+            }
+            Parsed::Consumed => {}
+            Parsed::Unhandled => panic!("{c:?} in {keys:?} was not handled"),
+        }
+    }
+    (buffer.content().to_string(), buffer.cursor(), insert)
+}
+
+#[test]
+fn motions() {
+    let cases = [
+        ("hello world", 0, "w", 6),
+        ("hello world", 0, "e", 4),
+        ("hello world", 0, "2e", 10),
+        ("hello world", 8, "b", 6),
+        ("foo.bar baz", 0, "w", 3),
+        ("foo.bar baz", 0, "W", 8),
+        ("hello world", 6, "0", 0),
+        ("  indented", 9, "^", 2),
+        ("hello world", 0, "$", 11),
+        ("hello world", 0, "fo", 4),
+        ("hello world", 0, "2fo", 7),
+        ("hello world", 0, "to", 3),
+        ("hello world", 10, "Fo", 7),
+        ("hello world", 0, "fo;", 7),
+        ("hello world", 0, "2fo,", 4),
+        ("hello world", 5, "3h", 2),
+        ("hello world", 0, "12l", 11),
+    ];
+    for (text, cursor, keys, expected) in cases {
+        let (content, pos, _) = run(text, cursor, keys);
+        assert_eq!(content, text, "{keys:?} changed the text");
+        assert_eq!(pos, expected, "{keys:?} on {text:?} from {cursor}");
+    }
+}
+
+#[test]
+fn edits() {
+    let cases = [
+        ("hello", 0, "x", "ello", 0),
+        ("hello", 0, "3x", "lo", 0),
+        ("hello", 3, "X", "helo", 2),
+        ("hello world", 0, "dw", "world", 0),
+        ("one two three", 0, "2dw", "three", 0),
+        ("one two three", 0, "d2w", "three", 0),
+        ("hello world", 2, "D", "he", 2),
+        ("hello world", 0, "dd", "", 0),
+        ("hello world", 0, "dfo", " world", 0),
+        ("hello world", 0, "dto", "o world", 0),
+        ("hello world", 6, "db", "world", 0),
+        ("one two three", 5, "diw", "one  three", 4),
+        ("one two three", 5, "daw", "one three", 4),
+        ("one two", 5, "daw", "one", 3),
+        ("hello", 0, "rj", "jello", 0),
+        ("hello", 0, "3r-", "---lo", 2),
+        ("hello", 0, "9rx", "hello", 0),
+        ("Hello", 0, "3~", "hELlo", 3),
+    ];
+    for (text, cursor, keys, expected, expected_cursor) in cases {
+        let (content, pos, insert) = run(text, cursor, keys);
+        assert_eq!(content, expected, "{keys:?} on {text:?} from {cursor}");
+        assert_eq!(pos, expected_cursor, "cursor after {keys:?} on {text:?}");
+        assert!(!insert, "{keys:?} should stay in normal mode");
+    }
+}
+
+#[test]
+fn changes_enter_insert_mode() {
+    let cases = [
+        ("hello world", 0, "cw", " world", 0),
+        ("hello world", 5, "cw", "helloworld", 5),
+        ("one two three", 5, "ciw", "one  three", 4),
+        ("hello world", 6, "C", "hello ", 6),
+        ("hello world", 3, "cc", "", 0),
+        ("hello world", 3, "S", "", 0),
+        ("hello", 0, "2s", "llo", 0),
+        ("hello", 1, "a", "hello", 2),
+        ("  hello", 5, "I", "  hello", 2),
+        ("hello", 1, "A", "hello", 5),
+    ];
+    for (text, cursor, keys, expected, expected_cursor) in cases {
+        let (content, pos, insert) = run(text, cursor, keys);
+        assert_eq!(content, expected, "{keys:?} on {text:?} from {cursor}");
+        assert_eq!(pos, expected_cursor, "cursor after {keys:?} on {text:?}");
+        assert!(insert, "{keys:?} should enter insert mode");
+    }
+}
+
+#[test]
+fn non_editing_keys_are_left_to_the_app() {
+    let mut parser = NormalParser::new();
+    for code in [KeyCode::Char('j'), KeyCode::Char(':'), KeyCode::Enter, KeyCode::F(2)] {
+        assert_eq!(parser.feed(KeyEvent::new(code, KeyModifiers::NONE)), Parsed::Unhandled);
+    }
+
+    // ...unless they interrupt a pending command
+    parser.feed(KeyEvent::new(KeyCode::Char('d'), KeyModifiers::NONE));
+    assert!(parser.is_pending());
+    assert_eq!(
+        parser.feed(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE)),
+        Parsed::Consumed
+    );
+    assert!(!parser.is_pending());
+}
+
+fn key(c: char) -> KeyEvent {
+    KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)
+}
+
+fn feed(parser: &mut NormalParser, buffer: &mut InputBuffer, event: KeyEvent) {
+    if let Parsed::Command(command) = parser.feed(event) {
+        command.apply(buffer);
+    }
+}
+
+#[test]
+fn undo_and_redo() {
+    let (content, _, _) = run("hello world", 0, "dwu");
+    assert_eq!(content, "hello world");
+
+    let (content, _, _) = run("one two three", 0, "dwdw2u");
+    assert_eq!(content, "one two three");
+
+    // Motions between undo and redo keep the redo history
+    let mut buffer = InputBuffer::new();
+    buffer.set("hello world");
+    buffer.set_cursor(0);
+    buffer.checkpoint();
+    let mut parser = NormalParser::new();
+    for c in "dwu$".chars() {
+        feed(&mut parser, &mut buffer, key(c));
+    }
+    feed(&mut parser, &mut buffer, KeyEvent::new(KeyCode::Char('r'), KeyModifiers::CONTROL));
+    assert_eq!(buffer.content(), "world");
+}
+
+#[test]
+fn change_and_typed_text_undo_together() {
+    let mut buffer = InputBuffer::new();
+    buffer.set("hello world");
+    buffer.set_cursor(0);
+    let mut parser = NormalParser::new();
+
+    feed(&mut parser, &mut buffer, key('c'));
+    feed(&mut parser, &mut buffer, key('w'));
+    for c in "howdy".chars() {
+        buffer.insert(c);
+    }
+    assert_eq!(buffer.content(), "howdy world");
+
+    feed(&mut parser, &mut buffer, key('u'));
+    assert_eq!(buffer.content(), "hello world");
+}
+
+#[test]
+fn masked_buffers_keep_no_history() {
+    let mut buffer = InputBuffer::masked();
+    for c in "secret".chars() {
+        buffer.insert(c);
+    }
+    buffer.checkpoint();
+    buffer.clear();
+
+    assert!(!buffer.undo());
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn pickers_ignore_space_and_digits_in_normal_mode() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/demo.toml");
+    let mut config = OnboardConfig::load_from(path).unwrap();
+    config.general.dryrun = true;
+    let mut app = OnboardApp::new(config);
+    // Opening a picker starts filtering; Esc goes back to normal mode
+    for c in "\n\x1b:goto locale\n\x1b".chars() {
+        let code = match c {
+            '\n' => KeyCode::Enter,
+            '\x1b' => KeyCode::Esc,
+            c => KeyCode::Char(c),
+        };
+        app.handle_key(KeyEvent::new(code, KeyModifiers::NONE));
+    }
+
+    assert_eq!(app.vim_mode, VimMode::Normal);
+    app.handle_key(key(' '));
+    app.handle_key(key('1'));
+    assert_eq!(app.picker_filter.content(), "");
+    assert_eq!(app.vim_mode, VimMode::Normal);
+
+    // Other characters start filtering
+    app.handle_key(key('d'));
+    assert_eq!(app.picker_filter.content(), "d");
+    assert_eq!(app.vim_mode, VimMode::Insert);
+}